@@ -0,0 +1,55 @@
+//! Throughput of bulk pose composition, run with `cargo bench --bench compose`.
+
+use spatial::pose::Pose;
+use spatial::quaternion::Quaternion;
+use spatial::vector::Vector;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Number of poses of the batch measurements.
+const POSES: usize = 100_000;
+/// Number of times every batch is processed.
+const REPEATS: usize = 100;
+
+fn measure(name: &str, compose: impl Fn(&Pose<f64, f64>, &mut [f64])) {
+    let base = Pose::new(
+        Vector::new(0.1, -0.2, 0.3),
+        Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5),
+    );
+    let link = Pose::new(
+        Vector::new(1.0, 2.0, 3.0),
+        Quaternion::with_wijk(0.0, 0.6, 0.0, 0.8),
+    );
+    let mut buffer = vec![link; POSES]
+        .into_iter()
+        .flat_map(Pose::to_array)
+        .collect::<Vec<_>>();
+    let start = Instant::now();
+    for _ in 0..REPEATS {
+        compose(black_box(&base), black_box(&mut buffer));
+    }
+    let elapsed = start.elapsed();
+    black_box(&buffer);
+    println!(
+        "{name}: {:.2} ns per pose",
+        elapsed.as_secs_f64() * 1e9 / (REPEATS * POSES) as f64
+    );
+}
+
+fn main() {
+    measure("copied arrays", |base, buffer| {
+        for chunk in buffer.chunks_exact_mut(7) {
+            let pose = *base * Pose::try_from(&*chunk).unwrap();
+            chunk.copy_from_slice(&pose.to_array());
+        }
+    });
+    measure("pointwise in place", |base, buffer| {
+        Pose::from_flat_slice_mut(buffer)
+            .unwrap()
+            .iter_mut()
+            .for_each(|pose| *pose = *base * *pose)
+    });
+    measure("slice", |base, buffer| {
+        base.combine_slice(Pose::from_flat_slice_mut(buffer).unwrap())
+    });
+}
@@ -0,0 +1,72 @@
+//! Throughput of vector rotation, run with `cargo bench --bench rotate`.
+
+use spatial::pose::Pose;
+use spatial::quaternion::Quaternion;
+use spatial::vector::Vector;
+use std::hint::black_box;
+use std::time::Instant;
+
+const COUNT: usize = 10_000_000;
+
+/// Expanded rotation formula used before the cross product form.
+fn rotate_expanded(rotation: Quaternion<f64>, vector: Vector<f64>) -> Vector<f64> {
+    let (w, i, j, k) = (rotation.w(), rotation.i(), rotation.j(), rotation.k());
+    let (x, y, z) = (vector.x(), vector.y(), vector.z());
+    let prep_x = i * j * y + i * k * z + j * w * z - k * w * y;
+    let result_x = prep_x + prep_x + (i * i - j * j - k * k + w * w) * x;
+    let prep_y = i * j * x - i * w * z + j * k * z + k * w * x;
+    let result_y = prep_y + prep_y + (j * j - i * i - k * k + w * w) * y;
+    let prep_z = i * k * x + i * w * y + j * k * y - j * w * x;
+    let result_z = prep_z + prep_z + (w * w - i * i - j * j + k * k) * z;
+    Vector::new(result_x, result_y, result_z)
+}
+
+fn measure(name: &str, rotate: impl Fn(Quaternion<f64>, Vector<f64>) -> Vector<f64>) {
+    let rotation = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5);
+    let mut vector = Vector::new(1.0, 2.0, 3.0);
+    let start = Instant::now();
+    for _ in 0..COUNT {
+        vector = rotate(black_box(rotation), black_box(vector));
+    }
+    let elapsed = start.elapsed();
+    black_box(vector);
+    println!(
+        "{name}: {:.2} ns per rotation",
+        elapsed.as_secs_f64() * 1e9 / COUNT as f64
+    );
+}
+
+/// Point cloud size of the batch measurements.
+const POINTS: usize = 100_000;
+
+fn measure_batch(name: &str, transform: impl Fn(&Pose<f64, f64>, &mut [Vector<f64>])) {
+    let pose = Pose::new(
+        Vector::new(0.1, -0.2, 0.3),
+        Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5),
+    );
+    let mut points = vec![Vector::new(1.0, 2.0, 3.0); POINTS];
+    let repeats = COUNT / POINTS;
+    let start = Instant::now();
+    for _ in 0..repeats {
+        transform(black_box(&pose), black_box(&mut points));
+    }
+    let elapsed = start.elapsed();
+    black_box(&points);
+    println!(
+        "{name}: {:.2} ns per point",
+        elapsed.as_secs_f64() * 1e9 / (repeats * POINTS) as f64
+    );
+}
+
+fn main() {
+    measure("expanded", rotate_expanded);
+    measure("cross product", Quaternion::rotate);
+    let prepared = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5).prepare();
+    measure("prepared", |_, vector| prepared.rotate(vector));
+    measure_batch("pointwise", |pose, points| {
+        points
+            .iter_mut()
+            .for_each(|point| *point = pose.apply_to(*point))
+    });
+    measure_batch("slice", Pose::apply_to_slice);
+}
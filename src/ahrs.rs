@@ -0,0 +1,498 @@
+use crate::ops::{Epsilon, Norm, Real, Sqrt, Trig};
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use num::Float;
+
+/// Madgwick gradient descent orientation filter.
+///
+/// Orientation is integrated from gyroscope rates and corrected towards the orientation, in which
+/// the measured accelerometer and magnetometer directions match gravity and the local magnetic
+/// field, with the correction rate `beta` in radians per second. Correction step has fixed
+/// size, so the estimate jitters by about `beta * dt` radians even with perfect measurements.
+///
+/// Estimated rotation transforms vectors from the sensor frame into the world frame with `z` axis
+/// pointing up and `x` axis pointing to magnetic north. Gyroscope rates are in radians per second,
+/// accelerometer and magnetometer units are arbitrary as only their directions are used.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::ahrs::Madgwick;
+/// let accel = Vector::new(0.0, 0.5_f64.sin(), 0.5_f64.cos());
+/// let mut filter = Madgwick::new(0.5);
+/// for _ in 0..2000 {
+///     filter.update_imu(Vector::zero(), accel, 0.01_f64);
+/// }
+/// let gravity = filter.rotation().inverse().rotate(Vector::<f64>::unit_z());
+/// assert!((gravity - accel).norm() < 1e-2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Madgwick<T> {
+    beta: T,
+    rotation: Quaternion<T>,
+}
+
+impl<T> Madgwick<T>
+where
+    T: Float + Real,
+{
+    /// Create new filter with given correction gain, starting at identity rotation.
+    pub fn new(beta: T) -> Self {
+        Self::with_rotation(beta, Quaternion::identity())
+    }
+
+    /// Create new filter with given correction gain, starting at given rotation.
+    pub fn with_rotation(beta: T, rotation: Quaternion<T>) -> Self {
+        Self { beta, rotation }
+    }
+
+    /// Feed gyroscope and accelerometer sample taken `dt` after the previous one,
+    /// returns updated rotation. Zero accelerometer reading skips correction.
+    pub fn update_imu(&mut self, gyro: Vector<T>, accel: Vector<T>, dt: T) -> Quaternion<T> {
+        let gradient = accel
+            .normalized_checked()
+            .map(|accel| gravity_gradient(self.rotation, accel));
+        self.step(gyro, gradient, dt)
+    }
+
+    /// Feed gyroscope, accelerometer and magnetometer sample taken `dt` after the previous one,
+    /// returns updated rotation. Zero magnetometer reading falls back to [`Madgwick::update_imu`],
+    /// zero accelerometer reading skips correction.
+    pub fn update(
+        &mut self,
+        gyro: Vector<T>,
+        accel: Vector<T>,
+        magnetic: Vector<T>,
+        dt: T,
+    ) -> Quaternion<T> {
+        let gradient = accel.normalized_checked().map(|accel| {
+            let gradient = gravity_gradient(self.rotation, accel);
+            match magnetic.normalized_checked() {
+                Some(magnetic) => gradient + magnetic_gradient(self.rotation, magnetic),
+                None => gradient,
+            }
+        });
+        self.step(gyro, gradient, dt)
+    }
+
+    /// Get current rotation estimate.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.rotation
+    }
+
+    /// Reset rotation estimate to given rotation.
+    pub fn reset(&mut self, rotation: Quaternion<T>) {
+        self.rotation = rotation;
+    }
+
+    fn step(&mut self, gyro: Vector<T>, gradient: Option<Quaternion<T>>, dt: T) -> Quaternion<T> {
+        let mut derivative = rate_derivative(self.rotation, gyro);
+        if let Some(gradient) = gradient.and_then(normalized) {
+            derivative = derivative + gradient * -self.beta;
+        }
+        self.rotation = normalized(self.rotation + derivative * dt).unwrap_or(self.rotation);
+        self.rotation
+    }
+}
+
+/// Mahony complementary orientation filter with gyroscope bias estimation.
+///
+/// Orientation is integrated from gyroscope rates corrected by the proportional and integral
+/// feedback of the misalignment between measured and predicted gravity and magnetic field
+/// directions. Frame and unit conventions are the same as for [`Madgwick`].
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::ahrs::Mahony;
+/// let accel = Vector::new(0.0, 0.5_f64.sin(), 0.5_f64.cos());
+/// let gyro = Vector::new(0.0, 0.0, 0.01);
+/// let mut filter = Mahony::new(2.0, 0.5);
+/// for _ in 0..5000 {
+///     filter.update_imu(gyro, accel, 0.01);
+/// }
+/// let gravity = filter.rotation().inverse().rotate(Vector::<f64>::unit_z());
+/// assert!((gravity - accel).norm() < 1e-3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Mahony<T> {
+    proportional_gain: T,
+    integral_gain: T,
+    rotation: Quaternion<T>,
+    correction: Vector<T>,
+}
+
+impl<T> Mahony<T>
+where
+    T: Float + Real,
+{
+    /// Create new filter with given proportional and integral gains, starting at identity
+    /// rotation. Zero integral gain disables gyroscope bias estimation.
+    pub fn new(proportional_gain: T, integral_gain: T) -> Self {
+        Self::with_rotation(proportional_gain, integral_gain, Quaternion::identity())
+    }
+
+    /// Create new filter with given proportional and integral gains, starting at given rotation.
+    pub fn with_rotation(proportional_gain: T, integral_gain: T, rotation: Quaternion<T>) -> Self {
+        Self {
+            proportional_gain,
+            integral_gain,
+            rotation,
+            correction: Vector::zero(),
+        }
+    }
+
+    /// Feed gyroscope and accelerometer sample taken `dt` after the previous one,
+    /// returns updated rotation. Zero accelerometer reading skips correction.
+    pub fn update_imu(&mut self, gyro: Vector<T>, accel: Vector<T>, dt: T) -> Quaternion<T> {
+        let error = accel
+            .normalized_checked()
+            .map(|accel| gravity_error(self.rotation, accel));
+        self.step(gyro, error, dt)
+    }
+
+    /// Feed gyroscope, accelerometer and magnetometer sample taken `dt` after the previous one,
+    /// returns updated rotation. Zero magnetometer reading falls back to [`Mahony::update_imu`],
+    /// zero accelerometer reading skips correction.
+    pub fn update(
+        &mut self,
+        gyro: Vector<T>,
+        accel: Vector<T>,
+        magnetic: Vector<T>,
+        dt: T,
+    ) -> Quaternion<T> {
+        let error = accel.normalized_checked().map(|accel| {
+            let error = gravity_error(self.rotation, accel);
+            match magnetic.normalized_checked() {
+                Some(magnetic) => error + magnetic_error(self.rotation, magnetic),
+                None => error,
+            }
+        });
+        self.step(gyro, error, dt)
+    }
+
+    /// Get current rotation estimate.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.rotation
+    }
+
+    /// Get current gyroscope bias estimate in radians per second.
+    pub fn gyro_bias(&self) -> Vector<T> {
+        -self.correction
+    }
+
+    /// Reset rotation estimate to given rotation and forget gyroscope bias estimate.
+    pub fn reset(&mut self, rotation: Quaternion<T>) {
+        self.rotation = rotation;
+        self.correction = Vector::zero();
+    }
+
+    fn step(&mut self, gyro: Vector<T>, error: Option<Vector<T>>, dt: T) -> Quaternion<T> {
+        let mut rate = gyro;
+        if let Some(error) = error {
+            if self.integral_gain > T::zero() {
+                self.correction = self.correction + error * (self.integral_gain * dt);
+            }
+            rate = rate + error * self.proportional_gain;
+        }
+        rate = rate + self.correction;
+        let derivative = rate_derivative(self.rotation, rate);
+        self.rotation = normalized(self.rotation + derivative * dt).unwrap_or(self.rotation);
+        self.rotation
+    }
+}
+
+/// Complementary orientation filter for gyroscope and accelerometer samples.
+///
+/// Gyroscope rates are integrated exactly and the resulting tilt is blended towards
+/// the accelerometer tilt with the given time constant in seconds, yaw is never corrected.
+/// This is the cheapest filter in this module, frame and unit conventions are the same
+/// as for [`Madgwick`].
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::ahrs::Complementary;
+/// let accel = Vector::new(0.0, 0.5_f64.sin(), 0.5_f64.cos());
+/// let mut filter = Complementary::from_accel(0.5, accel).unwrap();
+/// let gravity = filter.rotation().inverse().rotate(Vector::<f64>::unit_z());
+/// assert!((gravity - accel).norm() < 1e-9);
+/// let gyro = Vector::new(0.01, 0.0, 0.0);
+/// for _ in 0..1000 {
+///     filter.update(gyro, accel, 0.01);
+/// }
+/// let gravity = filter.rotation().inverse().rotate(Vector::<f64>::unit_z());
+/// assert!((gravity - accel).norm() < 1e-2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complementary<T> {
+    time_constant: T,
+    rotation: Quaternion<T>,
+}
+
+impl<T> Complementary<T>
+where
+    T: Float + Real,
+{
+    /// Create new filter with given time constant, starting at identity rotation.
+    /// Zero time constant makes tilt follow the accelerometer without smoothing.
+    pub fn new(time_constant: T) -> Self {
+        Self::with_rotation(time_constant, Quaternion::identity())
+    }
+
+    /// Create new filter with given time constant, starting at given rotation.
+    pub fn with_rotation(time_constant: T, rotation: Quaternion<T>) -> Self {
+        Self {
+            time_constant,
+            rotation,
+        }
+    }
+
+    /// Create new filter with given time constant, starting at tilt measured by a static
+    /// accelerometer sample with zero yaw. Returns `None` for zero accelerometer reading.
+    pub fn from_accel(time_constant: T, accel: Vector<T>) -> Option<Self> {
+        Some(Self::with_rotation(time_constant, tilt(accel)?))
+    }
+
+    /// Feed gyroscope and accelerometer sample taken `dt` after the previous one,
+    /// returns updated rotation. Zero accelerometer reading skips correction.
+    pub fn update(&mut self, gyro: Vector<T>, accel: Vector<T>, dt: T) -> Quaternion<T> {
+        if dt.is_nan() || dt <= T::zero() {
+            return self.rotation;
+        }
+        let mut rotation = self.rotation.integrate(gyro, dt);
+        if let Some(accel) = accel.normalized_checked() {
+            let predicted = predicted(rotation, T::zero(), T::one());
+            let axis = accel.cross(predicted);
+            let sin = axis.norm();
+            if sin > <T as Epsilon>::epsilon() {
+                let angle = Trig::atan2(sin, accel.dot(predicted));
+                let blend = dt / (self.time_constant + dt);
+                rotation *= Quaternion::exp(axis * (angle * blend / sin));
+            }
+        }
+        self.rotation = normalized(rotation).unwrap_or(self.rotation);
+        self.rotation
+    }
+
+    /// Get current rotation estimate.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.rotation
+    }
+
+    /// Reset rotation estimate to given rotation.
+    pub fn reset(&mut self, rotation: Quaternion<T>) {
+        self.rotation = rotation;
+    }
+}
+
+/// Calculate yaw of the sensor relative to true north from magnetometer reading compensated
+/// by roll and pitch of given rotation, `None` if the field has no horizontal component.
+///
+/// Yaw follows [`Quaternion::from_rpy`], it is measured counterclockwise about the world
+/// z axis pointing up from the world x axis pointing to true north. Declination is the angle
+/// of magnetic north east of true north, the result is wrapped into `[-pi, pi]`.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::ahrs::heading;
+/// let truth = Quaternion::<f64>::from_rpy(0.2, -0.1, 1.0);
+/// let magnetic = truth.inverse().rotate(Vector::new(20.0, 0.0, -45.0));
+/// let rotation = Quaternion::<f64>::from_rpy(0.2, -0.1, 0.0);
+/// assert!((heading(rotation, magnetic, 0.0).unwrap() - 1.0_f64).abs() < 1e-9);
+/// assert!((heading(rotation, magnetic, 0.1).unwrap() - 0.9_f64).abs() < 1e-9);
+/// ```
+pub fn heading<T>(rotation: Quaternion<T>, magnetic: Vector<T>, declination: T) -> Option<T>
+where
+    T: Float + Real,
+{
+    let field = rotation.rotate(magnetic);
+    if Vector::new(field.x(), field.y(), T::zero()).norm() < <T as Epsilon>::epsilon() {
+        return None;
+    }
+    let two = T::one() + T::one();
+    let yaw = Trig::atan2(
+        two * (rotation.w() * rotation.k() + rotation.i() * rotation.j()),
+        T::one() - two * (rotation.j() * rotation.j() + rotation.k() * rotation.k()),
+    );
+    let heading = yaw - Trig::atan2(field.y(), field.x()) - declination;
+    Some(Trig::atan2(Trig::sin(heading), Trig::cos(heading)))
+}
+
+/// Calculate yaw of the sensor relative to true north from magnetometer reading compensated
+/// by tilt measured by a static accelerometer, see [`heading`]. Returns `None` for zero
+/// accelerometer reading or field without horizontal component.
+pub fn heading_from_gravity<T>(accel: Vector<T>, magnetic: Vector<T>, declination: T) -> Option<T>
+where
+    T: Float + Real,
+{
+    heading(tilt(accel)?, magnetic, declination)
+}
+
+/// Rotation with zero yaw, in which gravity is measured along given accelerometer reading,
+/// `None` for zero reading.
+fn tilt<T>(accel: Vector<T>) -> Option<Quaternion<T>>
+where
+    T: Float + Real,
+{
+    Quaternion::from_gravity(-accel)
+}
+
+/// Calculate time derivative of rotation for given sensor-frame angular rate.
+fn rate_derivative<T>(rotation: Quaternion<T>, rate: Vector<T>) -> Quaternion<T>
+where
+    T: Float,
+{
+    let half = T::one() / (T::one() + T::one());
+    rotation * Quaternion::with_wijk(T::zero(), rate.x(), rate.y(), rate.z()) * half
+}
+
+/// Scale quaternion to unit norm, `None` if norm is below epsilon.
+fn normalized<T>(quaternion: Quaternion<T>) -> Option<Quaternion<T>>
+where
+    T: Float + Sqrt<Output = T> + Epsilon,
+{
+    let norm = Norm::norm(quaternion);
+    if norm < <T as Epsilon>::epsilon() {
+        None
+    } else {
+        Some(quaternion * (T::one() / norm))
+    }
+}
+
+/// Direction of the world reference field given by its horizontal and vertical components,
+/// expressed in the sensor frame.
+fn predicted<T>(rotation: Quaternion<T>, horizontal: T, vertical: T) -> Vector<T>
+where
+    T: Float,
+{
+    rotation
+        .inverse()
+        .rotate(Vector::new(horizontal, T::zero(), vertical))
+}
+
+/// Reference magnetic field with the measured inclination, pointing to the north.
+fn magnetic_reference<T>(rotation: Quaternion<T>, magnetic: Vector<T>) -> (T, T)
+where
+    T: Float + Sqrt<Output = T>,
+{
+    let world = rotation.rotate(magnetic);
+    let horizontal = Vector::new(world.x(), world.y(), T::zero()).norm();
+    (horizontal, world.z())
+}
+
+/// Gradient of the squared misalignment between predicted and measured gravity directions.
+fn gravity_gradient<T>(rotation: Quaternion<T>, accel: Vector<T>) -> Quaternion<T>
+where
+    T: Float,
+{
+    let (w, i, j, k) = (rotation.w(), rotation.i(), rotation.j(), rotation.k());
+    let two = T::one() + T::one();
+    let four = two + two;
+    let error = predicted(rotation, T::zero(), T::one()) - accel;
+    Quaternion::with_wijk(-two * j, two * k, -two * w, two * i) * error.x()
+        + Quaternion::with_wijk(two * i, two * w, two * k, two * j) * error.y()
+        + Quaternion::with_wijk(T::zero(), -four * i, -four * j, T::zero()) * error.z()
+}
+
+/// Gradient of the squared misalignment between predicted and measured magnetic field
+/// directions.
+fn magnetic_gradient<T>(rotation: Quaternion<T>, magnetic: Vector<T>) -> Quaternion<T>
+where
+    T: Float + Sqrt<Output = T>,
+{
+    let (w, i, j, k) = (rotation.w(), rotation.i(), rotation.j(), rotation.k());
+    let two = T::one() + T::one();
+    let (x, z) = magnetic_reference(rotation, magnetic);
+    let (x2, z2, x4, z4) = (two * x, two * z, two * two * x, two * two * z);
+    let error = predicted(rotation, x, z) - magnetic;
+    Quaternion::with_wijk(-z2 * j, z2 * k, -x4 * j - z2 * w, -x4 * k + z2 * i) * error.x()
+        + Quaternion::with_wijk(
+            -x2 * k + z2 * i,
+            x2 * j + z2 * w,
+            x2 * i + z2 * k,
+            -x2 * w + z2 * j,
+        ) * error.y()
+        + Quaternion::with_wijk(x2 * j, x2 * k - z4 * i, x2 * w - z4 * j, x2 * i) * error.z()
+}
+
+/// Rotation error between measured and predicted gravity directions.
+fn gravity_error<T>(rotation: Quaternion<T>, accel: Vector<T>) -> Vector<T>
+where
+    T: Float,
+{
+    accel.cross(predicted(rotation, T::zero(), T::one()))
+}
+
+/// Rotation error between measured and predicted magnetic field directions.
+fn magnetic_error<T>(rotation: Quaternion<T>, magnetic: Vector<T>) -> Vector<T>
+where
+    T: Float + Sqrt<Output = T>,
+{
+    let (horizontal, vertical) = magnetic_reference(rotation, magnetic);
+    magnetic.cross(predicted(rotation, horizontal, vertical))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{heading, heading_from_gravity, Complementary, Madgwick, Mahony};
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    fn measurements(rotation: Quaternion<f64>) -> (Vector<f64>, Vector<f64>) {
+        let inverse = rotation.inverse();
+        let accel = inverse.rotate(Vector::new(0.0, 0.0, 9.81));
+        let magnetic = inverse.rotate(Vector::new(20.0, 0.0, -45.0));
+        (accel, magnetic)
+    }
+
+    #[test]
+    fn marg_filters_converge_to_true_orientation() {
+        let truth = Quaternion::from_angle_axis(1.0, Vector::new(0.3, -0.4, 1.0));
+        let (accel, magnetic) = measurements(truth);
+        let mut madgwick = Madgwick::new(0.5);
+        let mut mahony = Mahony::new(2.0, 0.0);
+        for _ in 0..6000 {
+            madgwick.update(Vector::zero(), accel, magnetic, 0.01);
+            mahony.update(Vector::zero(), accel, magnetic, 0.01);
+        }
+        assert!(madgwick.rotation().dot(truth).abs() > 1.0 - 1e-6);
+        assert!(mahony.rotation().dot(truth).abs() > 1.0 - 1e-6);
+    }
+
+    #[test]
+    fn mahony_estimates_gyro_bias() {
+        let truth = Quaternion::from_angle_axis(0.2, Vector::unit_x());
+        let (accel, magnetic) = measurements(truth);
+        let bias = Vector::new(0.01, -0.02, 0.03);
+        let mut filter = Mahony::with_rotation(2.0, 0.5, truth);
+        for _ in 0..20000 {
+            filter.update(bias, accel, magnetic, 0.01);
+        }
+        assert!((filter.gyro_bias() - bias).norm() < 1e-4);
+        assert!(filter.rotation().dot(truth).abs() > 1.0 - 1e-6);
+    }
+
+    #[test]
+    fn complementary_filter_converges_to_accelerometer_tilt() {
+        let truth = Quaternion::from_angle_axis(0.4, Vector::unit_z())
+            * Quaternion::from_angle_axis(-0.3, Vector::new(1.0, 1.0, 0.0));
+        let (accel, _) = measurements(truth);
+        let mut filter = Complementary::new(0.2);
+        for _ in 0..2000 {
+            filter.update(Vector::zero(), accel, 0.01);
+        }
+        let gravity = filter.rotation().inverse().rotate(Vector::<f64>::unit_z());
+        assert!((gravity - accel.normalized_checked().unwrap()).norm() < 1e-9);
+    }
+
+    #[test]
+    fn tilt_compensated_heading_matches_true_yaw() {
+        for yaw in [-3.0_f64, -1.5, 0.0, 0.7, 2.5] {
+            let truth = Quaternion::<f64>::from_rpy(0.4, -0.6, yaw);
+            let (accel, magnetic) = measurements(truth);
+            let estimated = heading_from_gravity(accel, magnetic, 0.0).unwrap();
+            assert!((estimated - yaw).abs() < 1e-9);
+            let estimated = heading(truth, magnetic, -0.5).unwrap();
+            let expected = yaw + 0.5;
+            let expected = expected.sin().atan2(expected.cos());
+            assert!((estimated - expected).abs() < 1e-9);
+        }
+        assert!(heading_from_gravity(Vector::zero(), Vector::unit_x(), 0.0).is_none());
+        assert!(heading(Quaternion::identity(), Vector::unit_z(), 0.0).is_none());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::fitting::symmetric_eigen;
+use crate::ops::Real;
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+
+/// Corresponding motions `A` and `B` of two rigidly attached frames, see [`solve_ax_xb`].
+pub type MotionPair<T> = (Pose<T, T>, Pose<T, T>);
+
+/// Solve hand-eye calibration `A * X = X * B` for `X` by the method of Park and Martin.
+///
+/// Every pair holds motion `A` of one frame, e.g. the robot flange between two stations,
+/// and the corresponding motion `B` of the rigidly attached frame, e.g. the camera observing
+/// a fixed target, then `X` is the pose of the attached frame relative to the first one.
+/// Rotation is the least-squares fit of rotation vectors of the motions and cross products
+/// of consecutive ones, translation is the least-squares solution of the remaining linear
+/// equations. Returns `None` if there are fewer than two pairs or rotation axes of all
+/// motions are parallel, which leaves the solution undetermined.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::calibration::solve_ax_xb;
+/// let camera = Pose::new(
+///     Vector::new(0.05_f64, -0.02, 0.1),
+///     Quaternion::from_angle_axis(1.2, Vector::new(1.0, 0.5, -0.2)),
+/// );
+/// let motions = [
+///     Pose::new(Vector::new(0.1, 0.0, 0.2), Quaternion::from_angle_axis(0.5, Vector::unit_x())),
+///     Pose::new(Vector::new(-0.3, 0.2, 0.0), Quaternion::from_angle_axis(0.8, Vector::unit_y())),
+/// ];
+/// let pairs = motions.map(|motion| (motion, camera.inverse() * motion * camera));
+/// let solution = solve_ax_xb(&pairs).unwrap();
+/// assert!((solution.translation() - camera.translation()).norm() < 1e-12);
+/// assert!(solution.rotation().angle_to(camera.rotation()) < 1e-12);
+/// ```
+pub fn solve_ax_xb<T>(pairs: &[MotionPair<T>]) -> Option<Pose<T, T>>
+where
+    T: Real,
+{
+    if pairs.len() < 2 {
+        return None;
+    }
+    let mut m = [[T::zero(); 3]; 3];
+    let mut add = |alpha: Vector<T>, beta: Vector<T>| {
+        for (row, value) in m.iter_mut().zip(<[T; 3]>::from(beta)) {
+            for (cell, other) in row.iter_mut().zip(<[T; 3]>::from(alpha)) {
+                *cell = *cell + value * other;
+            }
+        }
+    };
+    let mut previous: Option<(Vector<T>, Vector<T>)> = None;
+    for (a, b) in pairs {
+        let (alpha, beta) = (a.rotation().log(), b.rotation().log());
+        add(alpha, beta);
+        // Cross products of rotation vectors are related by the same rotation, two motions
+        // with distinct axes would otherwise leave `M` singular.
+        if let Some((last_alpha, last_beta)) = previous {
+            add(last_alpha.cross(alpha), last_beta.cross(beta));
+        }
+        previous = Some((alpha, beta));
+    }
+    let mut normal = [[T::zero(); 3]; 3];
+    for (row, values) in normal.iter_mut().enumerate() {
+        for (column, value) in values.iter_mut().enumerate() {
+            *value = (0..3).fold(T::zero(), |sum, k| sum + m[k][row] * m[k][column]);
+        }
+    }
+    // Polar decomposition `R = (M^T M)^(-1/2) M^T` expanded over eigenvectors of `M^T M`.
+    let (values, vectors) = well_conditioned(symmetric_eigen(normal))?;
+    let mut matrix = [[T::zero(); 3]; 3];
+    for (value, vector) in values.into_iter().zip(vectors) {
+        let scale = T::one() / value.sqrt();
+        let left = <[T; 3]>::from(vector);
+        let image = [0, 1, 2].map(|row| (0..3).fold(T::zero(), |sum, k| sum + m[row][k] * left[k]));
+        for (row, first) in matrix.iter_mut().zip(left) {
+            for (cell, second) in row.iter_mut().zip(image) {
+                *cell = *cell + first * second * scale;
+            }
+        }
+    }
+    let rotation = Quaternion::from_rotation_matrix(matrix);
+    // Normal equations of `(R_A - I) t = R * t_B - t_A`, `(R_A - I)^T (R_A - I)` equals
+    // `2 I - R_A - R_A^T`.
+    let mut normal = [[T::zero(); 3]; 3];
+    let mut right = Vector::zero();
+    for (a, b) in pairs {
+        let motion = a.rotation().into_rotation_matrix();
+        for (row, values) in normal.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                let diagonal = if row == column {
+                    T::one() + T::one()
+                } else {
+                    T::zero()
+                };
+                *value = *value + diagonal - motion[row][column] - motion[column][row];
+            }
+        }
+        let residual = rotation.rotate(b.translation()) - a.translation();
+        right = right + a.rotation().rotate_inverse(residual) - residual;
+    }
+    let (values, vectors) = well_conditioned(symmetric_eigen(normal))?;
+    let translation = values
+        .into_iter()
+        .zip(vectors)
+        .fold(Vector::zero(), |sum, (value, vector)| {
+            sum + vector * (vector.dot(right) / value)
+        });
+    Some(Pose::new(translation, rotation))
+}
+
+/// Pass through eigen decomposition of positive semi-definite matrix, `None` if the smallest
+/// eigenvalue is negligible compared to the largest one.
+fn well_conditioned<T>(eigen: ([T; 3], [Vector<T>; 3])) -> Option<([T; 3], [Vector<T>; 3])>
+where
+    T: Real,
+{
+    let (values, _) = eigen;
+    if values[2] <= values[0] * T::epsilon().sqrt() {
+        None
+    } else {
+        Some(eigen)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::solve_ax_xb;
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn hand_eye_solution_is_least_squares_and_rejects_parallel_axes() {
+        let camera = Pose::new(
+            Vector::new(-0.1_f64, 0.03, 0.2),
+            Quaternion::from_angle_axis(2.7, Vector::new(-0.3, 1.0, 0.4)),
+        );
+        let flange = |index: usize| {
+            let x = index as f64;
+            Pose::new(
+                Vector::new(0.5 + 0.1 * x.sin(), 0.2 * x.cos(), 0.3 + 0.05 * x),
+                Quaternion::from_angle_axis(0.3 * x, Vector::new(x.cos(), x.sin(), 1.0)),
+            )
+        };
+        let mut pairs = [(Pose::identity(), Pose::identity()); 8];
+        for (index, pair) in pairs.iter_mut().enumerate() {
+            let motion = flange(index).inverse() * flange(index + 1);
+            let noise = Pose::exp(crate::twist::Twist::new(
+                Vector::new(1.0, -2.0, 0.5) * (1e-5 * (index as f64).sin()),
+                Vector::new(-1.0, 0.5, 2.0) * (1e-5 * (index as f64).cos()),
+            ));
+            *pair = (motion, camera.inverse() * motion * camera * noise);
+        }
+        let solution = solve_ax_xb(&pairs).unwrap();
+        let (translation, rotation) = solution.distance_to(camera);
+        assert!(translation < 1e-3 && rotation < 1e-3);
+        let exact = pairs.map(|(motion, _)| (motion, camera.inverse() * motion * camera));
+        let solution = solve_ax_xb(&exact).unwrap();
+        let (translation, rotation) = solution.distance_to(camera);
+        assert!(translation < 1e-9 && rotation < 1e-9);
+        let turn = |angle: f64| {
+            let motion = Pose::new(
+                Vector::new(angle, 0.0, 0.0),
+                Quaternion::from_angle_axis(angle, Vector::unit_z()),
+            );
+            (motion, camera.inverse() * motion * camera)
+        };
+        assert_eq!(solve_ax_xb(&[turn(0.3), turn(-0.7), turn(1.1)]), None);
+        assert_eq!(solve_ax_xb(&exact[..1]), None);
+    }
+}
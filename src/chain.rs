@@ -0,0 +1,222 @@
+use crate::error::FrameError;
+use crate::pose::Pose;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Neg, Sub};
+use num::{One, Zero};
+
+/// Tree of named frames, every non-root frame stores its pose relative to the parent frame.
+///
+/// Frames referenced as parents become roots until they are attached themselves, so several
+/// disconnected trees may coexist. Attaching frame never creates cycles.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::chain::FrameTree;
+/// # use core::f64::consts::FRAC_PI_2;
+/// let mut tree = FrameTree::new();
+/// let turn = Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z());
+/// tree.insert("base", "world", Pose::new(Vector::new(1.0, 0.0, 0.0), turn)).unwrap();
+/// tree.insert("tool", "base", Pose::new(Vector::new(0.0, 0.0, 0.5), Quaternion::identity())).unwrap();
+/// tree.insert("camera", "world", Pose::new(Vector::new(0.0, 2.0, 0.0), turn)).unwrap();
+/// let tool = tree.pose_of(&"tool", &"world").unwrap();
+/// assert!((tool.translation() - Vector::new(1.0, 0.0, 0.5)).norm() < 1e-12);
+/// let seen = tree.pose_of(&"tool", &"camera").unwrap();
+/// assert!((seen.translation() - Vector::new(-2.0, -1.0, 0.5)).norm() < 1e-12);
+/// ```
+#[derive(Clone, Debug)]
+pub struct FrameTree<K, T, R> {
+    frames: BTreeMap<K, Option<Link<K, T, R>>>,
+}
+
+/// Parent of a non-root frame and pose of the frame relative to it.
+#[derive(Clone, Debug)]
+struct Link<K, T, R> {
+    parent: K,
+    pose: Pose<T, R>,
+}
+
+impl<K: Ord, T, R> FrameTree<K, T, R> {
+    /// Create empty tree.
+    pub fn new() -> Self {
+        Self {
+            frames: BTreeMap::new(),
+        }
+    }
+
+    /// Get number of frames including roots.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Check whether there are no frames.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Check whether frame is in the tree.
+    pub fn contains(&self, frame: &K) -> bool {
+        self.frames.contains_key(frame)
+    }
+
+    /// Get parent of the frame, `None` for roots and unknown frames.
+    pub fn parent(&self, frame: &K) -> Option<&K> {
+        self.frames.get(frame)?.as_ref().map(|link| &link.parent)
+    }
+}
+
+impl<K, T, R> FrameTree<K, T, R>
+where
+    K: Ord + Clone,
+    T: Copy + Zero + Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+    R: Copy
+        + One
+        + Zero
+        + Mul<Output = R>
+        + Add<Output = R>
+        + Sub<Output = R>
+        + Neg<Output = R>
+        + Mul<T, Output = T>,
+{
+    /// Add root frame without parent, does nothing if the frame already exists.
+    pub fn insert_root(&mut self, frame: K) {
+        self.frames.entry(frame).or_insert(None);
+    }
+
+    /// Attach `child` to `parent` with pose of the child relative to the parent, adding
+    /// the parent as root if it is not in the tree yet. Attaching existing frame moves it
+    /// with its whole subtree and returns its previous pose relative to the old parent.
+    /// Fails with [`FrameError::Cycle`] if the parent is the child or its descendant.
+    pub fn insert(
+        &mut self,
+        child: K,
+        parent: K,
+        pose: Pose<T, R>,
+    ) -> Result<Option<Pose<T, R>>, FrameError> {
+        if self.ancestors(&parent).any(|ancestor| *ancestor == child) {
+            return Err(FrameError::Cycle);
+        }
+        self.insert_root(parent.clone());
+        let previous = self.frames.insert(child, Some(Link { parent, pose }));
+        Ok(previous.flatten().map(|link| link.pose))
+    }
+
+    /// Replace pose of the frame relative to its parent.
+    pub fn set_pose(&mut self, frame: &K, pose: Pose<T, R>) -> Result<(), FrameError> {
+        match self.frames.get_mut(frame) {
+            Some(Some(link)) => {
+                link.pose = pose;
+                Ok(())
+            }
+            Some(None) => Err(FrameError::RootFrame),
+            None => Err(FrameError::UnknownFrame),
+        }
+    }
+
+    /// Get pose of the frame relative to its parent.
+    pub fn pose(&self, frame: &K) -> Result<Pose<T, R>, FrameError> {
+        match self.frames.get(frame) {
+            Some(Some(link)) => Ok(link.pose),
+            Some(None) => Err(FrameError::RootFrame),
+            None => Err(FrameError::UnknownFrame),
+        }
+    }
+
+    /// Calculate pose of `frame` relative to `relative_to`, i.e. transform from `frame`
+    /// coordinates into `relative_to` coordinates. Poses are composed only up to the
+    /// closest common ancestor.
+    pub fn pose_of(&self, frame: &K, relative_to: &K) -> Result<Pose<T, R>, FrameError> {
+        if !self.contains(frame) || !self.contains(relative_to) {
+            return Err(FrameError::UnknownFrame);
+        }
+        let path: Vec<&K> = self.ancestors(relative_to).collect();
+        let (common, from_frame) =
+            self.pose_to_ancestor(frame, |ancestor| path.contains(&ancestor));
+        let common = common.ok_or(FrameError::Disconnected)?;
+        let (_, from_reference) = self.pose_to_ancestor(relative_to, |ancestor| ancestor == common);
+        Ok(match (from_reference, from_frame) {
+            (Some(reference), Some(frame)) => reference.inverse() * frame,
+            (Some(reference), None) => reference.inverse(),
+            (None, Some(frame)) => frame,
+            (None, None) => Pose::identity(),
+        })
+    }
+
+    /// Iterate over the frame and its ancestors up to the root.
+    fn ancestors<'a>(&'a self, frame: &'a K) -> impl Iterator<Item = &'a K> + 'a {
+        let mut next = Some(frame);
+        core::iter::from_fn(move || {
+            let current = next?;
+            next = self.parent(current);
+            Some(current)
+        })
+    }
+
+    /// Compose poses from the frame up to the first ancestor accepted by `stop`, `None` pose
+    /// stands for identity. Returns `None` ancestor if the root is passed without stopping.
+    fn pose_to_ancestor<'a>(
+        &'a self,
+        frame: &'a K,
+        stop: impl Fn(&K) -> bool,
+    ) -> (Option<&'a K>, Option<Pose<T, R>>) {
+        let mut pose: Option<Pose<T, R>> = None;
+        for ancestor in self.ancestors(frame) {
+            if stop(ancestor) {
+                return (Some(ancestor), pose);
+            }
+            if let Some(Some(link)) = self.frames.get(ancestor) {
+                pose = Some(pose.map_or(link.pose, |pose| link.pose * pose));
+            }
+        }
+        (None, pose)
+    }
+}
+
+impl<K: Ord, T, R> Default for FrameTree<K, T, R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FrameTree;
+    use crate::error::FrameError;
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn frame_tree_composes_arm_and_rejects_cycles() {
+        let link = |angle: f64| {
+            Pose::new(
+                Vector::new(0.0, 0.0, 1.0),
+                Quaternion::from_angle_axis(angle, Vector::unit_y()),
+            )
+        };
+        let mut tree = FrameTree::new();
+        for (joint, angle) in [0.3, -0.5, 1.1, 0.2].into_iter().enumerate() {
+            assert_eq!(tree.insert(joint + 1, joint, link(angle)), Ok(None));
+        }
+        let expected = link(0.3) * link(-0.5) * link(1.1) * link(0.2);
+        let tip = tree.pose_of(&4, &0).unwrap();
+        assert!((tip.translation() - expected.translation()).norm() < 1e-12);
+        let between = tree.pose_of(&1, &3).unwrap();
+        let expected = (link(-0.5) * link(1.1)).inverse();
+        assert!((between.translation() - expected.translation()).norm() < 1e-12);
+        assert!((between.rotation().dot(expected.rotation()) - 1.0).abs() < 1e-12);
+        let same = tree.pose_of(&2, &2).unwrap();
+        assert!(same.translation().norm() < 1e-12);
+        assert_eq!(tree.insert(1, 3, link(0.0)), Err(FrameError::Cycle));
+        assert_eq!(tree.insert(2, 2, link(0.0)), Err(FrameError::Cycle));
+        assert_eq!(tree.insert(3, 1, link(0.0)), Ok(Some(link(1.1))));
+        assert_eq!(tree.parent(&3), Some(&1));
+        tree.insert_root(10);
+        assert_eq!(tree.pose_of(&4, &10), Err(FrameError::Disconnected));
+        assert_eq!(tree.pose_of(&4, &11), Err(FrameError::UnknownFrame));
+        assert_eq!(tree.set_pose(&0, link(0.0)), Err(FrameError::RootFrame));
+        assert_eq!(tree.set_pose(&4, link(0.0)), Ok(()));
+        assert_eq!(tree.len(), 6);
+    }
+}
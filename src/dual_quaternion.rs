@@ -0,0 +1,280 @@
+#[cfg(feature = "num")]
+use crate::ops::{IntoProgress, Norm, Real};
+#[cfg(feature = "num")]
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+#[cfg(feature = "num")]
+use crate::vector::Vector;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Dual quaternion `real + dual * e` with `e^2 = 0`.
+///
+/// Unit dual quaternions represent poses, the real part is the rotation and the dual part is
+/// `translation * rotation / 2` with translation as pure quaternion. Multiplication composes
+/// poses in the same order as [`Pose`](crate::pose::Pose) multiplication.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct DualQuaternion<T> {
+    real: Quaternion<T>,
+    dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T> {
+    /// Create dual quaternion from real and dual parts.
+    pub const fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        Self { real, dual }
+    }
+
+    /// Get reference to the real part.
+    pub fn real_ref(&self) -> &Quaternion<T> {
+        &self.real
+    }
+
+    /// Get reference to the dual part.
+    pub fn dual_ref(&self) -> &Quaternion<T> {
+        &self.dual
+    }
+}
+
+/// Implement constant dual quaternions for given floating point type.
+macro_rules! impl_dual_quaternion_constants {
+    ($($float:ty),+) => {$(
+        impl DualQuaternion<$float> {
+            /// Dual quaternion representing no translation and no rotation.
+            pub const IDENTITY: Self = Self::new(
+                Quaternion::<$float>::IDENTITY,
+                Quaternion::with_wijk(0.0, 0.0, 0.0, 0.0),
+            );
+        }
+    )+};
+}
+
+impl_dual_quaternion_constants!(f32, f64);
+
+impl<T> DualQuaternion<T>
+where
+    T: Copy,
+{
+    /// Get real part, the rotation of unit dual quaternion.
+    pub fn real(&self) -> Quaternion<T> {
+        self.real
+    }
+
+    /// Get dual part.
+    pub fn dual(&self) -> Quaternion<T> {
+        self.dual
+    }
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    /// Conjugate both parts as quaternions, which is the inverse of unit dual quaternion.
+    pub fn conjugate(self) -> Self {
+        Self {
+            real: self.real.inverse(),
+            dual: self.dual.inverse(),
+        }
+    }
+
+    /// Negate the dual part.
+    pub fn dual_conjugate(self) -> Self {
+        Self {
+            real: self.real,
+            dual: -self.dual,
+        }
+    }
+
+    /// Apply both quaternion and dual conjugates, used to transform points as
+    /// `q * (1 + p e) * q.combined_conjugate()`.
+    pub fn combined_conjugate(self) -> Self {
+        self.conjugate().dual_conjugate()
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> DualQuaternion<T>
+where
+    T: Real,
+{
+    /// Dual quaternion representing identity pose.
+    pub fn identity() -> Self {
+        Self {
+            real: Quaternion::identity(),
+            dual: Quaternion::with_wijk(T::zero(), T::zero(), T::zero(), T::zero()),
+        }
+    }
+
+    /// Create unit dual quaternion representing the pose, rotation must be unit.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::dual_quaternion::DualQuaternion;
+    /// let first = Pose::new(Vector::new(1.0_f64, 2.0, 3.0), Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5));
+    /// let second = Pose::new(Vector::new(-1.0, 0.0, 4.0), Quaternion::with_wijk(0.0, 0.0, 1.0, 0.0));
+    /// let product = DualQuaternion::from_pose(first) * DualQuaternion::from_pose(second);
+    /// let expected = first * second;
+    /// let result = product.into_pose();
+    /// assert!((result.translation() - expected.translation()).norm() < 1e-12);
+    /// assert!((result.rotation().dot(expected.rotation()) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_pose(pose: Pose<T, T>) -> Self {
+        let half = T::one() / (T::one() + T::one());
+        let translation = pose.translation() * half;
+        let pure =
+            Quaternion::with_wijk(T::zero(), translation.x(), translation.y(), translation.z());
+        Self {
+            real: pose.rotation(),
+            dual: pure * pose.rotation(),
+        }
+    }
+
+    /// Convert unit dual quaternion into pose, see [`DualQuaternion::normalize`].
+    pub fn into_pose(self) -> Pose<T, T> {
+        let translation = self.dual * self.real.inverse();
+        let two = T::one() + T::one();
+        Pose::new(
+            Vector::new(translation.i(), translation.j(), translation.k()) * two,
+            self.real,
+        )
+    }
+
+    /// Transform point by the pose of unit dual quaternion.
+    pub fn apply_to(self, point: Vector<T>) -> Vector<T> {
+        self.into_pose().apply_to(point)
+    }
+
+    /// Scale to unit norm and remove the component of the dual part violating the
+    /// orthogonality constraint of unit dual quaternions, `None` if the real part norm
+    /// is below epsilon.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::dual_quaternion::DualQuaternion;
+    /// let drifted = DualQuaternion::new(
+    ///     Quaternion::with_wijk(2.0_f64, 0.0, 0.0, 0.0),
+    ///     Quaternion::with_wijk(0.3, 1.0, 0.0, 0.0),
+    /// );
+    /// let unit = drifted.normalize().unwrap();
+    /// assert_eq!(unit.real(), Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0));
+    /// assert_eq!(unit.dual(), Quaternion::with_wijk(0.0, 0.5, 0.0, 0.0));
+    /// ```
+    pub fn normalize(self) -> Option<Self> {
+        let norm = Norm::norm(self.real);
+        if norm < T::epsilon() {
+            return None;
+        }
+        let scale = T::one() / norm;
+        let (real, dual) = (self.real * scale, self.dual * scale);
+        Some(Self {
+            real,
+            dual: dual + -(real * real.dot(dual)),
+        })
+    }
+
+    /// Screw linear interpolation between this and other unit dual quaternion, moves with
+    /// constant twist along the shortest screw motion, see [`Pose::interpolate_screw`].
+    /// `progress` is either plain value or [`Progress`](crate::ops::Progress).
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::dual_quaternion::DualQuaternion;
+    /// # use core::f64::consts::PI;
+    /// let start = DualQuaternion::identity();
+    /// let end = DualQuaternion::from_pose(Pose::new(
+    ///     Vector::new(0.0, 2.0, 4.0),
+    ///     Quaternion::from_angle_axis(PI, Vector::unit_z()),
+    /// ));
+    /// let middle = start.sclerp(end, 0.5).into_pose();
+    /// assert!((middle.translation() - Vector::new(1.0, 1.0, 2.0)).norm() < 1e-12);
+    /// ```
+    pub fn sclerp<P: IntoProgress<T>>(self, other: Self, progress: P) -> Self {
+        Self::from_pose(
+            self.into_pose()
+                .interpolate_screw(other.into_pose(), progress),
+        )
+    }
+}
+
+impl<T> Mul for DualQuaternion<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            real: self.real * other.real,
+            dual: self.real * other.dual + self.dual * other.real,
+        }
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T: Real> From<Pose<T, T>> for DualQuaternion<T> {
+    fn from(pose: Pose<T, T>) -> Self {
+        Self::from_pose(pose)
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T: Real> From<DualQuaternion<T>> for Pose<T, T> {
+    fn from(dual: DualQuaternion<T>) -> Self {
+        dual.into_pose()
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod test {
+    use super::DualQuaternion;
+    use crate::pose::Pose;
+    use crate::quaternion::{Quaternion, UnitQuaternion};
+    use crate::twist::Twist;
+    use crate::vector::Vector;
+    use crate::wrench::Wrench;
+
+    #[test]
+    fn sclerp_follows_constant_screw_motion() {
+        let start = Pose::new(
+            Vector::new(1.0_f64, -2.0, 0.5),
+            Quaternion::from_angle_axis(0.4, Vector::new(1.0, 1.0, 0.0)),
+        );
+        let end = Pose::new(
+            Vector::new(3.0, 1.0, -1.0),
+            Quaternion::from_angle_axis(2.0, Vector::new(0.0, -1.0, 2.0)),
+        );
+        let (first, second) = (DualQuaternion::from(start), DualQuaternion::from(end));
+        let flipped = DualQuaternion::new(-second.real(), -second.dual());
+        let point = Vector::new(0.3, 0.2, -0.1);
+        assert!((first.apply_to(point) - start.apply_to(point)).norm() < 1e-12);
+        let twist = (start.inverse() * end).log();
+        for progress in [0.0, 0.25, 0.6, 1.0] {
+            let expected = start * Pose::exp(twist * progress);
+            for other in [second, flipped] {
+                let result = Pose::from(first.sclerp(other, progress));
+                assert!((result.translation() - expected.translation()).norm() < 1e-12);
+                assert!((result.rotation().dot(expected.rotation()).abs() - 1.0).abs() < 1e-12);
+            }
+        }
+        let inverse = Pose::from(first.conjugate() * first);
+        assert!(inverse.translation().norm() < 1e-12);
+        assert!((inverse.rotation().w() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn constants_match_runtime_identities() {
+        static MOUNT: DualQuaternion<f32> = DualQuaternion::<f32>::IDENTITY;
+        assert_eq!(MOUNT, DualQuaternion::identity());
+        assert_eq!(UnitQuaternion::<f64>::IDENTITY, UnitQuaternion::identity());
+        assert_eq!(
+            Twist::<f64, f64>::ZERO,
+            Twist::new(Vector::zero(), Vector::zero())
+        );
+        assert_eq!(
+            Wrench::<f32, f32>::ZERO,
+            Wrench::new(Vector::zero(), Vector::zero())
+        );
+    }
+}
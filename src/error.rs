@@ -0,0 +1,261 @@
+use core::fmt::{Debug, Display, Formatter};
+
+/// Error of conversion from a slice with unexpected number of elements.
+/// ```
+/// # use spatial::error::ConversionError;
+/// # use spatial::vector::Vector;
+/// let packet = [1.0, 2.0, 3.0, 4.0];
+/// let vector = Vector::try_from(&packet[..3]).unwrap();
+/// assert_eq!(vector, Vector::new(1.0, 2.0, 3.0));
+/// let error = Vector::<f64>::try_from(&packet[..]).unwrap_err();
+/// assert_eq!(error, ConversionError::TooLong { expected: 3, actual: 4 });
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConversionError {
+    /// Slice has fewer elements than required.
+    TooShort { expected: usize, actual: usize },
+    /// Slice has more elements than required.
+    TooLong { expected: usize, actual: usize },
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooShort { expected, actual } => {
+                write!(formatter, "expected {expected} elements, got only {actual}")
+            }
+            Self::TooLong { expected, actual } => {
+                write!(formatter, "expected {expected} elements, got {actual}")
+            }
+        }
+    }
+}
+
+/// Error of normalizing vector or quaternion whose norm is below epsilon.
+/// ```
+/// # use spatial::error::ZeroNormError;
+/// # use spatial::vector::Vector;
+/// let error = Vector::new(0.0_f64, 1e-20, 0.0).try_normalized().unwrap_err();
+/// assert_eq!(error, ZeroNormError { norm: 1e-20 });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ZeroNormError<T> {
+    /// Norm of the value.
+    pub norm: T,
+}
+
+impl<T: Display> Display for ZeroNormError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "norm {} is too close to zero to normalize",
+            self.norm
+        )
+    }
+}
+
+/// Error of validating externally supplied components of a rotation or pose.
+/// ```
+/// # use spatial::error::ValidationError;
+/// # use spatial::quaternion::Quaternion;
+/// # #[cfg(feature = "num")] {
+/// let parsed = Quaternion::try_from_components(1.0, 0.0, f64::NAN, 0.0);
+/// assert_eq!(parsed, Err(ValidationError::NonFinite { component: 2 }));
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValidationError<T> {
+    /// Some component is infinite or `NaN`, carries index of the first such component in the
+    /// `[w, i, j, k]` order of quaternions or `[x, y, z, w, i, j, k]` order of poses.
+    NonFinite { component: usize },
+    /// Quaternion norm is too close to zero to represent a rotation.
+    ZeroNorm { norm: T },
+    /// Rotation quaternion norm differs from one by more than the tolerance.
+    NotNormalized { norm: T },
+}
+
+impl<T: Display> Display for ValidationError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NonFinite { component } => {
+                write!(formatter, "component {component} is infinite or NaN")
+            }
+            Self::ZeroNorm { norm } => {
+                write!(
+                    formatter,
+                    "norm {norm} is too close to zero to be a rotation"
+                )
+            }
+            Self::NotNormalized { norm } => {
+                write!(
+                    formatter,
+                    "rotation is not a unit quaternion, norm is {norm}"
+                )
+            }
+        }
+    }
+}
+
+/// Error of converting matrix or basis which is not orthonormal with positive determinant
+/// into rotation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonOrthonormalError<T> {
+    /// Largest absolute element of `matrix^T * matrix - I`.
+    pub residual: T,
+    /// Determinant of the matrix.
+    pub determinant: T,
+}
+
+impl<T: Display> Display for NonOrthonormalError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "matrix is not a proper orthonormal rotation, orthonormality residual is {} and \
+             determinant is {}",
+            self.residual, self.determinant
+        )
+    }
+}
+
+/// Warning of Euler angle conversion close to gimbal lock, where roll and yaw are no longer
+/// independent. Carries angles computed anyway, with the whole heading assigned to yaw.
+/// ```
+/// # use spatial::quaternion::Quatd;
+/// # use core::f64::consts::FRAC_PI_2;
+/// let warning = Quatd::from_rpy(0.2, FRAC_PI_2, 0.5).into_rpy_checked().unwrap_err();
+/// assert!(warning.roll.abs() < 1e-6 && (warning.yaw - 0.3).abs() < 1e-6);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GimbalLockWarning<T> {
+    /// Roll angle, zero at gimbal lock.
+    pub roll: T,
+    /// Pitch angle, close to plus or minus quarter turn.
+    pub pitch: T,
+    /// Yaw angle absorbing the roll.
+    pub yaw: T,
+}
+
+impl<T: Display> Display for GimbalLockWarning<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "pitch {} is close to gimbal lock, roll {} and yaw {} are ambiguous",
+            self.pitch, self.roll, self.yaw
+        )
+    }
+}
+
+/// Error of iterative solver which has used up its iteration budget before converging.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConvergenceError<T> {
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Remaining error of the last accepted solution.
+    pub error: T,
+}
+
+impl<T: Display> Display for ConvergenceError<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "no convergence after {} iterations, error is still {}",
+            self.iterations, self.error
+        )
+    }
+}
+
+/// Error of frame tree operation, see [`FrameTree`](crate::chain::FrameTree).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FrameError {
+    /// Frame is not in the tree.
+    UnknownFrame,
+    /// Frame is a root and has no pose relative to a parent.
+    RootFrame,
+    /// Attaching frame to the parent would make it its own ancestor.
+    Cycle,
+    /// Frames belong to different trees.
+    Disconnected,
+}
+
+impl Display for FrameError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownFrame => write!(formatter, "frame is not in the tree"),
+            Self::RootFrame => write!(formatter, "root frame has no parent"),
+            Self::Cycle => write!(formatter, "frame would become its own ancestor"),
+            Self::Disconnected => write!(formatter, "frames have no common ancestor"),
+        }
+    }
+}
+
+/// Error of parsing robot description, see [`urdf`](crate::urdf).
+#[cfg(feature = "urdf")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UrdfError {
+    /// Tag or attribute is not terminated or not quoted.
+    Malformed,
+    /// Fragment contains no `<origin>` element.
+    MissingOrigin,
+    /// `xyz` or `rpy` attribute is not three numbers.
+    InvalidTriple,
+    /// Joint has no name, parent link or child link.
+    IncompleteJoint,
+    /// Joints do not form a tree of links.
+    Frame(FrameError),
+}
+
+#[cfg(feature = "urdf")]
+impl From<FrameError> for UrdfError {
+    fn from(error: FrameError) -> Self {
+        Self::Frame(error)
+    }
+}
+
+#[cfg(feature = "urdf")]
+impl Display for UrdfError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Malformed => write!(formatter, "tag or attribute is malformed"),
+            Self::MissingOrigin => write!(formatter, "origin element is missing"),
+            Self::InvalidTriple => write!(formatter, "expected three numbers"),
+            Self::IncompleteJoint => write!(formatter, "joint lacks name, parent or child"),
+            Self::Frame(error) => write!(formatter, "joints do not form a tree: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+#[cfg(feature = "std")]
+impl<T: Debug + Display> std::error::Error for ZeroNormError<T> {}
+
+#[cfg(feature = "std")]
+impl<T: Debug + Display> std::error::Error for ValidationError<T> {}
+
+#[cfg(feature = "std")]
+impl<T: Debug + Display> std::error::Error for NonOrthonormalError<T> {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FrameError {}
+
+#[cfg(feature = "urdf")]
+impl std::error::Error for UrdfError {}
+
+#[cfg(feature = "std")]
+impl<T: Debug + Display> std::error::Error for GimbalLockWarning<T> {}
+
+#[cfg(feature = "std")]
+impl<T: Debug + Display> std::error::Error for ConvergenceError<T> {}
+
+/// Copy slice into array of exactly `N` elements.
+pub(crate) fn to_array<T: Copy, const N: usize>(slice: &[T]) -> Result<[T; N], ConversionError> {
+    let (expected, actual) = (N, slice.len());
+    <[T; N]>::try_from(slice).map_err(|_| {
+        if actual < expected {
+            ConversionError::TooShort { expected, actual }
+        } else {
+            ConversionError::TooLong { expected, actual }
+        }
+    })
+}
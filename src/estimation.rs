@@ -0,0 +1,469 @@
+use crate::ops::{Epsilon, Real};
+use crate::pose::Pose;
+use crate::quaternion::{skew, Quaternion};
+use crate::vector::Vector;
+use core::ops::Mul;
+use num::{Float, One, Zero};
+
+/// Error-state extended Kalman filter for orientation with gyroscope bias estimation.
+///
+/// Nominal state consists of rotation from the sensor frame into the world frame and gyroscope
+/// bias, uncertainty is tracked by the covariance of the six-dimensional error state: rotation
+/// error vector in the sensor frame followed by bias error. Prediction integrates bias-corrected
+/// gyroscope rates with [`Quaternion::exp`], updates use unit direction measurements of known
+/// world reference directions, such as gravity or magnetic field.
+///
+/// Gyroscope noise density is in radians per second per square root of hertz, bias random walk
+/// density is in radians per second squared per square root of hertz, direction measurement
+/// noise is the standard deviation of the unit direction components.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::estimation::OrientationEkf;
+/// let accel = Vector::new(0.0, 0.3_f64.sin(), 0.3_f64.cos());
+/// let bias = Vector::new(0.02, -0.01, 0.0);
+/// let mut filter = OrientationEkf::new(1e-3, 1e-4, 1.0, 0.1);
+/// for _ in 0..5000 {
+///     filter.predict(bias, 0.01);
+///     filter.update_gravity(accel, 0.01);
+/// }
+/// let gravity = filter.rotation().inverse().rotate(Vector::<f64>::unit_z());
+/// assert!((gravity - accel).norm() < 1e-3);
+/// assert!((filter.bias().x() - bias.x()).abs() < 1e-3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrientationEkf<T> {
+    rotation: Quaternion<T>,
+    bias: Vector<T>,
+    covariance: [[T; 6]; 6],
+    gyro_noise: T,
+    bias_noise: T,
+}
+
+impl<T> OrientationEkf<T>
+where
+    T: Float + Real,
+{
+    /// Create new filter with given gyroscope noise and bias random walk densities, starting at
+    /// identity rotation and zero bias with given standard deviations of rotation error in
+    /// radians and bias error in radians per second.
+    pub fn new(gyro_noise: T, bias_noise: T, rotation_std: T, bias_std: T) -> Self {
+        let mut covariance = [[T::zero(); 6]; 6];
+        for index in 0..3 {
+            covariance[index][index] = rotation_std * rotation_std;
+            covariance[index + 3][index + 3] = bias_std * bias_std;
+        }
+        Self {
+            rotation: Quaternion::identity(),
+            bias: Vector::zero(),
+            covariance,
+            gyro_noise,
+            bias_noise,
+        }
+    }
+
+    /// Get current rotation estimate.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.rotation
+    }
+
+    /// Get current gyroscope bias estimate in radians per second.
+    pub fn bias(&self) -> Vector<T> {
+        self.bias
+    }
+
+    /// Get covariance of the error state, rotation error followed by bias error.
+    pub fn covariance(&self) -> [[T; 6]; 6] {
+        self.covariance
+    }
+
+    /// Replace rotation estimate keeping its uncertainty, e.g. after initialization
+    /// from a static accelerometer sample.
+    pub fn set_rotation(&mut self, rotation: Quaternion<T>) {
+        self.rotation = rotation;
+    }
+
+    /// Propagate state with gyroscope sample taken `dt` after the previous one.
+    pub fn predict(&mut self, gyro: Vector<T>, dt: T) {
+        if dt.is_nan() || dt <= T::zero() {
+            return;
+        }
+        let step = (gyro - self.bias) * dt;
+        self.rotation *= Quaternion::exp(step);
+        let inverse = Quaternion::exp(-step).prepare().matrix();
+        let mut transition = identity::<T, 6>();
+        for row in 0..3 {
+            for column in 0..3 {
+                transition[row][column] = inverse[row][column];
+            }
+            transition[row][row + 3] = -dt;
+        }
+        let mut covariance = multiply(
+            &multiply(&transition, &self.covariance),
+            &transpose(&transition),
+        );
+        for index in 0..3 {
+            covariance[index][index] =
+                covariance[index][index] + self.gyro_noise * self.gyro_noise * dt;
+            covariance[index + 3][index + 3] =
+                covariance[index + 3][index + 3] + self.bias_noise * self.bias_noise * dt;
+        }
+        self.covariance = covariance;
+    }
+
+    /// Correct state with measured sensor-frame direction of known world reference direction,
+    /// returns `false` if either direction is zero or the update is degenerate.
+    pub fn update_direction(
+        &mut self,
+        measured: Vector<T>,
+        reference: Vector<T>,
+        noise: T,
+    ) -> bool {
+        let (measured, reference) = match (
+            measured.normalized_checked(),
+            reference.normalized_checked(),
+        ) {
+            (Some(measured), Some(reference)) => (measured, reference),
+            _ => return false,
+        };
+        let predicted = self.rotation.inverse().rotate(reference);
+        let skew = skew(predicted);
+        let mut observation = [[T::zero(); 6]; 3];
+        for row in 0..3 {
+            for column in 0..3 {
+                observation[row][column] = skew[row][column];
+            }
+        }
+        let cross = multiply(&self.covariance, &transpose(&observation));
+        let mut innovation_covariance = multiply(&observation, &cross);
+        for (index, row) in innovation_covariance.iter_mut().enumerate() {
+            row[index] = row[index] + noise * noise;
+        }
+        let inverse = match invert(&innovation_covariance) {
+            Some(inverse) => inverse,
+            None => return false,
+        };
+        let gain = multiply(&cross, &inverse);
+        let residual = measured - predicted;
+        let residual = [[residual.x()], [residual.y()], [residual.z()]];
+        let correction = multiply(&gain, &residual);
+        let rotation = Vector::new(correction[0][0], correction[1][0], correction[2][0]);
+        let bias = Vector::new(correction[3][0], correction[4][0], correction[5][0]);
+        self.rotation *= Quaternion::exp(rotation);
+        self.bias = self.bias + bias;
+        let mut reduction = identity::<T, 6>();
+        let product = multiply(&gain, &observation);
+        for row in 0..6 {
+            for column in 0..6 {
+                reduction[row][column] = reduction[row][column] - product[row][column];
+            }
+        }
+        let covariance = multiply(&reduction, &self.covariance);
+        let transposed = transpose(&covariance);
+        let half = T::one() / (T::one() + T::one());
+        for (row, values) in self.covariance.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = (covariance[row][column] + transposed[row][column]) * half;
+            }
+        }
+        true
+    }
+
+    /// Correct state with accelerometer sample of a sensor at rest or moving with constant
+    /// velocity, see [`OrientationEkf::update_direction`].
+    pub fn update_gravity(&mut self, accel: Vector<T>, noise: T) -> bool {
+        self.update_direction(accel, Vector::unit_z(), noise)
+    }
+}
+
+/// Pose estimate with covariance of its six-dimensional body-frame error.
+///
+/// The true pose is `pose * Pose::exp(error)` with error twist stacked as
+/// `[linear, angular]` like [`Pose::adjoint`]. Combining and inverting propagate the
+/// covariance to first order, errors of combined poses are assumed independent.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::estimation::PoseWithCovariance;
+/// # use core::f64::consts::FRAC_PI_2;
+/// let step = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()));
+/// let odometry = PoseWithCovariance::from_std(step, 0.01, 0.001);
+/// let twice = odometry * odometry;
+/// assert!((twice.pose().translation() - Vector::new(1.0, 1.0, 0.0)).norm() < 1e-12);
+/// let covariance = twice.covariance();
+/// // Heading error of the first step moves the end along its x axis, not along y.
+/// assert!(covariance[0][0] > covariance[1][1]);
+/// assert!((covariance[5][5] - 2e-6).abs() < 1e-15);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoseWithCovariance<T, R> {
+    pose: Pose<T, R>,
+    covariance: [[T; 6]; 6],
+}
+
+impl<T, R> PoseWithCovariance<T, R> {
+    /// Create estimate from pose and covariance of its body-frame error.
+    pub const fn new(pose: Pose<T, R>, covariance: [[T; 6]; 6]) -> Self {
+        Self { pose, covariance }
+    }
+}
+
+impl<T, R> PoseWithCovariance<T, R>
+where
+    T: Copy,
+    R: Copy,
+{
+    /// Get estimated pose.
+    pub fn pose(&self) -> Pose<T, R> {
+        self.pose
+    }
+
+    /// Get covariance of the body-frame error, linear error followed by angular error.
+    pub fn covariance(&self) -> [[T; 6]; 6] {
+        self.covariance
+    }
+}
+
+impl<T> PoseWithCovariance<T, T>
+where
+    T: Float + Real,
+{
+    /// Create estimate with independent errors of given standard deviations of every
+    /// translation component and every rotation error component in radians.
+    pub fn from_std(pose: Pose<T, T>, translation_std: T, rotation_std: T) -> Self {
+        let mut covariance = [[T::zero(); 6]; 6];
+        for index in 0..3 {
+            covariance[index][index] = translation_std * translation_std;
+            covariance[index + 3][index + 3] = rotation_std * rotation_std;
+        }
+        Self { pose, covariance }
+    }
+
+    /// Create estimate of exactly known pose.
+    pub fn exact(pose: Pose<T, T>) -> Self {
+        Self::from_std(pose, T::zero(), T::zero())
+    }
+
+    /// Combine this estimate with the estimate of other pose relative to this one,
+    /// see [`Pose::combine`].
+    pub fn combine(self, other: Self) -> Self {
+        let mapping = other.pose.inverse().adjoint();
+        let mut covariance = multiply(&multiply(&mapping, &self.covariance), &transpose(&mapping));
+        for (row, values) in covariance.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = *value + other.covariance[row][column];
+            }
+        }
+        Self {
+            pose: self.pose * other.pose,
+            covariance,
+        }
+    }
+
+    /// Invert the estimate, see [`Pose::inverse`].
+    pub fn inverse(self) -> Self {
+        let mapping = self.pose.adjoint();
+        Self {
+            pose: self.pose.inverse(),
+            covariance: multiply(&multiply(&mapping, &self.covariance), &transpose(&mapping)),
+        }
+    }
+}
+
+impl<T> Mul for PoseWithCovariance<T, T>
+where
+    T: Float + Real,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        self.combine(other)
+    }
+}
+
+/// Create identity matrix.
+pub(crate) fn identity<T, const N: usize>() -> [[T; N]; N]
+where
+    T: Copy + Zero + One,
+{
+    let mut result = [[T::zero(); N]; N];
+    for (index, row) in result.iter_mut().enumerate() {
+        row[index] = T::one();
+    }
+    result
+}
+
+fn transpose<T, const N: usize, const M: usize>(matrix: &[[T; M]; N]) -> [[T; N]; M]
+where
+    T: Float,
+{
+    let mut result = [[T::zero(); N]; M];
+    for (row, values) in matrix.iter().enumerate() {
+        for (column, value) in values.iter().enumerate() {
+            result[column][row] = *value;
+        }
+    }
+    result
+}
+
+/// Multiply matrices.
+pub(crate) fn multiply<T, const N: usize, const M: usize, const P: usize>(
+    left: &[[T; M]; N],
+    right: &[[T; P]; M],
+) -> [[T; P]; N]
+where
+    T: Copy + Zero + Mul<Output = T>,
+{
+    let mut result = [[T::zero(); P]; N];
+    for (row, values) in left.iter().enumerate() {
+        for (column, cell) in result[row].iter_mut().enumerate() {
+            *cell = values
+                .iter()
+                .zip(right.iter())
+                .fold(T::zero(), |sum, (value, other)| {
+                    sum + *value * other[column]
+                });
+        }
+    }
+    result
+}
+
+/// Invert 3x3 matrix by adjugate, `None` if it is singular.
+fn invert<T>(matrix: &[[T; 3]; 3]) -> Option<[[T; 3]; 3]>
+where
+    T: Float + Epsilon,
+{
+    let m = matrix;
+    let cofactor = |row: usize, column: usize| {
+        let (r1, r2) = ((row + 1) % 3, (row + 2) % 3);
+        let (c1, c2) = ((column + 1) % 3, (column + 2) % 3);
+        m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+    };
+    let determinant =
+        m[0][0] * cofactor(0, 0) + m[0][1] * cofactor(0, 1) + m[0][2] * cofactor(0, 2);
+    if determinant.abs() < <T as Epsilon>::epsilon() {
+        return None;
+    }
+    let mut result = [[T::zero(); 3]; 3];
+    for (row, values) in result.iter_mut().enumerate() {
+        for (column, value) in values.iter_mut().enumerate() {
+            *value = cofactor(column, row) / determinant;
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{OrientationEkf, PoseWithCovariance};
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::twist::Twist;
+    use crate::vector::Vector;
+
+    #[test]
+    fn gravity_and_magnetic_updates_estimate_full_bias() {
+        let truth = Quaternion::from_angle_axis(0.7_f64, Vector::new(1.0, -2.0, 0.5));
+        let inverse = truth.inverse();
+        let north = Vector::new(20.0, 0.0, -45.0);
+        let accel = inverse.rotate(Vector::new(0.0, 0.0, 9.81));
+        let magnetic = inverse.rotate(north);
+        let bias = Vector::new(0.01, -0.02, 0.03);
+        let mut filter = OrientationEkf::new(1e-3, 1e-5, 2.0, 0.1);
+        for _ in 0..10000 {
+            filter.predict(bias, 0.01);
+            assert!(filter.update_gravity(accel, 0.02));
+            assert!(filter.update_direction(magnetic, north, 0.05));
+        }
+        assert!(filter.rotation().dot(truth).abs() > 1.0 - 1e-6);
+        assert!((filter.bias() - bias).norm() < 1e-4);
+        let covariance = filter.covariance();
+        assert!((0..6).all(|index| covariance[index][index] > 0.0));
+        assert!(!filter.update_gravity(Vector::zero(), 0.02));
+    }
+
+    /// Derivative of `error(exp(delta e_i))` by `delta` for every error basis vector.
+    fn jacobian(error: impl Fn(Pose<f64, f64>) -> Twist<f64, f64>) -> [[f64; 6]; 6] {
+        let delta = 1e-6;
+        let mut result = [[0.0; 6]; 6];
+        for column in 0..6 {
+            let mut perturbation = [0.0; 6];
+            perturbation[column] = delta;
+            let twist = Twist::new(
+                Vector::new(perturbation[0], perturbation[1], perturbation[2]),
+                Vector::new(perturbation[3], perturbation[4], perturbation[5]),
+            );
+            let error = error(Pose::exp(twist));
+            let values = [error.linear(), error.angular()].map(<[f64; 3]>::from);
+            for row in 0..6 {
+                result[row][column] = values[row / 3][row % 3] / delta;
+            }
+        }
+        result
+    }
+
+    /// Calculate `J * covariance * J^T`.
+    fn propagate(jacobian: &[[f64; 6]; 6], covariance: &[[f64; 6]; 6]) -> [[f64; 6]; 6] {
+        let mut result = [[0.0; 6]; 6];
+        for (row, values) in result.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                for a in 0..6 {
+                    for b in 0..6 {
+                        *value += jacobian[row][a] * covariance[a][b] * jacobian[column][b];
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn assert_close(actual: [[f64; 6]; 6], expected: [[f64; 6]; 6]) {
+        for (actual, expected) in actual.iter().flatten().zip(expected.iter().flatten()) {
+            assert!((actual - expected).abs() < 1e-6, "{actual} != {expected}");
+        }
+    }
+
+    #[test]
+    fn covariance_propagation_matches_numeric_jacobians() {
+        let first = Pose::new(
+            Vector::new(1.0, -2.0, 0.5),
+            Quaternion::from_angle_axis(0.7, Vector::new(1.0, 2.0, -1.0)),
+        );
+        let second = Pose::new(
+            Vector::new(-0.3, 0.8, 2.0),
+            Quaternion::from_angle_axis(-1.2, Vector::new(0.0, 1.0, 3.0)),
+        );
+        let mut covariance = [[0.0; 6]; 6];
+        for (row, values) in covariance.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = 1.0 / (1.0 + row as f64 + column as f64)
+                    + if row == column { 1.0 } else { 0.0 };
+            }
+        }
+        let estimate = PoseWithCovariance::new(first, covariance);
+        let other = PoseWithCovariance::from_std(second, 0.2, 0.1);
+        let combined = estimate * other;
+        let product = first * second;
+        let through_first = jacobian(|error| (product.inverse() * first * error * second).log());
+        let through_second = jacobian(|error| (product.inverse() * first * second * error).log());
+        let mut expected = propagate(&through_first, &covariance);
+        let second_part = propagate(&through_second, &other.covariance());
+        for (row, values) in expected.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value += second_part[row][column];
+            }
+        }
+        assert_close(combined.covariance(), expected);
+        let inverse = estimate.inverse();
+        let through_inverse = jacobian(|error| (first * (first * error).inverse()).log());
+        assert_close(
+            inverse.covariance(),
+            propagate(&through_inverse, &covariance),
+        );
+        let restored = inverse.inverse();
+        assert_close(restored.covariance(), covariance);
+        assert!((restored.pose().translation() - first.translation()).norm() < 1e-12);
+        let exact = PoseWithCovariance::exact(second);
+        assert_close((exact * exact).covariance(), [[0.0; 6]; 6]);
+    }
+}
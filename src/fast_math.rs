@@ -0,0 +1,221 @@
+//! Fast approximate `f32` math for small microcontrollers without hardware floating point
+//! division or double precision support.
+//!
+//! Square root and inverse square root start from the hardware estimate on x86 targets with
+//! SSE and from the bit-level estimate elsewhere or with the `deterministic` feature, refined
+//! by one Newton step. Their relative error is below `1e-6` with SSE and below `2e-3` otherwise.
+//!
+//! Absolute error of the trigonometric functions is below `2e-3`, precision of sine and cosine
+//! degrades for large angles.
+//!
+//! The approximations are only used where called explicitly, directly or through
+//! `normalized_fast` methods, the [`Sqrt`](crate::ops::Sqrt) and [`Trig`](crate::ops::Trig)
+//! implementations of `f32` stay precise.
+//! ```
+//! # use spatial::fast_math;
+//! let (sin, cos) = (fast_math::sin(0.5), fast_math::cos(0.5));
+//! assert!((fast_math::atan2(sin, cos) - 0.5).abs() < 2e-3);
+//! ```
+
+use core::f32::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4, PI};
+
+#[cfg(all(
+    target_arch = "x86",
+    target_feature = "sse",
+    not(feature = "deterministic")
+))]
+fn inverse_sqrt_estimate(value: f32) -> f32 {
+    use core::arch::x86::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+    // SAFETY: SSE support is guaranteed by the compile-time target feature.
+    unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(value))) }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "sse",
+    not(feature = "deterministic")
+))]
+fn inverse_sqrt_estimate(value: f32) -> f32 {
+    use core::arch::x86_64::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+    // SAFETY: SSE support is guaranteed by the compile-time target feature.
+    unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(value))) }
+}
+
+#[cfg(any(
+    not(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse"
+    )),
+    feature = "deterministic"
+))]
+fn inverse_sqrt_estimate(value: f32) -> f32 {
+    f32::from_bits(0x5f37_59df - (value.to_bits() >> 1))
+}
+
+/// Inverse square root of finite positive value, refined by one Newton step.
+pub fn inverse_sqrt(value: f32) -> f32 {
+    let estimate = inverse_sqrt_estimate(value);
+    estimate * (1.5 - 0.5 * value * estimate * estimate)
+}
+
+/// Square root, `NaN` for negative values.
+pub fn sqrt(value: f32) -> f32 {
+    if value.is_nan() || value < 0.0 {
+        return f32::NAN;
+    }
+    if value == 0.0 || value.is_infinite() {
+        return value;
+    }
+    value * inverse_sqrt(value)
+}
+
+/// Reduce angle into the `[-pi/4, pi/4]` range, returns reduced angle and quadrant.
+fn reduce(angle: f32) -> (f32, i32) {
+    let offset = if angle < 0.0 { -0.5 } else { 0.5 };
+    let quadrant = (angle * FRAC_2_PI + offset) as i32;
+    (angle - quadrant as f32 * FRAC_PI_2, quadrant)
+}
+
+fn sin_reduced(angle: f32) -> f32 {
+    let squared = angle * angle;
+    angle
+        * (1.0
+            - squared
+                * (1.0 / 6.0)
+                * (1.0 - squared * (1.0 / 20.0) * (1.0 - squared * (1.0 / 42.0))))
+}
+
+fn cos_reduced(angle: f32) -> f32 {
+    let squared = angle * angle;
+    1.0 - squared * 0.5 * (1.0 - squared * (1.0 / 12.0) * (1.0 - squared * (1.0 / 30.0)))
+}
+
+/// Sine of angle in radians.
+pub fn sin(angle: f32) -> f32 {
+    if !angle.is_finite() {
+        return f32::NAN;
+    }
+    let (reduced, quadrant) = reduce(angle);
+    match quadrant & 3 {
+        0 => sin_reduced(reduced),
+        1 => cos_reduced(reduced),
+        2 => -sin_reduced(reduced),
+        _ => -cos_reduced(reduced),
+    }
+}
+
+/// Cosine of angle in radians.
+pub fn cos(angle: f32) -> f32 {
+    if !angle.is_finite() {
+        return f32::NAN;
+    }
+    let (reduced, quadrant) = reduce(angle);
+    match quadrant & 3 {
+        0 => cos_reduced(reduced),
+        1 => -sin_reduced(reduced),
+        2 => -cos_reduced(reduced),
+        _ => sin_reduced(reduced),
+    }
+}
+
+/// Arctangent approximation by a rational correction of the linear term.
+fn atan(value: f32) -> f32 {
+    if value < 0.0 {
+        -atan(-value)
+    } else if value > 1.0 {
+        FRAC_PI_2 - atan(1.0 / value)
+    } else {
+        FRAC_PI_4 * value - value * (value - 1.0) * (0.2447 + 0.0663 * value)
+    }
+}
+
+/// Four quadrant arctangent of `y` and `x`.
+pub fn atan2(y: f32, x: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        f32::NAN
+    } else if x > 0.0 {
+        atan(y / x)
+    } else if x < 0.0 {
+        if y.is_sign_negative() {
+            atan(y / x) - PI
+        } else {
+            atan(y / x) + PI
+        }
+    } else if y > 0.0 {
+        FRAC_PI_2
+    } else if y < 0.0 {
+        -FRAC_PI_2
+    } else if x.is_sign_negative() {
+        if y.is_sign_negative() {
+            -PI
+        } else {
+            PI
+        }
+    } else {
+        y
+    }
+}
+
+/// Arccosine of value in the `[-1, 1]` range.
+pub fn acos(value: f32) -> f32 {
+    atan2(sqrt((1.0 - value) * (1.0 + value)), value)
+}
+
+#[cfg(test)]
+mod test {
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, PI};
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 2e-3,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn approximations_at_known_values() {
+        assert_close(super::sqrt(2.0) / core::f32::consts::SQRT_2, 1.0);
+        assert_close(super::sqrt(1e6) / 1e3, 1.0);
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse",
+            not(feature = "deterministic")
+        ))]
+        for value in [1e-6, 0.3, 2.0, 7.0, 1e6] {
+            let inverse = super::inverse_sqrt(value);
+            assert!((inverse * inverse * value - 1.0).abs() < 2e-6);
+        }
+        assert_close(super::sin(FRAC_PI_2), 1.0);
+        assert_close(super::cos(FRAC_PI_3), 0.5);
+        assert_close(super::sin(-PI / 6.0), -0.5);
+        assert_close(super::acos(0.5), FRAC_PI_3);
+        assert_close(super::atan2(1.0, 1.0), FRAC_PI_4);
+        assert_close(super::atan2(-1.0, -1.0), -3.0 * FRAC_PI_4);
+    }
+
+    #[test]
+    fn fast_normalization_is_close_to_unit() {
+        use crate::quaternion::Quaternion;
+        use crate::vector::Vector;
+        // Squared norm doubles the relative error of the bit-level estimate.
+        let vector = Vector::new(3.0_f32, -4.0, 12.0).normalized_fast();
+        assert_close(vector.dot(vector) * 0.5, 0.5);
+        assert_close(vector.z(), 12.0 / 13.0);
+        let quaternion = Quaternion::with_wijk(1.0_f32, 2.0, -2.0, 4.0).normalized_fast();
+        assert_close(quaternion.dot(quaternion) * 0.5, 0.5);
+        assert_close(quaternion.w(), 0.2);
+    }
+
+    #[test]
+    fn sine_and_cosine_are_consistent() {
+        let mut angle = -10.0;
+        while angle < 10.0 {
+            let (sin, cos) = (super::sin(angle), super::cos(angle));
+            assert_close(sin * sin + cos * cos, 1.0);
+            let restored = super::atan2(sin, cos);
+            assert_close(super::sin(restored), sin);
+            assert_close(super::cos(restored), cos);
+            angle += 0.01;
+        }
+    }
+}
@@ -0,0 +1,351 @@
+use crate::ops::Real;
+use crate::pose::Pose;
+use crate::twist::Twist;
+use crate::vector::Vector;
+use num::Float;
+
+/// First-order low-pass filter for pose streams.
+///
+/// Translation is smoothed exponentially, rotation is smoothed by spherical interpolation
+/// towards each new sample. Smoothing factor is derived from the time step of every update,
+/// so the filter behaves consistently with irregular sample rates.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::filter::PoseLowPass;
+/// let mut filter = PoseLowPass::new(0.1, 0.1);
+/// filter.update(Pose::identity(), 0.01);
+/// let target = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::identity());
+/// for _ in 0..100 {
+///     filter.update(target, 0.01);
+/// }
+/// let result = filter.update(target, 0.01);
+/// assert!((result.translation() - target.translation()).norm() < 1e-3);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoseLowPass<T> {
+    translation_time_constant: T,
+    rotation_time_constant: T,
+    state: Option<Pose<T, T>>,
+}
+
+impl<T> PoseLowPass<T>
+where
+    T: Float + Real,
+{
+    /// Create new filter with given time constants for translation and rotation.
+    /// Zero time constant disables smoothing of the corresponding component.
+    pub fn new(translation_time_constant: T, rotation_time_constant: T) -> Self {
+        Self {
+            translation_time_constant,
+            rotation_time_constant,
+            state: None,
+        }
+    }
+
+    /// Feed new sample taken `dt` after the previous one, returns filtered pose.
+    /// The first sample initializes the filter and is returned unchanged.
+    pub fn update(&mut self, pose: Pose<T, T>, dt: T) -> Pose<T, T> {
+        let filtered = match self.state {
+            None => pose,
+            Some(state) => {
+                let translation = state.translation().interpolate(
+                    pose.translation(),
+                    smoothing(dt, self.translation_time_constant),
+                );
+                let rotation = state
+                    .rotation()
+                    .slerp(pose.rotation(), smoothing(dt, self.rotation_time_constant));
+                Pose::new(translation, rotation)
+            }
+        };
+        self.state = Some(filtered);
+        filtered
+    }
+
+    /// Get current filtered pose, `None` if no samples were provided yet.
+    pub fn value(&self) -> Option<Pose<T, T>> {
+        self.state
+    }
+
+    /// Forget filter state, the next sample initializes the filter again.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Dead-band filter for noisy pose input.
+///
+/// Keeps reporting the last accepted pose until the input moves away from it by more than
+/// the translation threshold or rotates away by more than the rotation threshold,
+/// after which the input is passed through unchanged and becomes the new accepted pose.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::filter::DeadBand;
+/// let mut filter = DeadBand::new(0.01, 0.01);
+/// filter.update(Pose::identity());
+/// let noise = Pose::new(Vector::new(0.005, 0.0, 0.0), Quaternion::identity());
+/// assert_eq!(filter.update(noise), Pose::identity());
+/// let motion = Pose::new(Vector::new(0.05, 0.0, 0.0), Quaternion::identity());
+/// assert_eq!(filter.update(motion), motion);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DeadBand<T> {
+    translation_threshold: T,
+    rotation_threshold: T,
+    state: Option<Pose<T, T>>,
+}
+
+impl<T> DeadBand<T>
+where
+    T: Float + Real,
+{
+    /// Create new filter with given translation distance and rotation angle thresholds.
+    pub fn new(translation_threshold: T, rotation_threshold: T) -> Self {
+        Self {
+            translation_threshold,
+            rotation_threshold,
+            state: None,
+        }
+    }
+
+    /// Feed new sample, returns the currently accepted pose.
+    /// The first sample is always accepted.
+    pub fn update(&mut self, pose: Pose<T, T>) -> Pose<T, T> {
+        let accepted = match self.state {
+            Some(state) => {
+                let translation = (pose.translation() - state.translation()).norm();
+                let rotation = (state.rotation().inverse() * pose.rotation()).log().norm();
+                if translation > self.translation_threshold || rotation > self.rotation_threshold {
+                    pose
+                } else {
+                    state
+                }
+            }
+            None => pose,
+        };
+        self.state = Some(accepted);
+        accepted
+    }
+
+    /// Get currently accepted pose, `None` if no samples were provided yet.
+    pub fn value(&self) -> Option<Pose<T, T>> {
+        self.state
+    }
+
+    /// Forget accepted pose, the next sample is accepted unconditionally.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Body-frame twist estimator for timestamped pose samples.
+///
+/// Twist is calculated by finite differences of consecutive samples and smoothed exponentially
+/// with the given time constant. Samples arriving closer than the minimum time step after
+/// the last differenced sample are skipped, so the next difference is taken over a longer
+/// interval, which keeps timestamp jitter from amplifying noise.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::filter::TwistEstimator;
+/// let mut estimator = TwistEstimator::new(0.0, 1e-3);
+/// estimator.update(0.0, Pose::identity());
+/// let pose = Pose::new(Vector::new(0.2, 0.0, 0.0), Quaternion::identity());
+/// let twist = estimator.update(0.1, pose).unwrap();
+/// assert!((twist.linear() - Vector::new(2.0, 0.0, 0.0)).norm() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TwistEstimator<T> {
+    time_constant: T,
+    min_dt: T,
+    sample: Option<(T, Pose<T, T>)>,
+    estimate: Option<Twist<T, T>>,
+}
+
+impl<T> TwistEstimator<T>
+where
+    T: Float + Real,
+{
+    /// Create new estimator with given smoothing time constant and minimum time step between
+    /// differenced samples. Zero time constant disables smoothing.
+    pub fn new(time_constant: T, min_dt: T) -> Self {
+        Self {
+            time_constant,
+            min_dt,
+            sample: None,
+            estimate: None,
+        }
+    }
+
+    /// Feed new sample, returns current twist estimate, `None` until two samples
+    /// far enough apart in time were provided.
+    pub fn update(&mut self, time: T, pose: Pose<T, T>) -> Option<Twist<T, T>> {
+        let (last_time, last) = match self.sample {
+            Some(sample) => sample,
+            None => {
+                self.sample = Some((time, pose));
+                return None;
+            }
+        };
+        let dt = time - last_time;
+        if dt < self.min_dt || dt <= T::zero() {
+            return self.estimate;
+        }
+        let raw = (last.inverse() * pose).log() / dt;
+        let estimate = match self.estimate {
+            Some(estimate) => estimate + (raw - estimate) * smoothing(dt, self.time_constant),
+            None => raw,
+        };
+        self.sample = Some((time, pose));
+        self.estimate = Some(estimate);
+        self.estimate
+    }
+
+    /// Get current twist estimate, `None` if not enough samples were provided yet.
+    pub fn value(&self) -> Option<Twist<T, T>> {
+        self.estimate
+    }
+
+    /// Forget all samples and the current estimate.
+    pub fn reset(&mut self) {
+        self.sample = None;
+        self.estimate = None;
+    }
+}
+
+/// Velocity-limited tracker smoothing target poses between a planner and a servo loop.
+///
+/// Translation moves towards the target with speed limited by the maximum linear velocity
+/// and speed changes limited by the maximum linear acceleration, slowing down in time to stop
+/// at the target. Rotation turns towards the target by at most the maximum angular velocity
+/// times the time step. Infinite limits disable the corresponding constraint.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::filter::PoseTracker;
+/// let mut tracker = PoseTracker::new(1.0_f64, 2.0, 0.5);
+/// tracker.update(Pose::identity(), 0.01);
+/// let target = Pose::new(
+///     Vector::new(1.0, 0.0, 0.0),
+///     Quaternion::from_angle_axis(1.0, Vector::unit_z()),
+/// );
+/// let step = tracker.update(target, 0.01);
+/// assert!((step.translation().x() - 2e-4).abs() < 1e-12);
+/// assert!((step.rotation().angle_to(Quaternion::identity()) - 5e-3).abs() < 1e-12);
+/// for _ in 0..300 {
+///     tracker.update(target, 0.01);
+/// }
+/// assert_eq!(tracker.value(), Some(target));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PoseTracker<T> {
+    max_linear_velocity: T,
+    max_linear_acceleration: T,
+    max_angular_velocity: T,
+    state: Option<(Pose<T, T>, Vector<T>)>,
+}
+
+impl<T> PoseTracker<T>
+where
+    T: Float + Real,
+{
+    /// Create new tracker with given maximum linear velocity, linear acceleration and angular
+    /// velocity in radians per unit of time.
+    pub fn new(
+        max_linear_velocity: T,
+        max_linear_acceleration: T,
+        max_angular_velocity: T,
+    ) -> Self {
+        Self {
+            max_linear_velocity,
+            max_linear_acceleration,
+            max_angular_velocity,
+            state: None,
+        }
+    }
+
+    /// Feed new target taken `dt` after the previous one, returns the tracked pose.
+    /// The first target initializes the tracker at rest and is returned unchanged,
+    /// non-positive `dt` keeps the tracked pose.
+    pub fn update(&mut self, target: Pose<T, T>, dt: T) -> Pose<T, T> {
+        let (pose, velocity) = match self.state {
+            None => (target, Vector::new(T::zero(), T::zero(), T::zero())),
+            Some((pose, velocity)) if dt <= T::zero() => (pose, velocity),
+            Some((pose, velocity)) => {
+                let offset = target.translation() - pose.translation();
+                let distance = offset.norm();
+                let desired = if distance > T::zero() {
+                    let two = T::one() + T::one();
+                    let braking = Float::sqrt(two * self.max_linear_acceleration * distance);
+                    let speed = self.max_linear_velocity.min(braking).min(distance / dt);
+                    offset * (speed / distance)
+                } else {
+                    offset
+                };
+                let velocity =
+                    velocity + clamp_norm(desired - velocity, self.max_linear_acceleration * dt);
+                let rotation = pose
+                    .rotation()
+                    .rotate_towards(target.rotation(), self.max_angular_velocity * dt);
+                let step = velocity * dt;
+                if (offset - step).norm() <= <T as Float>::epsilon() * (T::one() + distance) {
+                    (Pose::new(target.translation(), rotation), velocity)
+                } else {
+                    (Pose::new(pose.translation() + step, rotation), velocity)
+                }
+            }
+        };
+        self.state = Some((pose, velocity));
+        pose
+    }
+
+    /// Get current tracked pose, `None` if no targets were provided yet.
+    pub fn value(&self) -> Option<Pose<T, T>> {
+        self.state.map(|(pose, _)| pose)
+    }
+
+    /// Get current linear velocity of the tracked pose, `None` if no targets were provided yet.
+    pub fn velocity(&self) -> Option<Vector<T>> {
+        self.state.map(|(_, velocity)| velocity)
+    }
+
+    /// Forget tracked pose, the next target initializes the tracker again.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+}
+
+/// Scale vector down to the given norm if it is longer.
+fn clamp_norm<T>(vector: Vector<T>, max_norm: T) -> Vector<T>
+where
+    T: Float + Real,
+{
+    let norm = vector.norm();
+    if norm > max_norm {
+        vector * (max_norm / norm)
+    } else {
+        vector
+    }
+}
+
+/// Calculate exponential smoothing factor for given time step and time constant.
+fn smoothing<T>(dt: T, time_constant: T) -> T
+where
+    T: Float,
+{
+    if time_constant > T::zero() {
+        #[cfg(not(feature = "deterministic"))]
+        let decay = (-dt / time_constant).exp();
+        #[cfg(feature = "deterministic")]
+        let decay = crate::math::via_f64(-dt / time_constant, crate::math::exp);
+        T::one() - decay
+    } else {
+        T::one()
+    }
+}
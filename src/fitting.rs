@@ -0,0 +1,401 @@
+use crate::geometry::{Line, Plane};
+use crate::ops::Real;
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+
+/// Fit plane to points containing outliers by random sample consensus.
+///
+/// Every iteration builds plane through three points picked with `rng`, which is called with
+/// the number of points and must return index below it. Plane with the largest number of
+/// points closer than `threshold` wins, ties keep the earlier plane. Inlier flags of the
+/// winning plane are written to `inliers`, which must be as long as `points`.
+/// Returns the plane and the number of its inliers, `None` if lengths differ or no
+/// non-degenerate sample was drawn.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::fitting::fit_plane_ransac;
+/// let mut points = [Vector::new(0.0, 0.0, 0.0); 12];
+/// for (index, point) in points.iter_mut().enumerate() {
+///     let (x, y) = ((index % 4) as f64, (index / 4) as f64);
+///     *point = Vector::new(x, y, 1.0 + 0.001 * x);
+/// }
+/// points[5] = Vector::new(1.0, 1.0, 4.0);
+/// let mut state = 7_u32;
+/// let rng = |bound: usize| {
+///     state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+///     (state >> 16) as usize % bound
+/// };
+/// let mut inliers = [false; 12];
+/// let (plane, count) = fit_plane_ransac(&points, 0.01, 50, rng, &mut inliers).unwrap();
+/// assert_eq!(count, 11);
+/// assert!(!inliers[5]);
+/// assert!((plane.normal().z().abs() - 1.0).abs() < 1e-3);
+/// ```
+pub fn fit_plane_ransac<T, F>(
+    points: &[Vector<T>],
+    threshold: T,
+    iterations: usize,
+    mut rng: F,
+    inliers: &mut [bool],
+) -> Option<(Plane<T>, usize)>
+where
+    T: Real,
+    F: FnMut(usize) -> usize,
+{
+    if points.len() != inliers.len() || points.len() < 3 {
+        return None;
+    }
+    let within = |plane: &Plane<T>, point: &Vector<T>| {
+        let distance = plane.signed_distance(*point);
+        -threshold <= distance && distance <= threshold
+    };
+    let mut best: Option<(Plane<T>, usize)> = None;
+    for _ in 0..iterations {
+        let (a, b, c) = (rng(points.len()), rng(points.len()), rng(points.len()));
+        let (Some(a), Some(b), Some(c)) = (points.get(a), points.get(b), points.get(c)) else {
+            continue;
+        };
+        let normal = (*b - *a).cross(*c - *a);
+        let Some(plane) = Plane::from_point_normal(*a, normal) else {
+            continue;
+        };
+        let count = points.iter().filter(|point| within(&plane, point)).count();
+        if best.is_none_or(|(_, best)| count > best) {
+            best = Some((plane, count));
+        }
+    }
+    let (plane, count) = best?;
+    for (flag, point) in inliers.iter_mut().zip(points) {
+        *flag = within(&plane, point);
+    }
+    Some((plane, count))
+}
+
+/// Centroid and principal axes of a point set.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PrincipalAxes<T> {
+    /// Mean of the points.
+    pub centroid: Vector<T>,
+    /// Variances along the axes in descending order.
+    pub variances: [T; 3],
+    /// Unit axes forming right-handed basis, in the order of variances.
+    pub axes: [Vector<T>; 3],
+}
+
+/// Calculate centroid and principal axes of points, returns `None` for no points.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::fitting::principal_axes;
+/// let points = [
+///     Vector::new(0.0_f64, 0.0, 0.0),
+///     Vector::new(4.0, 0.0, 0.0),
+///     Vector::new(2.0, 1.0, 0.0),
+///     Vector::new(2.0, -1.0, 0.0),
+/// ];
+/// let axes = principal_axes(&points).unwrap();
+/// assert_eq!(axes.centroid, Vector::new(2.0, 0.0, 0.0));
+/// assert!((axes.variances[0] - 2.0).abs() < 1e-12 && (axes.variances[1] - 0.5).abs() < 1e-12);
+/// assert!((axes.axes[0].x().abs() - 1.0).abs() < 1e-12);
+/// ```
+pub fn principal_axes<T>(points: &[Vector<T>]) -> Option<PrincipalAxes<T>>
+where
+    T: Real,
+{
+    let first = *points.first()?;
+    let (sum, count) = points
+        .iter()
+        .fold((Vector::zero(), T::zero()), |(sum, count), point| {
+            (sum + (*point - first), count + T::one())
+        });
+    let centroid = first + sum / count;
+    let mut covariance = [[T::zero(); 3]; 3];
+    for point in points {
+        let offset = <[T; 3]>::from(*point - centroid);
+        for (row, values) in covariance.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = *value + offset[row] * offset[column] / count;
+            }
+        }
+    }
+    let (variances, axes) = symmetric_eigen(covariance);
+    Some(PrincipalAxes {
+        centroid,
+        variances,
+        axes,
+    })
+}
+
+/// Fit line to points by total least squares, i.e. minimizing sum of squared distances
+/// from points to the line. Returns `None` for fewer than two distinct points.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::fitting::fit_line;
+/// let points = [
+///     Vector::new(1.0_f64, 1.0, 0.1),
+///     Vector::new(2.0, 2.0, -0.1),
+///     Vector::new(3.0, 3.0, -0.1),
+///     Vector::new(4.0, 4.0, 0.1),
+/// ];
+/// let line = fit_line(&points).unwrap();
+/// assert!(line.distance(Vector::new(10.0, 10.0, 0.0)) < 1e-12);
+/// assert!(fit_line(&points[..1]).is_none());
+/// ```
+pub fn fit_line<T>(points: &[Vector<T>]) -> Option<Line<T>>
+where
+    T: Real,
+{
+    let axes = principal_axes(points)?;
+    if axes.variances[0] <= T::zero() {
+        return None;
+    }
+    Line::new(axes.centroid, axes.axes[0])
+}
+
+/// Uncertainty ellipsoid of position covariance, semi-axes are standard deviations along
+/// principal axes given by the rotation of the ellipsoid frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ellipsoid<T> {
+    /// Center of the ellipsoid, usually the estimated position.
+    pub center: Vector<T>,
+    /// Rotation of the frame whose axes are principal axes of the ellipsoid.
+    pub rotation: Quaternion<T>,
+    /// Semi-axis lengths along x, y and z axes of the ellipsoid frame in descending order.
+    pub semi_axes: Vector<T>,
+}
+
+impl<T> Ellipsoid<T>
+where
+    T: Real,
+{
+    /// Create one-sigma ellipsoid of 3x3 position covariance, only the upper triangle of the
+    /// matrix is read. Negative variances caused by round-off are clamped to zero.
+    /// Multiply semi-axes by the desired number of sigmas for wider confidence regions.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::fitting::Ellipsoid;
+    /// let covariance = [[4.0_f64, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 9.0]];
+    /// let ellipsoid = Ellipsoid::from_covariance(Vector::zero(), covariance);
+    /// assert!((ellipsoid.semi_axes - Vector::new(3.0, 2.0, 1.0)).norm() < 1e-12);
+    /// let major = ellipsoid.rotation.rotate(Vector::<f64>::unit_x());
+    /// assert!((major.z().abs() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_covariance(center: Vector<T>, covariance: [[T; 3]; 3]) -> Self {
+        let (variances, axes) = symmetric_eigen(covariance);
+        let [x, y, z] = variances.map(|variance| {
+            if variance > T::zero() {
+                variance.sqrt()
+            } else {
+                T::zero()
+            }
+        });
+        Self {
+            center,
+            rotation: Quaternion::from_rotation_matrix(
+                [0, 1, 2].map(|row| axes.map(|axis| <[T; 3]>::from(axis)[row])),
+            ),
+            semi_axes: Vector::new(x, y, z),
+        }
+    }
+
+    /// Calculate covariance matrix whose one-sigma ellipsoid this is.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::fitting::Ellipsoid;
+    /// let ellipsoid = Ellipsoid {
+    ///     center: Vector::zero(),
+    ///     rotation: Quaternion::from_angle_axis(core::f64::consts::FRAC_PI_2, Vector::unit_z()),
+    ///     semi_axes: Vector::new(2.0, 1.0, 0.5),
+    /// };
+    /// let covariance = ellipsoid.covariance();
+    /// assert!((covariance[0][0] - 1.0).abs() < 1e-12 && (covariance[1][1] - 4.0).abs() < 1e-12);
+    /// assert!((covariance[2][2] - 0.25).abs() < 1e-12 && covariance[0][1].abs() < 1e-12);
+    /// ```
+    pub fn covariance(&self) -> [[T; 3]; 3] {
+        let matrix = self.rotation.prepare().matrix();
+        let variances = <[T; 3]>::from(self.semi_axes).map(|axis| axis * axis);
+        let mut covariance = [[T::zero(); 3]; 3];
+        for (row, values) in covariance.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = (0..3).fold(T::zero(), |sum, axis| {
+                    sum + matrix[row][axis] * variances[axis] * matrix[column][axis]
+                });
+            }
+        }
+        covariance
+    }
+
+    /// Move ellipsoid expressed in child frame of the pose into its parent frame, this
+    /// rotates the covariance as `R * C * R^T`.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        Self {
+            center: pose.apply_to(self.center),
+            rotation: pose.rotation() * self.rotation,
+            semi_axes: self.semi_axes,
+        }
+    }
+}
+
+/// Maximal number of Jacobi sweeps in [`symmetric_eigen`], convergence is quadratic
+/// and usually takes four or five sweeps.
+const SWEEPS: usize = 16;
+
+/// Calculate eigenvalues and unit eigenvectors of symmetric 3x3 matrix by cyclic Jacobi
+/// rotations. Eigenvalues are sorted in descending order, eigenvectors form right-handed
+/// basis. Only the upper triangle of the matrix is read.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::fitting::symmetric_eigen;
+/// let (values, vectors) = symmetric_eigen([[2.0_f64, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]]);
+/// assert!((values[0] - 5.0).abs() < 1e-12 && (values[1] - 3.0).abs() < 1e-12);
+/// assert!((values[2] - 1.0).abs() < 1e-12);
+/// let expected = Vector::new(1.0, -1.0, 0.0) / 2.0_f64.sqrt();
+/// assert!((vectors[2].dot(expected).abs() - 1.0).abs() < 1e-12);
+/// ```
+pub fn symmetric_eigen<T>(matrix: [[T; 3]; 3]) -> ([T; 3], [Vector<T>; 3])
+where
+    T: Real,
+{
+    let mut a = matrix;
+    (a[1][0], a[2][0], a[2][1]) = (a[0][1], a[0][2], a[1][2]);
+    let mut vectors = [
+        [T::one(), T::zero(), T::zero()],
+        [T::zero(), T::one(), T::zero()],
+        [T::zero(), T::zero(), T::one()],
+    ];
+    let two = T::one() + T::one();
+    for _ in 0..SWEEPS {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diagonal = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= T::epsilon() * T::epsilon() * diagonal {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == T::zero() {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+            let magnitude = if theta < T::zero() { -theta } else { theta };
+            let t = T::one() / (magnitude + (theta * theta + T::one()).sqrt());
+            let t = if theta < T::zero() { -t } else { t };
+            let c = T::one() / (t * t + T::one()).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (x, y) = (row[p], row[q]);
+                row[p] = c * x - s * y;
+                row[q] = s * x + c * y;
+            }
+            let (x, y) = (a[p], a[q]);
+            for column in 0..3 {
+                a[p][column] = c * x[column] - s * y[column];
+                a[q][column] = s * x[column] + c * y[column];
+            }
+            for row in vectors.iter_mut() {
+                let (x, y) = (row[p], row[q]);
+                row[p] = c * x - s * y;
+                row[q] = s * x + c * y;
+            }
+        }
+    }
+    let mut order = [0, 1, 2];
+    order.sort_unstable_by(|first, second| {
+        a[*second][*second]
+            .partial_cmp(&a[*first][*first])
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    let values = order.map(|index| a[index][index]);
+    let [first, second, _] =
+        order.map(|index| Vector::new(vectors[0][index], vectors[1][index], vectors[2][index]));
+    (values, [first, second, first.cross(second)])
+}
+
+#[cfg(test)]
+mod test {
+    use super::{fit_plane_ransac, symmetric_eigen, Ellipsoid};
+    use crate::ops::Norm;
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn ransac_rejects_outliers() {
+        let normal = Vector::new(1.0_f64, -2.0, 2.0) / 3.0;
+        let (u, v) = (
+            Vector::new(2.0, 1.0, 0.0) / 5.0_f64.sqrt(),
+            Vector::new(-2.0, 4.0, 5.0) / 45.0_f64.sqrt(),
+        );
+        let mut points = [Vector::zero(); 40];
+        for (index, point) in points.iter_mut().enumerate() {
+            let (a, b) = ((index % 8) as f64 - 3.5, (index / 8) as f64 - 2.0);
+            *point = normal * 1.5 + u * a + v * b;
+            if index % 7 == 3 {
+                *point += normal * (0.5 + 0.1 * index as f64);
+            }
+        }
+        let mut index = 0;
+        let rng = |bound: usize| {
+            index = (index + 13) % 41;
+            index % bound
+        };
+        let mut inliers = [false; 40];
+        let (plane, count) = fit_plane_ransac(&points, 1e-6, 30, rng, &mut inliers).unwrap();
+        assert_eq!(count, 34);
+        assert!(inliers
+            .iter()
+            .enumerate()
+            .all(|(index, inlier)| *inlier == (index % 7 != 3)));
+        assert!((plane.normal().dot(normal).abs() - 1.0).abs() < 1e-12);
+        assert!(fit_plane_ransac(&points, 1e-6, 30, |_| 0, &mut inliers).is_none());
+        assert!(fit_plane_ransac(&points[..3], 1e-6, 30, |_| 0, &mut inliers).is_none());
+    }
+
+    #[test]
+    fn eigen_decomposition_reconstructs_matrix() {
+        let matrices = [
+            [[4.0_f64, -2.0, 0.5], [-2.0, 3.0, 1.5], [0.5, 1.5, -1.0]],
+            [[1.0, 1e-9, 0.0], [1e-9, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            [[0.0; 3]; 3],
+            [[1e6, 2.0, 3.0], [2.0, 1e-6, 0.0], [3.0, 0.0, -5.0]],
+        ];
+        for matrix in matrices {
+            let (values, vectors) = symmetric_eigen(matrix);
+            assert!(values[0] >= values[1] && values[1] >= values[2]);
+            let scale = 1.0 + values[0].abs().max(values[2].abs());
+            for (value, vector) in values.iter().zip(vectors) {
+                let product = Vector::from(matrix.map(|row| Vector::from(row).dot(vector)));
+                assert!((product - vector * *value).norm() < 1e-12 * scale);
+                assert!((vector.norm() - 1.0).abs() < 1e-12);
+            }
+            assert!(vectors[0].dot(vectors[1]).abs() < 1e-12);
+            assert!((vectors[0].cross(vectors[1]).dot(vectors[2]) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn ellipsoid_round_trips_covariance_through_poses() {
+        let covariance = [[4.0_f64, -2.0, 0.5], [-2.0, 3.0, 1.5], [0.5, 1.5, 2.0]];
+        let ellipsoid = Ellipsoid::from_covariance(Vector::new(1.0, 2.0, 3.0), covariance);
+        assert!((Norm::norm(ellipsoid.rotation) - 1.0).abs() < 1e-12);
+        for (row, expected) in ellipsoid.covariance().iter().zip(covariance) {
+            assert!((Vector::from(*row) - Vector::from(expected)).norm() < 1e-12);
+        }
+        let rotation = Quaternion::<f64>::from_rpy(0.3, -0.7, 2.1);
+        let pose = Pose::new(Vector::new(-1.0, 0.5, 4.0), rotation);
+        let moved = ellipsoid.transform(pose);
+        assert!((moved.center - pose.apply_to(ellipsoid.center)).norm() < 1e-12);
+        let matrix = rotation.prepare().matrix();
+        let rotated = moved.covariance();
+        for (row, values) in rotated.iter().enumerate() {
+            for (column, value) in values.iter().enumerate() {
+                let mut expected = 0.0;
+                for (a, line) in covariance.iter().enumerate() {
+                    for (b, entry) in line.iter().enumerate() {
+                        expected += matrix[row][a] * entry * matrix[column][b];
+                    }
+                }
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::pose::Pose;
+use crate::vector::Vector;
+use core::fmt::{Debug, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Neg, Sub};
+
+/// Pose of frame `Child` relative to frame `Parent`, transforming `Child` coordinates into
+/// `Parent` coordinates. Frames are zero-sized marker types, so poses compose only when the
+/// inner frames match and mix-ups fail to compile.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::framed::{FramedPose, FramedVector};
+/// struct World;
+/// struct Base;
+/// struct Tool;
+/// let base: FramedPose<World, Base, f64, f64> =
+///     FramedPose::new(Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::<f64>::IDENTITY));
+/// let tool: FramedPose<Base, Tool, f64, f64> =
+///     FramedPose::new(Pose::new(Vector::new(0.0, 0.0, 0.5), Quaternion::<f64>::IDENTITY));
+/// let tip: FramedVector<World, f64> = base * tool * FramedVector::new(Vector::<f64>::ZERO);
+/// assert_eq!(tip.vector(), Vector::new(1.0, 0.0, 0.5));
+/// ```
+/// Composing poses in the wrong order is rejected.
+/// ```compile_fail
+/// # use spatial::pose::Pose;
+/// # use spatial::framed::FramedPose;
+/// struct World;
+/// struct Base;
+/// struct Tool;
+/// let base: FramedPose<World, Base, f64, f64> = FramedPose::new(Pose::<f64, f64>::IDENTITY);
+/// let tool: FramedPose<Base, Tool, f64, f64> = FramedPose::new(Pose::<f64, f64>::IDENTITY);
+/// let wrong = tool * base;
+/// ```
+pub struct FramedPose<Parent, Child, T, R> {
+    pose: Pose<T, R>,
+    frames: PhantomData<fn() -> (Parent, Child)>,
+}
+
+impl<Parent, Child, T, R> FramedPose<Parent, Child, T, R> {
+    /// Tag pose of `Child` relative to `Parent`.
+    pub const fn new(pose: Pose<T, R>) -> Self {
+        Self {
+            pose,
+            frames: PhantomData,
+        }
+    }
+
+    /// Get reference to the untagged pose.
+    pub fn pose_ref(&self) -> &Pose<T, R> {
+        &self.pose
+    }
+
+    /// Drop frame tags.
+    pub fn into_pose(self) -> Pose<T, R> {
+        self.pose
+    }
+}
+
+impl<Parent, Child, T, R> FramedPose<Parent, Child, T, R>
+where
+    T: Copy,
+    R: Copy,
+{
+    /// Get the untagged pose.
+    pub fn pose(&self) -> Pose<T, R> {
+        self.pose
+    }
+}
+
+impl<Parent, Child, T, R> FramedPose<Parent, Child, T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+{
+    /// Calculate pose of `Parent` relative to `Child`, see [`Pose::inverse`].
+    pub fn inverse(self) -> FramedPose<Child, Parent, T, R>
+    where
+        T: Neg<Output = T>,
+        R: Neg<Output = R>,
+    {
+        FramedPose::new(self.pose.inverse())
+    }
+
+    /// Transform point expressed in `Child` frame into `Parent` frame.
+    pub fn apply_to(self, point: FramedVector<Child, T>) -> FramedVector<Parent, T> {
+        FramedVector::new(self.pose.apply_to(point.vector))
+    }
+}
+
+impl<Parent, Child, Grandchild, T, R> Mul<FramedPose<Child, Grandchild, T, R>>
+    for FramedPose<Parent, Child, T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+{
+    type Output = FramedPose<Parent, Grandchild, T, R>;
+    fn mul(self, other: FramedPose<Child, Grandchild, T, R>) -> Self::Output {
+        FramedPose::new(self.pose * other.pose)
+    }
+}
+
+/// Transform point expressed in `Child` frame into `Parent` frame.
+impl<Parent, Child, T, R> Mul<FramedVector<Child, T>> for FramedPose<Parent, Child, T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+{
+    type Output = FramedVector<Parent, T>;
+    fn mul(self, point: FramedVector<Child, T>) -> Self::Output {
+        self.apply_to(point)
+    }
+}
+
+impl<Parent, Child, T: Clone, R: Clone> Clone for FramedPose<Parent, Child, T, R> {
+    fn clone(&self) -> Self {
+        Self::new(self.pose.clone())
+    }
+}
+
+impl<Parent, Child, T: Copy, R: Copy> Copy for FramedPose<Parent, Child, T, R> {}
+
+impl<Parent, Child, T: PartialEq, R: PartialEq> PartialEq for FramedPose<Parent, Child, T, R> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pose == other.pose
+    }
+}
+
+impl<Parent, Child, T: Debug, R: Debug> Debug for FramedPose<Parent, Child, T, R> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter
+            .debug_struct("FramedPose")
+            .field("pose", &self.pose)
+            .finish()
+    }
+}
+
+/// Point or direction expressed in coordinates of `Frame`, see [`FramedPose`].
+pub struct FramedVector<Frame, T> {
+    vector: Vector<T>,
+    frame: PhantomData<fn() -> Frame>,
+}
+
+impl<Frame, T> FramedVector<Frame, T> {
+    /// Tag vector expressed in `Frame`.
+    pub const fn new(vector: Vector<T>) -> Self {
+        Self {
+            vector,
+            frame: PhantomData,
+        }
+    }
+
+    /// Drop frame tag.
+    pub fn into_vector(self) -> Vector<T> {
+        self.vector
+    }
+}
+
+impl<Frame, T: Copy> FramedVector<Frame, T> {
+    /// Get the untagged vector.
+    pub fn vector(&self) -> Vector<T> {
+        self.vector
+    }
+}
+
+impl<Frame, T: Clone> Clone for FramedVector<Frame, T> {
+    fn clone(&self) -> Self {
+        Self::new(self.vector.clone())
+    }
+}
+
+impl<Frame, T: Copy> Copy for FramedVector<Frame, T> {}
+
+impl<Frame, T: PartialEq> PartialEq for FramedVector<Frame, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+    }
+}
+
+impl<Frame, T: Debug> Debug for FramedVector<Frame, T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter
+            .debug_struct("FramedVector")
+            .field("vector", &self.vector)
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod test {
+    use super::{FramedPose, FramedVector};
+    use crate::pose::{test_pose, Pose};
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    struct World;
+    struct Camera;
+    struct Marker;
+
+    #[test]
+    fn framed_poses_compose_like_poses_at_no_cost() {
+        let camera = test_pose();
+        let marker = Pose::new(
+            Vector::new(0.0, 0.1, 1.5),
+            Quaternion::from_angle_axis(0.7, Vector::new(1.0, 1.0, 0.0)),
+        );
+        let world_camera: FramedPose<World, Camera, _, _> = FramedPose::new(camera);
+        let camera_marker: FramedPose<Camera, Marker, _, _> = FramedPose::new(marker);
+        let world_marker = world_camera * camera_marker;
+        assert_eq!(world_marker.pose(), camera * marker);
+        let back: FramedPose<Marker, World, _, _> = world_marker.inverse();
+        let point = FramedVector::<World, _>::new(Vector::new(1.0, 2.0, 3.0));
+        let round_trip = world_marker * (back * point);
+        assert!((round_trip.vector() - point.vector()).norm() < 1e-12);
+        assert_eq!(
+            core::mem::size_of::<FramedPose<World, Camera, f64, f64>>(),
+            core::mem::size_of::<Pose<f64, f64>>()
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use crate::ops::{Sqrt, Trig};
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+
+/// Semi-major axis of the WGS84 ellipsoid in meters.
+pub const WGS84_SEMI_MAJOR_AXIS: f64 = 6_378_137.0;
+/// Flattening of the WGS84 ellipsoid.
+pub const WGS84_FLATTENING: f64 = 1.0 / 298.257_223_563;
+
+/// Mean Earth radius in meters used by great-circle navigation helpers.
+pub const MEAN_EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// Squared first eccentricity of the WGS84 ellipsoid.
+const ECCENTRICITY_SQUARED: f64 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+/// Number of fixed-point iterations of the ECEF to geodetic conversion, enough for
+/// sub-millimeter accuracy from the surface up to geostationary altitude.
+const ITERATIONS: usize = 6;
+
+/// WGS84 geodetic coordinates, latitude and longitude in radians, altitude above
+/// the ellipsoid in meters.
+///
+/// Coordinates are `f64` only, since `f32` resolves Earth-centered positions to about
+/// half a meter.
+/// ```
+/// # use spatial::geo::Geodetic;
+/// let fix = Geodetic::from_degrees(59.9343, 30.3351, 15.0);
+/// let ecef = fix.to_ecef();
+/// let back = Geodetic::from_ecef(ecef);
+/// assert!((back.latitude() - fix.latitude()).abs() < 1e-12);
+/// assert!((back.altitude() - 15.0).abs() < 1e-6);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Geodetic {
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+}
+
+impl Geodetic {
+    /// Create coordinates from latitude and longitude in radians and altitude in meters.
+    pub const fn new(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            altitude,
+        }
+    }
+
+    /// Create coordinates from latitude and longitude in degrees and altitude in meters.
+    pub fn from_degrees(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        Self::new(latitude.to_radians(), longitude.to_radians(), altitude)
+    }
+
+    /// Get latitude in radians.
+    pub fn latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// Get longitude in radians.
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// Get altitude above the ellipsoid in meters.
+    pub fn altitude(&self) -> f64 {
+        self.altitude
+    }
+
+    /// Convert into Earth-centered Earth-fixed position in meters.
+    pub fn to_ecef(&self) -> Vector<f64> {
+        let (sin_latitude, cos_latitude) = (Trig::sin(self.latitude), Trig::cos(self.latitude));
+        let (sin_longitude, cos_longitude) = (Trig::sin(self.longitude), Trig::cos(self.longitude));
+        let normal = prime_vertical_radius(sin_latitude);
+        let horizontal = (normal + self.altitude) * cos_latitude;
+        Vector::new(
+            horizontal * cos_longitude,
+            horizontal * sin_longitude,
+            (normal * (1.0 - ECCENTRICITY_SQUARED) + self.altitude) * sin_latitude,
+        )
+    }
+
+    /// Convert Earth-centered Earth-fixed position in meters into geodetic coordinates.
+    /// Longitude of points on the polar axis is zero.
+    pub fn from_ecef(ecef: Vector<f64>) -> Self {
+        let (x, y, z) = (ecef.x(), ecef.y(), ecef.z());
+        let horizontal = Sqrt::sqrt(x * x + y * y);
+        let longitude = Trig::atan2(y, x);
+        let mut latitude = Trig::atan2(z, horizontal * (1.0 - ECCENTRICITY_SQUARED));
+        for _ in 0..ITERATIONS {
+            let sin_latitude = Trig::sin(latitude);
+            let normal = prime_vertical_radius(sin_latitude);
+            latitude = Trig::atan2(z + ECCENTRICITY_SQUARED * normal * sin_latitude, horizontal);
+        }
+        let (sin_latitude, cos_latitude) = (Trig::sin(latitude), Trig::cos(latitude));
+        let altitude = horizontal * cos_latitude + z * sin_latitude
+            - WGS84_SEMI_MAJOR_AXIS
+                * Sqrt::sqrt(1.0 - ECCENTRICITY_SQUARED * sin_latitude * sin_latitude);
+        Self::new(latitude, longitude, altitude)
+    }
+
+    /// Calculate initial great-circle bearing towards other point in radians,
+    /// clockwise from north in the `[0, 2pi)` range.
+    /// ```
+    /// # use spatial::geo::Geodetic;
+    /// let start = Geodetic::from_degrees(0.0, 0.0, 0.0);
+    /// let east = start.bearing_to(&Geodetic::from_degrees(0.0, 1.0, 0.0));
+    /// assert!((east - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    pub fn bearing_to(&self, other: &Self) -> f64 {
+        let (sin_from, cos_from) = (Trig::sin(self.latitude), Trig::cos(self.latitude));
+        let (sin_to, cos_to) = (Trig::sin(other.latitude), Trig::cos(other.latitude));
+        let delta = other.longitude - self.longitude;
+        let bearing = Trig::atan2(
+            Trig::sin(delta) * cos_to,
+            cos_from * sin_to - sin_from * cos_to * Trig::cos(delta),
+        );
+        if bearing < 0.0 {
+            bearing + core::f64::consts::TAU
+        } else {
+            bearing
+        }
+    }
+
+    /// Calculate great-circle distance to other point in meters on the sphere with
+    /// [`MEAN_EARTH_RADIUS`] using haversine formula, altitudes are ignored.
+    /// ```
+    /// # use spatial::geo::Geodetic;
+    /// let london = Geodetic::from_degrees(51.5007, -0.1246, 0.0);
+    /// let new_york = Geodetic::from_degrees(40.6892, -74.0445, 0.0);
+    /// assert!((london.distance_to(&new_york) - 5_574_840.0).abs() < 1e3);
+    /// ```
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        let half_latitude = Trig::sin((other.latitude - self.latitude) / 2.0);
+        let half_longitude = Trig::sin((other.longitude - self.longitude) / 2.0);
+        let haversine = half_latitude * half_latitude
+            + Trig::cos(self.latitude)
+                * Trig::cos(other.latitude)
+                * half_longitude
+                * half_longitude;
+        let haversine = haversine.clamp(0.0, 1.0);
+        2.0 * MEAN_EARTH_RADIUS * Trig::atan2(Sqrt::sqrt(haversine), Sqrt::sqrt(1.0 - haversine))
+    }
+
+    /// Find point reached by following great circle with given initial bearing in radians
+    /// for given distance in meters, see [`Geodetic::distance_to`]. Altitude is kept,
+    /// longitude is wrapped into the `(-pi, pi]` range.
+    /// ```
+    /// # use spatial::geo::Geodetic;
+    /// let start = Geodetic::from_degrees(-33.9, 151.2, 10.0);
+    /// let end = start.destination(1.2, 250e3);
+    /// assert!((start.distance_to(&end) - 250e3).abs() < 1e-6);
+    /// assert!((start.bearing_to(&end) - 1.2).abs() < 1e-9);
+    /// ```
+    pub fn destination(&self, bearing: f64, distance: f64) -> Self {
+        let angle = distance / MEAN_EARTH_RADIUS;
+        let (sin_angle, cos_angle) = (Trig::sin(angle), Trig::cos(angle));
+        let (sin_from, cos_from) = (Trig::sin(self.latitude), Trig::cos(self.latitude));
+        let sin_to =
+            (sin_from * cos_angle + cos_from * sin_angle * Trig::cos(bearing)).clamp(-1.0, 1.0);
+        let latitude = Trig::atan2(sin_to, Sqrt::sqrt(1.0 - sin_to * sin_to));
+        let longitude = self.longitude
+            + Trig::atan2(
+                Trig::sin(bearing) * sin_angle * cos_from,
+                cos_angle - sin_from * sin_to,
+            );
+        let longitude = Trig::atan2(Trig::sin(longitude), Trig::cos(longitude));
+        Self::new(latitude, longitude, self.altitude)
+    }
+
+    /// Get unit direction of given bearing in radians as local east, north, up vector,
+    /// see [`LocalTangent`].
+    pub fn bearing_direction(bearing: f64) -> Vector<f64> {
+        Vector::new(Trig::sin(bearing), Trig::cos(bearing), 0.0)
+    }
+}
+
+/// Local tangent plane frame with east, north and up axes anchored at geodetic origin.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::geo::{Geodetic, LocalTangent};
+/// let home = LocalTangent::new(Geodetic::from_degrees(47.0, 8.0, 400.0));
+/// let fix = Geodetic::from_degrees(47.001, 8.0, 410.0);
+/// let local = home.geodetic_to_local(fix);
+/// assert!((local.y() - 111.2).abs() < 0.1 && local.x().abs() < 1e-6);
+/// let back = home.local_to_geodetic(local);
+/// assert!((back.latitude() - fix.latitude()).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocalTangent {
+    origin: Geodetic,
+    pose: Pose<f64, f64>,
+}
+
+impl LocalTangent {
+    /// Create east-north-up frame tangent to the ellipsoid at given origin.
+    pub fn new(origin: Geodetic) -> Self {
+        let azimuth = (origin.longitude + core::f64::consts::FRAC_PI_2) / 2.0;
+        let elevation = (core::f64::consts::FRAC_PI_2 - origin.latitude) / 2.0;
+        let around_z = Quaternion::with_wijk(Trig::cos(azimuth), 0.0, 0.0, Trig::sin(azimuth));
+        let around_x = Quaternion::with_wijk(Trig::cos(elevation), Trig::sin(elevation), 0.0, 0.0);
+        Self {
+            origin,
+            pose: Pose::new(origin.to_ecef(), around_z * around_x),
+        }
+    }
+
+    /// Get geodetic origin.
+    pub fn origin(&self) -> Geodetic {
+        self.origin
+    }
+
+    /// Get pose of the local frame in Earth-centered Earth-fixed frame.
+    pub fn pose(&self) -> Pose<f64, f64> {
+        self.pose
+    }
+
+    /// Convert Earth-centered Earth-fixed position into local east, north, up position.
+    pub fn to_local(&self, ecef: Vector<f64>) -> Vector<f64> {
+        self.pose
+            .rotation()
+            .inverse()
+            .rotate(ecef - self.pose.translation())
+    }
+
+    /// Convert local east, north, up position into Earth-centered Earth-fixed position.
+    pub fn to_ecef(&self, local: Vector<f64>) -> Vector<f64> {
+        self.pose.apply_to(local)
+    }
+
+    /// Convert geodetic coordinates into local east, north, up position.
+    pub fn geodetic_to_local(&self, geodetic: Geodetic) -> Vector<f64> {
+        self.to_local(geodetic.to_ecef())
+    }
+
+    /// Convert local east, north, up position into geodetic coordinates.
+    pub fn local_to_geodetic(&self, local: Vector<f64>) -> Geodetic {
+        Geodetic::from_ecef(self.to_ecef(local))
+    }
+
+    /// Convert pose expressed in Earth-centered Earth-fixed frame into the local frame.
+    pub fn pose_to_local(&self, pose: Pose<f64, f64>) -> Pose<f64, f64> {
+        self.pose.inverse() * pose
+    }
+
+    /// Convert pose expressed in the local frame into Earth-centered Earth-fixed frame.
+    pub fn pose_to_ecef(&self, pose: Pose<f64, f64>) -> Pose<f64, f64> {
+        self.pose * pose
+    }
+}
+
+/// Calculate radius of curvature in the prime vertical for given sine of latitude.
+fn prime_vertical_radius(sin_latitude: f64) -> f64 {
+    WGS84_SEMI_MAJOR_AXIS / Sqrt::sqrt(1.0 - ECCENTRICITY_SQUARED * sin_latitude * sin_latitude)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Geodetic, LocalTangent, WGS84_SEMI_MAJOR_AXIS};
+    use crate::vector::Vector;
+
+    #[test]
+    fn geodetic_round_trips_through_ecef_and_local_frame() {
+        let equator = Geodetic::new(0.0, 0.0, 0.0).to_ecef();
+        assert_eq!(equator, Vector::new(WGS84_SEMI_MAJOR_AXIS, 0.0, 0.0));
+        let pole = Geodetic::from_degrees(90.0, 0.0, 0.0).to_ecef();
+        assert!((pole.z() - 6_356_752.314_245).abs() < 1e-5);
+        for (latitude, longitude, altitude) in [
+            (89.999, -120.0, 1e3),
+            (-33.9, 151.2, -20.0),
+            (0.0, 180.0, 35_786e3),
+            (52.5, 13.4, 100.0),
+        ] {
+            let fix = Geodetic::from_degrees(latitude, longitude, altitude);
+            let back = Geodetic::from_ecef(fix.to_ecef());
+            assert!((back.latitude() - fix.latitude()).abs() < 1e-11);
+            assert!((back.to_ecef() - fix.to_ecef()).norm() < 1e-3);
+            let frame = LocalTangent::new(fix);
+            let up = frame.geodetic_to_local(Geodetic::new(
+                fix.latitude(),
+                fix.longitude(),
+                altitude + 10.0,
+            ));
+            assert!((up - Vector::new(0.0, 0.0, 10.0)).norm() < 1e-6);
+            let point = Vector::new(120.0, -45.0, 3.0);
+            assert!(
+                (frame.geodetic_to_local(frame.local_to_geodetic(point)) - point).norm() < 1e-6
+            );
+        }
+    }
+}
@@ -0,0 +1,1110 @@
+use crate::ops::Real;
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::{max, min, Vector};
+use core::ops::Mul;
+
+/// Plane of points `p` satisfying `normal.dot(p) = offset` with unit normal.
+///
+/// Signed distance is positive on the side the normal points to.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::geometry::Plane;
+/// let table = Plane::from_point_normal(Vector::new(0.0, 0.0, 0.5), Vector::new(0.0, 0.0, 2.0)).unwrap();
+/// assert_eq!(table.signed_distance(Vector::new(1.0, 2.0, 1.5)), 1.0);
+/// assert_eq!(table.project(Vector::new(1.0, 2.0, 1.5)), Vector::new(1.0, 2.0, 0.5));
+/// let hit = table.intersect_ray(Vector::new(0.0, 0.0, 1.0), Vector::new(1.0, 0.0, -1.0));
+/// assert_eq!(hit, Some(Vector::new(0.5, 0.0, 0.5)));
+/// let lifted = table.transform(Pose::new(Vector::new(0.0, 0.0, 1.0), Quaternion::identity()));
+/// assert_eq!(lifted.offset(), 1.5);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane<T> {
+    normal: Vector<T>,
+    offset: T,
+}
+
+impl<T> Plane<T>
+where
+    T: Real,
+{
+    /// Create plane from normal and offset along it, normal is normalized and the offset
+    /// is scaled accordingly. Returns `None` for zero normal.
+    pub fn new(normal: Vector<T>, offset: T) -> Option<Self> {
+        let norm: T = normal.norm();
+        let normal = normal.normalized_checked()?;
+        Some(Self {
+            normal,
+            offset: offset / norm,
+        })
+    }
+
+    /// Create plane passing through given point with given normal, normal is normalized.
+    /// Returns `None` for zero normal.
+    pub fn from_point_normal(point: Vector<T>, normal: Vector<T>) -> Option<Self> {
+        let normal = normal.normalized_checked()?;
+        Some(Self {
+            normal,
+            offset: normal.dot(point),
+        })
+    }
+
+    /// Get unit normal.
+    pub fn normal(&self) -> Vector<T> {
+        self.normal
+    }
+
+    /// Get signed distance from origin to the plane along normal.
+    pub fn offset(&self) -> T {
+        self.offset
+    }
+
+    /// Get point of the plane closest to origin.
+    pub fn point(&self) -> Vector<T> {
+        self.normal * self.offset
+    }
+
+    /// Calculate signed distance from the plane to given point.
+    pub fn signed_distance(&self, point: Vector<T>) -> T {
+        self.normal.dot(point) - self.offset
+    }
+
+    /// Project point onto the plane along normal.
+    pub fn project(&self, point: Vector<T>) -> Vector<T> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Reflect point across the plane.
+    pub fn reflect(&self, point: Vector<T>) -> Vector<T> {
+        let two = T::one() + T::one();
+        point - self.normal * (two * self.signed_distance(point))
+    }
+
+    /// Intersect line passing through `origin` along `direction` with the plane,
+    /// returns `None` if the line is parallel to the plane.
+    pub fn intersect_line(&self, origin: Vector<T>, direction: Vector<T>) -> Option<Vector<T>> {
+        self.line_parameter(origin, direction)
+            .map(|parameter| origin + direction * parameter)
+    }
+
+    /// Intersect ray starting at `origin` and pointing along `direction` with the plane,
+    /// returns `None` if the ray is parallel to the plane or points away from it.
+    pub fn intersect_ray(&self, origin: Vector<T>, direction: Vector<T>) -> Option<Vector<T>> {
+        self.line_parameter(origin, direction)
+            .filter(|parameter| *parameter >= T::zero())
+            .map(|parameter| origin + direction * parameter)
+    }
+
+    /// Move plane expressed in child frame of the pose into its parent frame.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        let normal = pose.rotation().rotate(self.normal);
+        Self {
+            normal,
+            offset: self.offset + normal.dot(pose.translation()),
+        }
+    }
+
+    /// Calculate parameter `t` of the line point `origin + direction * t` lying on the plane.
+    fn line_parameter(&self, origin: Vector<T>, direction: Vector<T>) -> Option<T> {
+        let projection: T = self.normal.dot(direction);
+        if projection.abs_value() <= T::epsilon() * direction.norm() {
+            None
+        } else {
+            Some(-self.signed_distance(origin) / projection)
+        }
+    }
+}
+
+/// Infinite line passing through a point along unit direction.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::geometry::Line;
+/// let first = Line::new(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 0.0, 0.0)).unwrap();
+/// let second = Line::through(Vector::new(1.0, -1.0, 1.0), Vector::new(1.0, 1.0, 1.0)).unwrap();
+/// assert_eq!(first.distance(Vector::new(5.0, 3.0, 4.0)), 5.0);
+/// let (a, b) = first.closest_points(&second);
+/// assert_eq!((a, b), (Vector::new(1.0, 0.0, 0.0), Vector::new(1.0, 0.0, 1.0)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Line<T> {
+    point: Vector<T>,
+    direction: Vector<T>,
+}
+
+impl<T> Line<T>
+where
+    T: Real,
+{
+    /// Create line through given point along given direction, direction is normalized.
+    /// Returns `None` for zero direction.
+    pub fn new(point: Vector<T>, direction: Vector<T>) -> Option<Self> {
+        let direction = direction.normalized_checked()?;
+        Some(Self { point, direction })
+    }
+
+    /// Create line through two points, returns `None` if the points coincide.
+    pub fn through(from: Vector<T>, to: Vector<T>) -> Option<Self> {
+        Self::new(from, to - from)
+    }
+
+    /// Get point the line was created with.
+    pub fn point(&self) -> Vector<T> {
+        self.point
+    }
+
+    /// Get unit direction.
+    pub fn direction(&self) -> Vector<T> {
+        self.direction
+    }
+
+    /// Get point at given signed distance from the stored point along direction.
+    pub fn at(&self, parameter: T) -> Vector<T> {
+        self.point + self.direction * parameter
+    }
+
+    /// Calculate signed distance along direction to the point of the line closest to given one.
+    pub fn closest_parameter(&self, point: Vector<T>) -> T {
+        self.direction.dot(point - self.point)
+    }
+
+    /// Find point of the line closest to given point.
+    pub fn closest_point(&self, point: Vector<T>) -> Vector<T> {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// Calculate distance from the line to given point.
+    pub fn distance(&self, point: Vector<T>) -> T {
+        (point - self.closest_point(point)).norm()
+    }
+
+    /// Find pair of closest points on this and other line, for skew lines they are the ends
+    /// of the common perpendicular. For parallel lines the stored point of this line and its
+    /// projection onto the other line are returned.
+    pub fn closest_points(&self, other: &Self) -> (Vector<T>, Vector<T>) {
+        let (first, second) =
+            closest_parameters((self.point, self.direction), (other.point, other.direction));
+        (self.at(first), other.at(second))
+    }
+
+    /// Calculate distance between this and other line.
+    pub fn distance_to_line(&self, other: &Self) -> T {
+        let (a, b) = self.closest_points(other);
+        (b - a).norm()
+    }
+
+    /// Intersect line with the plane, see [`Plane::intersect_line`].
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<Vector<T>> {
+        plane.intersect_line(self.point, self.direction)
+    }
+
+    /// Move line expressed in child frame of the pose into its parent frame.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        Self {
+            point: pose.apply_to(self.point),
+            direction: pose.rotation().rotate(self.direction),
+        }
+    }
+}
+
+/// Half-line starting at origin and extending along unit direction.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::geometry::{Plane, Ray};
+/// let ray = Ray::new(Vector::new(0.0, 0.0, 1.0), Vector::new(0.0, 0.0, -3.0)).unwrap();
+/// assert_eq!(ray.closest_point(Vector::new(1.0, 0.0, 2.0)), Vector::new(0.0, 0.0, 1.0));
+/// assert_eq!(ray.distance(Vector::new(1.0, 0.0, -2.0)), 1.0);
+/// let floor = Plane::new(Vector::new(0.0, 0.0, 1.0), -1.0).unwrap();
+/// assert_eq!(ray.intersect_plane(&floor), Some(Vector::new(0.0, 0.0, -1.0)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray<T> {
+    origin: Vector<T>,
+    direction: Vector<T>,
+}
+
+impl<T> Ray<T>
+where
+    T: Real,
+{
+    /// Create ray starting at given origin along given direction, direction is normalized.
+    /// Returns `None` for zero direction.
+    pub fn new(origin: Vector<T>, direction: Vector<T>) -> Option<Self> {
+        let direction = direction.normalized_checked()?;
+        Some(Self { origin, direction })
+    }
+
+    /// Get ray origin.
+    pub fn origin(&self) -> Vector<T> {
+        self.origin
+    }
+
+    /// Get unit direction.
+    pub fn direction(&self) -> Vector<T> {
+        self.direction
+    }
+
+    /// Get line containing the ray.
+    pub fn line(&self) -> Line<T> {
+        Line {
+            point: self.origin,
+            direction: self.direction,
+        }
+    }
+
+    /// Get point at given distance from origin along direction.
+    pub fn at(&self, parameter: T) -> Vector<T> {
+        self.origin + self.direction * parameter
+    }
+
+    /// Find point of the ray closest to given point, origin for points behind the ray.
+    pub fn closest_point(&self, point: Vector<T>) -> Vector<T> {
+        let parameter = self.line().closest_parameter(point);
+        if parameter > T::zero() {
+            self.at(parameter)
+        } else {
+            self.origin
+        }
+    }
+
+    /// Calculate distance from the ray to given point.
+    pub fn distance(&self, point: Vector<T>) -> T {
+        (point - self.closest_point(point)).norm()
+    }
+
+    /// Intersect ray with the plane, see [`Plane::intersect_ray`].
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<Vector<T>> {
+        plane.intersect_ray(self.origin, self.direction)
+    }
+
+    /// Move ray expressed in child frame of the pose into its parent frame.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        Self {
+            origin: pose.apply_to(self.origin),
+            direction: pose.rotation().rotate(self.direction),
+        }
+    }
+}
+
+/// Line segment between two points.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::geometry::Segment;
+/// let first = Segment::new(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 0.0, 0.0));
+/// let second = Segment::new(Vector::new(3.0, -1.0, 1.0), Vector::new(3.0, 1.0, 1.0));
+/// let (a, b) = first.closest_points(&second);
+/// assert_eq!((a, b), (Vector::new(2.0, 0.0, 0.0), Vector::new(3.0, 0.0, 1.0)));
+/// assert_eq!(first.distance(Vector::new(1.0, 3.0, 4.0)), 5.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Segment<T> {
+    start: Vector<T>,
+    end: Vector<T>,
+}
+
+impl<T> Segment<T>
+where
+    T: Real,
+{
+    /// Create segment between given points.
+    pub const fn new(start: Vector<T>, end: Vector<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Get start point.
+    pub fn start(&self) -> Vector<T> {
+        self.start
+    }
+
+    /// Get end point.
+    pub fn end(&self) -> Vector<T> {
+        self.end
+    }
+
+    /// Get segment length.
+    pub fn length(&self) -> T {
+        (self.end - self.start).norm()
+    }
+
+    /// Get point at given fraction of the way from start to end.
+    pub fn at(&self, parameter: T) -> Vector<T> {
+        self.start + (self.end - self.start) * parameter
+    }
+
+    /// Find point of the segment closest to given point.
+    pub fn closest_point(&self, point: Vector<T>) -> Vector<T> {
+        let direction = self.end - self.start;
+        let squared: T = direction.dot(direction);
+        if squared <= T::epsilon() {
+            return self.start;
+        }
+        self.at(clamp_01(direction.dot(point - self.start) / squared))
+    }
+
+    /// Calculate distance from the segment to given point.
+    pub fn distance(&self, point: Vector<T>) -> T {
+        (point - self.closest_point(point)).norm()
+    }
+
+    /// Find pair of closest points on this and other segment. Degenerate segments
+    /// are treated as points, for parallel segments one of the closest pairs is returned.
+    pub fn closest_points(&self, other: &Self) -> (Vector<T>, Vector<T>) {
+        let first = self.end - self.start;
+        let second = other.end - other.start;
+        let offset = self.start - other.start;
+        let first_squared: T = first.dot(first);
+        let second_squared: T = second.dot(second);
+        let along_second: T = second.dot(offset);
+        let (s, t) = if first_squared <= T::epsilon() && second_squared <= T::epsilon() {
+            (T::zero(), T::zero())
+        } else if first_squared <= T::epsilon() {
+            (T::zero(), clamp_01(along_second / second_squared))
+        } else {
+            let along_first: T = first.dot(offset);
+            if second_squared <= T::epsilon() {
+                (clamp_01(-along_first / first_squared), T::zero())
+            } else {
+                let cross: T = first.dot(second);
+                let denominator = first_squared * second_squared - cross * cross;
+                let s = if denominator > T::zero() {
+                    clamp_01((cross * along_second - along_first * second_squared) / denominator)
+                } else {
+                    T::zero()
+                };
+                let t = (cross * s + along_second) / second_squared;
+                if t < T::zero() {
+                    (clamp_01(-along_first / first_squared), T::zero())
+                } else if t > T::one() {
+                    (clamp_01((cross - along_first) / first_squared), T::one())
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), other.at(t))
+    }
+
+    /// Calculate distance between this and other segment.
+    pub fn distance_to_segment(&self, other: &Self) -> T {
+        let (a, b) = self.closest_points(other);
+        (b - a).norm()
+    }
+
+    /// Find pair of closest points on the segment and the line, for segments parallel to
+    /// the line the start point and its projection onto the line are returned.
+    pub fn closest_points_to_line(&self, line: &Line<T>) -> (Vector<T>, Vector<T>) {
+        let point = match Line::through(self.start, self.end) {
+            Some(axis) => {
+                let (along, _) =
+                    closest_parameters((axis.point, axis.direction), (line.point, line.direction));
+                axis.at(min(max(along, T::zero()), self.length()))
+            }
+            None => self.start,
+        };
+        (point, line.closest_point(point))
+    }
+
+    /// Calculate distance between the segment and the line.
+    pub fn distance_to_line(&self, line: &Line<T>) -> T {
+        let (a, b) = self.closest_points_to_line(line);
+        (b - a).norm()
+    }
+
+    /// Move segment expressed in child frame of the pose into its parent frame.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        Self {
+            start: pose.apply_to(self.start),
+            end: pose.apply_to(self.end),
+        }
+    }
+}
+
+/// Capsule of points within given radius from a segment, e.g. bounding volume of a link.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::geometry::{Capsule, Segment};
+/// let upper = Capsule::new(Segment::new(Vector::zero(), Vector::new(0.0, 0.0, 1.0)), 0.1);
+/// let lower = Capsule::new(Segment::new(Vector::new(0.15, 0.0, 0.5), Vector::new(1.0, 0.0, 0.5)), 0.1);
+/// assert!(upper.overlaps(&lower));
+/// assert!((upper.distance(&lower) + 0.05_f64).abs() < 1e-12);
+/// assert!(!upper.contains(Vector::new(0.2, 0.0, 0.0)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Capsule<T> {
+    segment: Segment<T>,
+    radius: T,
+}
+
+impl<T> Capsule<T>
+where
+    T: Real,
+{
+    /// Create capsule around segment with given non-negative radius.
+    pub const fn new(segment: Segment<T>, radius: T) -> Self {
+        Self { segment, radius }
+    }
+
+    /// Get axis segment.
+    pub fn segment(&self) -> Segment<T> {
+        self.segment
+    }
+
+    /// Get radius.
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    /// Check whether point lies inside the capsule or on its surface.
+    pub fn contains(&self, point: Vector<T>) -> bool {
+        self.segment.distance(point) <= self.radius
+    }
+
+    /// Calculate distance between capsule surfaces, negative values are penetration depth.
+    pub fn distance(&self, other: &Self) -> T {
+        self.segment.distance_to_segment(&other.segment) - self.radius - other.radius
+    }
+
+    /// Check whether capsules intersect, touching capsules are considered intersecting.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let (a, b) = self.segment.closest_points(&other.segment);
+        let gap = b - a;
+        let radius = self.radius + other.radius;
+        gap.dot(gap) <= radius * radius
+    }
+
+    /// Move capsule expressed in child frame of the pose into its parent frame.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        Self {
+            segment: self.segment.transform(pose),
+            radius: self.radius,
+        }
+    }
+}
+
+/// Triangle given by three vertices, front face has counter-clockwise vertex order.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::geometry::{Ray, Triangle};
+/// let triangle = Triangle::new(
+///     Vector::new(0.0, 0.0, 0.0),
+///     Vector::new(2.0, 0.0, 0.0),
+///     Vector::new(0.0, 2.0, 0.0),
+/// );
+/// assert_eq!(triangle.normal(), Some(Vector::new(0.0, 0.0, 1.0)));
+/// let ray = Ray::new(Vector::new(0.5, 0.5, 3.0), Vector::new(0.0, 0.0, -1.0)).unwrap();
+/// let (distance, weights) = triangle.intersect_ray(&ray).unwrap();
+/// assert_eq!(distance, 3.0);
+/// assert_eq!(weights, [0.5, 0.25, 0.25]);
+/// assert_eq!(triangle.point_at(weights), Vector::new(0.5, 0.5, 0.0));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle<T> {
+    vertices: [Vector<T>; 3],
+}
+
+impl<T> Triangle<T>
+where
+    T: Real,
+{
+    /// Create triangle with given vertices.
+    pub const fn new(a: Vector<T>, b: Vector<T>, c: Vector<T>) -> Self {
+        Self {
+            vertices: [a, b, c],
+        }
+    }
+
+    /// Get vertices.
+    pub fn vertices(&self) -> [Vector<T>; 3] {
+        self.vertices
+    }
+
+    /// Get unit normal of the front face, `None` for degenerate triangle.
+    pub fn normal(&self) -> Option<Vector<T>> {
+        let [a, b, c] = self.vertices;
+        (b - a).cross(c - a).normalized_checked()
+    }
+
+    /// Get triangle area.
+    pub fn area(&self) -> T {
+        let [a, b, c] = self.vertices;
+        let doubled: Vector<T> = (b - a).cross(c - a);
+        doubled.norm() / (T::one() + T::one())
+    }
+
+    /// Get plane containing the triangle, `None` for degenerate triangle.
+    pub fn plane(&self) -> Option<Plane<T>> {
+        Plane::from_point_normal(self.vertices[0], self.normal()?)
+    }
+
+    /// Get point with given barycentric coordinates.
+    pub fn point_at(&self, [u, v, w]: [T; 3]) -> Vector<T> {
+        let [a, b, c] = self.vertices;
+        a * u + b * v + c * w
+    }
+
+    /// Calculate barycentric coordinates of the point projected onto the triangle plane,
+    /// all of them are non-negative for points inside the triangle.
+    /// Returns `None` for degenerate triangle.
+    pub fn barycentric(&self, point: Vector<T>) -> Option<[T; 3]> {
+        let [a, b, c] = self.vertices;
+        let (first, second, offset) = (b - a, c - a, point - a);
+        let first_squared: T = first.dot(first);
+        let cross: T = first.dot(second);
+        let second_squared: T = second.dot(second);
+        let along_first: T = offset.dot(first);
+        let along_second: T = offset.dot(second);
+        let denominator = first_squared * second_squared - cross * cross;
+        if denominator <= T::epsilon() * first_squared * second_squared {
+            return None;
+        }
+        let v = (second_squared * along_first - cross * along_second) / denominator;
+        let w = (first_squared * along_second - cross * along_first) / denominator;
+        Some([T::one() - v - w, v, w])
+    }
+
+    /// Intersect ray with the triangle using Moller-Trumbore algorithm, both faces are hit.
+    /// Returns distance along the ray and barycentric coordinates of the hit point.
+    pub fn intersect_ray(&self, ray: &Ray<T>) -> Option<(T, [T; 3])> {
+        let [a, b, c] = self.vertices;
+        let (first, second) = (b - a, c - a);
+        let perpendicular: Vector<T> = ray.direction().cross(second);
+        let determinant: T = first.dot(perpendicular);
+        if determinant.abs_value() <= T::epsilon() * first.norm() * second.norm() {
+            return None;
+        }
+        let offset = ray.origin() - a;
+        let v = offset.dot(perpendicular) / determinant;
+        if v < T::zero() || v > T::one() {
+            return None;
+        }
+        let normal: Vector<T> = offset.cross(first);
+        let w = ray.direction().dot(normal) / determinant;
+        if w < T::zero() || v + w > T::one() {
+            return None;
+        }
+        let distance = second.dot(normal) / determinant;
+        if distance < T::zero() {
+            return None;
+        }
+        Some((distance, [T::one() - v - w, v, w]))
+    }
+
+    /// Move triangle expressed in child frame of the pose into its parent frame.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        Self {
+            vertices: self.vertices.map(|vertex| pose.apply_to(vertex)),
+        }
+    }
+}
+
+/// Axis-aligned bounding box given by minimal and maximal corners.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::geometry::Aabb;
+/// let points = [Vector::new(1.0, 0.0, 2.0), Vector::new(-1.0, 3.0, 0.0)];
+/// let bounds = Aabb::from_points(points).unwrap();
+/// assert_eq!(bounds.min(), Vector::new(-1.0, 0.0, 0.0));
+/// assert!(bounds.contains(Vector::new(0.0, 1.0, 1.0)));
+/// let turn = Quaternion::from_angle_axis(core::f64::consts::FRAC_PI_2, Vector::unit_z());
+/// let moved = bounds.transform(Pose::new(Vector::new(10.0, 0.0, 0.0), turn));
+/// assert!((moved.max() - Vector::new(10.0, 1.0, 2.0)).norm() < 1e-12);
+/// assert!(!moved.overlaps(&bounds));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb<T> {
+    min: Vector<T>,
+    max: Vector<T>,
+}
+
+impl<T> Aabb<T>
+where
+    T: Real,
+{
+    /// Create box with given opposite corners in any order.
+    pub fn new(a: Vector<T>, b: Vector<T>) -> Self {
+        Self {
+            min: a.component_min(b),
+            max: a.component_max(b),
+        }
+    }
+
+    /// Create smallest box containing all points, returns `None` for no points.
+    pub fn from_points<I>(points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Vector<T>>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |bounds, point| {
+            bounds.union(&Self::new(point, point))
+        }))
+    }
+
+    /// Get corner with minimal coordinates.
+    pub fn min(&self) -> Vector<T> {
+        self.min
+    }
+
+    /// Get corner with maximal coordinates.
+    pub fn max(&self) -> Vector<T> {
+        self.max
+    }
+
+    /// Get box center.
+    pub fn center(&self) -> Vector<T> {
+        (self.min + self.max) / (T::one() + T::one())
+    }
+
+    /// Get half of the box size along every axis.
+    pub fn half_extents(&self) -> Vector<T> {
+        (self.max - self.min) / (T::one() + T::one())
+    }
+
+    /// Create smallest box containing both boxes.
+    pub fn union(&self, other: &Self) -> Self {
+        let (a, b) = (self, other);
+        Self {
+            min: a.min.component_min(b.min),
+            max: a.max.component_max(b.max),
+        }
+    }
+
+    /// Create largest box contained in both boxes, `None` if they do not overlap, see
+    /// [`Aabb::overlaps`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::geometry::Aabb;
+    /// let first = Aabb::new(Vector::new(0.0, 0.0, 0.0), Vector::new(2.0, 2.0, 2.0));
+    /// let second = Aabb::new(Vector::new(1.0, -1.0, 2.0), Vector::new(3.0, 1.0, 4.0));
+    /// let common = first.intersection(&second).unwrap();
+    /// assert_eq!((common.min(), common.max()), (Vector::new(1.0, 0.0, 2.0), Vector::new(2.0, 1.0, 2.0)));
+    /// assert_eq!(first.intersection(&Aabb::new(Vector::new(3.0, 0.0, 0.0), Vector::new(4.0, 1.0, 1.0))), None);
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        let (a, b) = (self, other);
+        Some(Self {
+            min: a.min.component_max(b.min),
+            max: a.max.component_min(b.max),
+        })
+    }
+
+    /// Check whether point lies inside the box or on its boundary.
+    pub fn contains(&self, point: Vector<T>) -> bool {
+        let (min, max) = (self.min, self.max);
+        (min.x() <= point.x() && point.x() <= max.x())
+            && (min.y() <= point.y() && point.y() <= max.y())
+            && (min.z() <= point.z() && point.z() <= max.z())
+    }
+
+    /// Check whether boxes intersect, touching boxes are considered intersecting.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let (a, b) = (self, other);
+        (a.min.x() <= b.max.x() && b.min.x() <= a.max.x())
+            && (a.min.y() <= b.max.y() && b.min.y() <= a.max.y())
+            && (a.min.z() <= b.max.z() && b.min.z() <= a.max.z())
+    }
+
+    /// Create smallest axis-aligned box in parent frame of the pose containing this box
+    /// expressed in its child frame.
+    pub fn transform(&self, pose: Pose<T, T>) -> Self {
+        let center = pose.apply_to(self.center());
+        let half = <[T; 3]>::from(self.half_extents());
+        let extents = pose.rotation().prepare().matrix().map(|row| {
+            (row[0] * half[0]).abs_value()
+                + (row[1] * half[1]).abs_value()
+                + (row[2] * half[2]).abs_value()
+        });
+        let extents = Vector::from(extents);
+        Self {
+            min: center - extents,
+            max: center + extents,
+        }
+    }
+}
+
+/// Oriented bounding box given by center, half extents along its own axes and rotation
+/// of these axes.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::geometry::Obb;
+/// let turn = Quaternion::from_angle_axis(core::f64::consts::FRAC_PI_4, Vector::unit_z());
+/// let diamond = Obb::new(Vector::zero(), Vector::new(1.0, 1.0, 1.0), turn);
+/// let cube = Obb::new(Vector::new(2.3, 0.0, 0.0), Vector::new(1.0, 1.0, 1.0), Quaternion::identity());
+/// assert!(diamond.overlaps(&cube));
+/// let cube = Obb::new(Vector::new(2.5, 2.5, 0.0), Vector::new(1.0, 1.0, 1.0), Quaternion::identity());
+/// assert!(!diamond.overlaps(&cube));
+/// assert!(diamond.contains(Vector::new(1.3, 0.0, 0.0)));
+/// let bounds = diamond.aabb();
+/// assert!((bounds.max().x() - 2.0_f64.sqrt()).abs() < 1e-12);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Obb<T> {
+    center: Vector<T>,
+    half_extents: Vector<T>,
+    rotation: Quaternion<T>,
+}
+
+impl<T> Obb<T>
+where
+    T: Real,
+{
+    /// Create box with given center, non-negative half extents and unit rotation of its axes.
+    pub const fn new(center: Vector<T>, half_extents: Vector<T>, rotation: Quaternion<T>) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    /// Create smallest box with axes rotated by given unit rotation containing all points,
+    /// returns `None` for no points.
+    pub fn from_points<I>(rotation: Quaternion<T>, points: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Vector<T>>,
+    {
+        let inverse = rotation.inverse();
+        let local = Aabb::from_points(points.into_iter().map(|point| inverse.rotate(point)))?;
+        Some(Self {
+            center: rotation.rotate(local.center()),
+            half_extents: local.half_extents(),
+            rotation,
+        })
+    }
+
+    /// Create oriented box equal to axis-aligned one.
+    pub fn from_aabb(aabb: &Aabb<T>) -> Self {
+        Self::new(aabb.center(), aabb.half_extents(), Quaternion::identity())
+    }
+
+    /// Get box center.
+    pub fn center(&self) -> Vector<T> {
+        self.center
+    }
+
+    /// Get half of the box size along every box axis.
+    pub fn half_extents(&self) -> Vector<T> {
+        self.half_extents
+    }
+
+    /// Get rotation of box axes.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.rotation
+    }
+
+    /// Check whether point lies inside the box or on its boundary.
+    pub fn contains(&self, point: Vector<T>) -> bool {
+        let local = self.rotation.inverse().rotate(point - self.center);
+        let bounds = Aabb::new(-self.half_extents, self.half_extents);
+        bounds.contains(local)
+    }
+
+    /// Check whether boxes intersect using separating axis test.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let rotation = (self.rotation.inverse() * other.rotation)
+            .prepare()
+            .matrix();
+        let offset = <[T; 3]>::from(self.rotation.inverse().rotate(other.center - self.center));
+        let a = <[T; 3]>::from(self.half_extents);
+        let b = <[T; 3]>::from(other.half_extents);
+        let absolute = rotation.map(|row| row.map(|value| value.abs_value() + T::epsilon()));
+        let separated = |distance: T, a: T, b: T| distance.abs_value() > a + b;
+        for i in 0..3 {
+            let radius = b[0] * absolute[i][0] + b[1] * absolute[i][1] + b[2] * absolute[i][2];
+            if separated(offset[i], a[i], radius) {
+                return false;
+            }
+        }
+        for j in 0..3 {
+            let radius = a[0] * absolute[0][j] + a[1] * absolute[1][j] + a[2] * absolute[2][j];
+            let distance = offset[0] * rotation[0][j]
+                + offset[1] * rotation[1][j]
+                + offset[2] * rotation[2][j];
+            if separated(distance, radius, b[j]) {
+                return false;
+            }
+        }
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let first = a[i1] * absolute[i2][j] + a[i2] * absolute[i1][j];
+                let second = b[j1] * absolute[i][j2] + b[j2] * absolute[i][j1];
+                let distance = offset[i2] * rotation[i1][j] - offset[i1] * rotation[i2][j];
+                if separated(distance, first, second) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Create smallest axis-aligned box containing this box.
+    pub fn aabb(&self) -> Aabb<T> {
+        let local = Aabb::new(-self.half_extents, self.half_extents);
+        local.transform(Pose::new(self.center, self.rotation))
+    }
+
+    /// Move box expressed in child frame of the pose into its parent frame.
+    pub fn transform(self, pose: Pose<T, T>) -> Self {
+        Self {
+            center: pose.apply_to(self.center),
+            half_extents: self.half_extents,
+            rotation: pose.rotation() * self.rotation,
+        }
+    }
+}
+
+/// Implement `pose * primitive` as moving the primitive from child frame of the pose into its
+/// parent frame for given primitive types.
+macro_rules! impl_pose_transform {
+    ($($primitive:ident),+) => {$(
+        impl<T: Real> Mul<$primitive<T>> for Pose<T, T> {
+            type Output = $primitive<T>;
+            fn mul(self, primitive: $primitive<T>) -> Self::Output {
+                primitive.transform(self)
+            }
+        }
+    )+};
+}
+
+impl_pose_transform!(Plane, Line, Ray, Segment, Capsule, Triangle, Aabb, Obb);
+
+/// Calculate parameters of closest points of two lines given as point and unit direction.
+fn closest_parameters<T>(
+    (first, a): (Vector<T>, Vector<T>),
+    (second, b): (Vector<T>, Vector<T>),
+) -> (T, T)
+where
+    T: Real,
+{
+    let offset = first - second;
+    let cos: T = a.dot(b);
+    let along_first: T = a.dot(offset);
+    let along_second: T = b.dot(offset);
+    let sin_squared = T::one() - cos * cos;
+    if sin_squared <= T::epsilon() {
+        (T::zero(), along_second)
+    } else {
+        (
+            (cos * along_second - along_first) / sin_squared,
+            (along_second - cos * along_first) / sin_squared,
+        )
+    }
+}
+
+/// Helper absolute value for scalars providing only ordering and negation.
+trait AbsValue {
+    fn abs_value(self) -> Self;
+}
+
+impl<T: Real> AbsValue for T {
+    fn abs_value(self) -> Self {
+        if self < T::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+}
+
+/// Clamp value to the `[0, 1]` range.
+fn clamp_01<T: Real>(value: T) -> T {
+    min(max(value, T::zero()), T::one())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Aabb, Capsule, Line, Obb, Plane, Ray, Segment, Triangle};
+    use crate::pose::test_pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn transformed_plane_matches_transformed_points() {
+        let plane =
+            Plane::from_point_normal(Vector::new(1.0, -2.0, 0.5), Vector::new(1.0, 1.0, 2.0))
+                .unwrap();
+        let pose = test_pose();
+        let transformed = plane.transform(pose);
+        assert_eq!(pose * plane, transformed);
+        for point in [Vector::new(1.0, 2.0, 3.0), Vector::new(-4.0, 0.5, 0.0)] {
+            let distance = plane.signed_distance(point);
+            let moved = transformed.signed_distance(pose.apply_to(point));
+            assert!((distance - moved).abs() < 1e-12);
+            assert!(plane.signed_distance(plane.project(point)).abs() < 1e-12);
+        }
+        let direction = Vector::new(1.0, 0.0, 0.0);
+        assert!(plane
+            .intersect_line(plane.point(), direction.cross(plane.normal()))
+            .is_none());
+        let hit = plane.intersect_line(Vector::zero(), direction).unwrap();
+        assert!(plane.signed_distance(hit).abs() < 1e-12);
+        assert!(plane.intersect_ray(hit + direction, direction).is_none());
+    }
+
+    #[test]
+    fn closest_points_are_joined_by_common_perpendicular() {
+        let first =
+            Line::new(Vector::new(1.0_f64, 2.0, -1.0), Vector::new(1.0, -0.5, 0.3)).unwrap();
+        let second = Line::new(Vector::new(-2.0, 0.0, 4.0), Vector::new(0.2, 1.0, 1.0)).unwrap();
+        let (a, b) = first.closest_points(&second);
+        let join: Vector<f64> = b - a;
+        assert!(join.dot(first.direction()).abs() < 1e-12);
+        assert!(join.dot(second.direction()).abs() < 1e-12);
+        assert!((first.distance(a) + second.distance(b)) < 1e-12);
+        let parallel = Line::new(Vector::new(0.0, 1.0, 0.0), first.direction() * -2.0).unwrap();
+        let (a, b) = first.closest_points(&parallel);
+        assert_eq!(a, first.point());
+        assert!(((b - a).norm() - first.distance(parallel.point())).abs() < 1e-12);
+        let pose = test_pose();
+        let moved = (first.transform(pose), second.transform(pose));
+        assert!((moved.0.distance_to_line(&moved.1) - join.norm()).abs() < 1e-12);
+        let ray = Ray::new(first.point(), first.direction())
+            .unwrap()
+            .transform(pose);
+        assert!(ray.distance(pose.apply_to(first.at(2.0))) < 1e-12);
+        assert!((ray.distance(pose.apply_to(first.at(-2.0))) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bounding_boxes_contain_transformed_points() {
+        let points = [
+            Vector::new(1.0_f64, 2.0, -1.0),
+            Vector::new(-0.5, 0.0, 3.0),
+            Vector::new(2.0, -1.0, 0.5),
+            Vector::new(0.0, 1.5, 1.0),
+        ];
+        let pose = test_pose();
+        let bounds = Aabb::from_points(points).unwrap().transform(pose);
+        let rotation = Quaternion::<f64>::from_rpy(-0.3, 0.9, 0.2);
+        let oriented = Obb::from_points(rotation, points).unwrap().transform(pose);
+        let grown = Obb::new(
+            oriented.center(),
+            oriented.half_extents() + Vector::new(1e-9, 1e-9, 1e-9),
+            oriented.rotation(),
+        );
+        for point in points.map(|point| pose.apply_to(point)) {
+            assert!(bounds.contains(point));
+            assert!(grown.contains(point));
+            assert!(grown.aabb().contains(point));
+        }
+        let cube = |center| Obb::new(center, Vector::new(0.5, 0.5, 0.5), rotation);
+        for offset in [0.9, 1.1, 1.5] {
+            let (a, b) = (
+                cube(Vector::zero()),
+                cube(rotation.rotate(Vector::<f64>::unit_x()) * offset),
+            );
+            assert_eq!(a.overlaps(&b), offset < 1.0);
+            assert_eq!(b.transform(pose).overlaps(&a.transform(pose)), offset < 1.0);
+        }
+        let edge = Vector::new(0.6, 0.6, 0.0);
+        let turn = Quaternion::from_angle_axis(core::f64::consts::FRAC_PI_4, Vector::unit_z());
+        let diamond = Obb::new(Vector::zero(), Vector::new(0.5, 0.5, 0.5), turn);
+        let corner = Obb::from_aabb(&Aabb::new(edge, edge + Vector::new(1.0, 1.0, 1.0)));
+        assert!(diamond.aabb().overlaps(&corner.aabb()));
+        assert!(!diamond.overlaps(&corner));
+    }
+
+    #[test]
+    fn segment_distance_matches_sampled_minimum() {
+        let pairs = [
+            (Vector::new(0.0_f64, 0.0, 0.0), Vector::new(1.0, 2.0, 0.5)),
+            (Vector::new(-1.0, 1.0, 2.0), Vector::new(2.0, 0.0, -1.0)),
+            (Vector::new(3.0, 3.0, 3.0), Vector::new(3.5, 2.0, 3.0)),
+            (Vector::new(0.5, 1.0, 0.25), Vector::new(1.5, 3.0, 0.75)),
+            (Vector::new(1.0, 1.0, 1.0), Vector::new(1.0, 1.0, 1.0)),
+        ];
+        let segments = pairs.map(|(start, end)| Segment::new(start, end));
+        for first in &segments {
+            for second in &segments {
+                let distance = first.distance_to_segment(second);
+                let mut sampled = f64::INFINITY;
+                for i in 0..=200 {
+                    let point = first.at(f64::from(i) / 200.0);
+                    sampled = sampled.min(second.distance(point));
+                }
+                assert!(distance <= sampled + 1e-12);
+                assert!(distance >= sampled - 1e-2);
+            }
+        }
+        let line = Line::new(Vector::new(0.5, -1.0, 1.0), Vector::new(1.0, 0.5, -0.5)).unwrap();
+        let parallel = Line::new(Vector::new(0.0, 1.0, 0.0), Vector::new(0.5, 1.0, 0.25)).unwrap();
+        for segment in &segments {
+            for line in [line, parallel] {
+                let (a, b) = segment.closest_points_to_line(&line);
+                assert!(segment.distance(a) < 1e-12 && line.distance(b) < 1e-12);
+                let mut sampled = f64::INFINITY;
+                for i in 0..=200 {
+                    sampled = sampled.min(line.distance(segment.at(f64::from(i) / 200.0)));
+                }
+                assert!(segment.distance_to_line(&line) <= sampled + 1e-12);
+                assert!(segment.distance_to_line(&line) >= sampled - 1e-2);
+            }
+        }
+        let pose = test_pose();
+        let capsules = [
+            Capsule::new(segments[0], 0.2),
+            Capsule::new(segments[1], 0.3),
+        ];
+        let moved = capsules.map(|capsule| capsule.transform(pose));
+        let distance = capsules[0].distance(&capsules[1]);
+        assert!((moved[0].distance(&moved[1]) - distance).abs() < 1e-12);
+        assert_eq!(capsules[0].overlaps(&capsules[1]), distance <= 0.0);
+    }
+
+    #[test]
+    fn ray_hits_triangle_at_barycentric_point() {
+        let triangle = Triangle::new(
+            Vector::new(1.0_f64, 0.0, 0.5),
+            Vector::new(-1.0, 2.0, 0.0),
+            Vector::new(0.5, -1.0, 2.0),
+        );
+        let pose = test_pose();
+        let origin = Vector::new(3.0, 2.0, 4.0);
+        for weights in [[0.2, 0.3, 0.5], [0.9, 0.05, 0.05], [0.5, 0.6, -0.1]] {
+            let target = triangle.point_at(weights);
+            let ray = Ray::new(origin, target - origin).unwrap();
+            let hit = triangle.intersect_ray(&ray);
+            let moved = (pose * triangle).intersect_ray(&(pose * ray));
+            if weights.iter().all(|weight| *weight >= 0.0) {
+                let (distance, actual) = hit.unwrap();
+                assert!((distance - (target - origin).norm()).abs() < 1e-12);
+                assert!(actual
+                    .iter()
+                    .zip(weights)
+                    .all(|(a, b)| (a - b).abs() < 1e-12));
+                assert!((moved.unwrap().0 - distance).abs() < 1e-12);
+            } else {
+                assert!(hit.is_none() && moved.is_none());
+            }
+            let barycentric = triangle
+                .barycentric(target + triangle.normal().unwrap())
+                .unwrap();
+            assert!(barycentric
+                .iter()
+                .zip(weights)
+                .all(|(a, b)| (a - b).abs() < 1e-12));
+        }
+        let behind = Ray::new(origin, origin - triangle.point_at([0.3, 0.3, 0.4])).unwrap();
+        assert!(triangle.intersect_ray(&behind).is_none());
+        let flat =
+            Triangle::<f64>::new(Vector::zero(), Vector::unit_x(), Vector::new(2.0, 0.0, 0.0));
+        assert!(flat.normal().is_none() && flat.barycentric(Vector::unit_y()).is_none());
+    }
+}
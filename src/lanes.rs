@@ -0,0 +1,446 @@
+use crate::error::{to_array, ConversionError};
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use core::array::from_fn;
+use core::ops::{Add, Mul, Sub};
+
+/// Structure-of-arrays batch of `N` vectors, one array per coordinate.
+///
+/// Operations are performed lane by lane over plain arrays, so that they are easily
+/// vectorized by the compiler for throughput-bound workloads like point clouds.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::lanes::{QuaternionX4, VectorX4};
+/// let points = [1.0, 2.0, 3.0, 4.0].map(|x| Vector::new(x, 0.0, 0.0));
+/// let rotation = Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0);
+/// let rotated = QuaternionX4::splat(rotation).rotate(VectorX4::from(points));
+/// assert_eq!(rotated.x(), [-1.0, -2.0, -3.0, -4.0]);
+/// let points = VectorX4::try_from(&points[..]).unwrap();
+/// assert_eq!(points.get(2), Some(Vector::new(3.0, 0.0, 0.0)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VectorLanes<T, const N: usize> {
+    x: [T; N],
+    y: [T; N],
+    z: [T; N],
+}
+
+/// Batch of four vectors.
+pub type VectorX4<T> = VectorLanes<T, 4>;
+/// Batch of eight vectors.
+pub type VectorX8<T> = VectorLanes<T, 8>;
+
+impl<T, const N: usize> VectorLanes<T, N> {
+    /// Create new batch with given coordinate arrays.
+    pub const fn new(x: [T; N], y: [T; N], z: [T; N]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy, const N: usize> VectorLanes<T, N> {
+    /// Create batch with every lane holding given vector.
+    pub fn splat(vector: Vector<T>) -> Self {
+        Self {
+            x: [vector.x(); N],
+            y: [vector.y(); N],
+            z: [vector.z(); N],
+        }
+    }
+
+    /// Get vector stored in given lane.
+    pub fn get(&self, lane: usize) -> Option<Vector<T>> {
+        (lane < N).then(|| Vector::new(self.x[lane], self.y[lane], self.z[lane]))
+    }
+
+    /// Get x coordinates of all lanes.
+    pub fn x(&self) -> [T; N] {
+        self.x
+    }
+
+    /// Get y coordinates of all lanes.
+    pub fn y(&self) -> [T; N] {
+        self.y
+    }
+
+    /// Get z coordinates of all lanes.
+    pub fn z(&self) -> [T; N] {
+        self.z
+    }
+}
+
+/// Quaternions to be combined or applied lane by lane, see [`VectorLanes`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuaternionLanes<T, const N: usize> {
+    w: [T; N],
+    i: [T; N],
+    j: [T; N],
+    k: [T; N],
+}
+
+/// Batch of four quaternions.
+pub type QuaternionX4<T> = QuaternionLanes<T, 4>;
+/// Batch of eight quaternions.
+pub type QuaternionX8<T> = QuaternionLanes<T, 8>;
+
+impl<T, const N: usize> QuaternionLanes<T, N> {
+    /// Create new batch with given component arrays.
+    pub const fn with_wijk(w: [T; N], i: [T; N], j: [T; N], k: [T; N]) -> Self {
+        Self { w, i, j, k }
+    }
+}
+
+impl<T: Copy, const N: usize> QuaternionLanes<T, N> {
+    /// Create batch with every lane holding given quaternion.
+    pub fn splat(quaternion: Quaternion<T>) -> Self {
+        Self {
+            w: [quaternion.w(); N],
+            i: [quaternion.i(); N],
+            j: [quaternion.j(); N],
+            k: [quaternion.k(); N],
+        }
+    }
+
+    /// Get quaternion stored in given lane.
+    pub fn get(&self, lane: usize) -> Option<Quaternion<T>> {
+        (lane < N)
+            .then(|| Quaternion::with_wijk(self.w[lane], self.i[lane], self.j[lane], self.k[lane]))
+    }
+
+    /// Rotate every vector by the quaternion in the same lane, see [`Quaternion::rotate`].
+    pub fn rotate<U, R>(self, vectors: VectorLanes<U, N>) -> VectorLanes<R, N>
+    where
+        T: Mul<U, Output = R>
+            + Mul<R, Output = R>
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>,
+        U: Copy,
+        R: Copy + Add<Output = R> + Sub<Output = R>,
+    {
+        let (w, i, j, k) = (self.w, self.i, self.j, self.k);
+        let (x, y, z) = (vectors.x, vectors.y, vectors.z);
+        let rotated: [Vector<R>; N] = from_fn(|lane| {
+            Quaternion::with_wijk(w[lane], i[lane], j[lane], k[lane])
+                .rotate(Vector::new(x[lane], y[lane], z[lane]))
+        });
+        VectorLanes::from(rotated)
+    }
+}
+
+/// Fixed-capacity container of poses storing translations and rotations in separate arrays.
+///
+/// Bulk operations walk contiguous arrays of a single component type, which keeps
+/// large trajectory buffers cache-friendly. No allocation is performed.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::lanes::PoseSoA;
+/// let poses = [1.0, 2.0, 3.0].map(|x| Pose::new(Vector::new(x, 0.0, 0.0), Quaternion::<f64>::IDENTITY));
+/// let mut container = PoseSoA::<f64, f64, 8>::try_from(&poses[..]).unwrap();
+/// let shift = Pose::new(Vector::new(0.0, 1.0, 0.0), Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0));
+/// container.transform_all(shift);
+/// assert_eq!(container.translations()[2], Vector::new(-3.0, 1.0, 0.0));
+/// assert!(container.iter().eq(poses.map(|pose| shift * pose)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PoseSoA<T, R, const N: usize> {
+    translations: [Vector<T>; N],
+    rotations: [Quaternion<R>; N],
+    len: usize,
+}
+
+impl<T, R, const N: usize> PoseSoA<T, R, N>
+where
+    T: Copy + Default,
+    R: Copy + Default,
+{
+    /// Create empty container.
+    pub fn new() -> Self {
+        let zero = T::default();
+        let rotation = R::default();
+        Self {
+            translations: [Vector::new(zero, zero, zero); N],
+            rotations: [Quaternion::with_wijk(rotation, rotation, rotation, rotation); N],
+            len: 0,
+        }
+    }
+}
+
+impl<T, R, const N: usize> Default for PoseSoA<T, R, N>
+where
+    T: Copy + Default,
+    R: Copy + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Containers are equal when they store equal poses, unused slots are ignored.
+impl<T: PartialEq, R: PartialEq, const N: usize> PartialEq for PoseSoA<T, R, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && self.translations[..self.len] == other.translations[..other.len]
+            && self.rotations[..self.len] == other.rotations[..other.len]
+    }
+}
+
+impl<T: Copy, R: Copy, const N: usize> PoseSoA<T, R, N> {
+    /// Get maximum number of stored poses.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Get number of stored poses.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether container has no poses.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all poses.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Append pose, returns `false` and keeps the container unchanged if it is full.
+    pub fn push(&mut self, pose: Pose<T, R>) -> bool {
+        if self.len == N {
+            return false;
+        }
+        self.translations[self.len] = pose.translation();
+        self.rotations[self.len] = pose.rotation();
+        self.len += 1;
+        true
+    }
+
+    /// Get pose by index.
+    pub fn get(&self, index: usize) -> Option<Pose<T, R>> {
+        (index < self.len).then(|| Pose::new(self.translations[index], self.rotations[index]))
+    }
+
+    /// Get translations of stored poses.
+    pub fn translations(&self) -> &[Vector<T>] {
+        &self.translations[..self.len]
+    }
+
+    /// Get rotations of stored poses.
+    pub fn rotations(&self) -> &[Quaternion<R>] {
+        &self.rotations[..self.len]
+    }
+
+    /// Iterate over stored poses.
+    pub fn iter(&self) -> impl Iterator<Item = Pose<T, R>> + '_ {
+        self.translations()
+            .iter()
+            .zip(self.rotations())
+            .map(|(translation, rotation)| Pose::new(*translation, *rotation))
+    }
+
+    /// Copy stored poses into the beginning of given slice, returns number of copied poses.
+    pub fn write_to(&self, poses: &mut [Pose<T, R>]) -> usize {
+        let mut count = 0;
+        for (target, pose) in poses.iter_mut().zip(self.iter()) {
+            *target = pose;
+            count += 1;
+        }
+        count
+    }
+
+    /// Replace every stored pose `p` with `pose * p`.
+    pub fn transform_all(&mut self, pose: Pose<T, R>)
+    where
+        T: Add<Output = T> + Sub<Output = T>,
+        R: Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+    {
+        let (translation, rotation) = (pose.translation(), pose.rotation());
+        let prepared = rotation.prepare();
+        for vector in &mut self.translations[..self.len] {
+            *vector = translation + prepared.rotate(*vector);
+        }
+        for quaternion in &mut self.rotations[..self.len] {
+            *quaternion = rotation * *quaternion;
+        }
+    }
+}
+
+impl<T, R, const N: usize> TryFrom<&[Pose<T, R>]> for PoseSoA<T, R, N>
+where
+    T: Copy + Default,
+    R: Copy + Default,
+{
+    type Error = ConversionError;
+    fn try_from(poses: &[Pose<T, R>]) -> Result<Self, Self::Error> {
+        if poses.len() > N {
+            return Err(ConversionError::TooLong {
+                expected: N,
+                actual: poses.len(),
+            });
+        }
+        let mut container = Self::new();
+        for pose in poses {
+            container.push(*pose);
+        }
+        Ok(container)
+    }
+}
+
+impl<T, const N: usize> From<[Vector<T>; N]> for VectorLanes<T, N>
+where
+    T: Copy,
+{
+    fn from(vectors: [Vector<T>; N]) -> Self {
+        Self {
+            x: vectors.map(|vector| vector.x()),
+            y: vectors.map(|vector| vector.y()),
+            z: vectors.map(|vector| vector.z()),
+        }
+    }
+}
+
+impl<T, const N: usize> From<VectorLanes<T, N>> for [Vector<T>; N]
+where
+    T: Copy,
+{
+    fn from(lanes: VectorLanes<T, N>) -> Self {
+        from_fn(|lane| Vector::new(lanes.x[lane], lanes.y[lane], lanes.z[lane]))
+    }
+}
+
+impl<T: Copy, const N: usize> TryFrom<&[Vector<T>]> for VectorLanes<T, N> {
+    type Error = ConversionError;
+    fn try_from(slice: &[Vector<T>]) -> Result<Self, Self::Error> {
+        to_array(slice).map(<[Vector<T>; N]>::into)
+    }
+}
+
+impl<T, const N: usize> From<[Quaternion<T>; N]> for QuaternionLanes<T, N>
+where
+    T: Copy,
+{
+    fn from(quaternions: [Quaternion<T>; N]) -> Self {
+        Self {
+            w: quaternions.map(|quaternion| quaternion.w()),
+            i: quaternions.map(|quaternion| quaternion.i()),
+            j: quaternions.map(|quaternion| quaternion.j()),
+            k: quaternions.map(|quaternion| quaternion.k()),
+        }
+    }
+}
+
+impl<T, const N: usize> From<QuaternionLanes<T, N>> for [Quaternion<T>; N]
+where
+    T: Copy,
+{
+    fn from(lanes: QuaternionLanes<T, N>) -> Self {
+        from_fn(|lane| {
+            Quaternion::with_wijk(lanes.w[lane], lanes.i[lane], lanes.j[lane], lanes.k[lane])
+        })
+    }
+}
+
+impl<T: Copy, const N: usize> TryFrom<&[Quaternion<T>]> for QuaternionLanes<T, N> {
+    type Error = ConversionError;
+    fn try_from(slice: &[Quaternion<T>]) -> Result<Self, Self::Error> {
+        to_array(slice).map(<[Quaternion<T>; N]>::into)
+    }
+}
+
+impl<T, const N: usize> Add for VectorLanes<T, N>
+where
+    T: Add<Output = T> + Copy,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            x: from_fn(|lane| self.x[lane] + other.x[lane]),
+            y: from_fn(|lane| self.y[lane] + other.y[lane]),
+            z: from_fn(|lane| self.z[lane] + other.z[lane]),
+        }
+    }
+}
+
+impl<T, const N: usize> Sub for VectorLanes<T, N>
+where
+    T: Sub<Output = T> + Copy,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            x: from_fn(|lane| self.x[lane] - other.x[lane]),
+            y: from_fn(|lane| self.y[lane] - other.y[lane]),
+            z: from_fn(|lane| self.z[lane] - other.z[lane]),
+        }
+    }
+}
+
+/// Combine quaternions lane by lane, see [`Quaternion::multiply`].
+impl<T, const N: usize> Mul for QuaternionLanes<T, N>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Copy,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        let (a, b) = (self, other);
+        Self {
+            w: from_fn(|l| a.w[l] * b.w[l] - a.i[l] * b.i[l] - a.j[l] * b.j[l] - a.k[l] * b.k[l]),
+            i: from_fn(|l| a.w[l] * b.i[l] + a.i[l] * b.w[l] + a.j[l] * b.k[l] - a.k[l] * b.j[l]),
+            j: from_fn(|l| a.w[l] * b.j[l] - a.i[l] * b.k[l] + a.j[l] * b.w[l] + a.k[l] * b.i[l]),
+            k: from_fn(|l| a.w[l] * b.k[l] + a.i[l] * b.j[l] - a.j[l] * b.i[l] + a.k[l] * b.w[l]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PoseSoA, QuaternionX8, VectorX8};
+    use crate::error::ConversionError;
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn lanes_match_scalar_operations() {
+        let rotations: [Quaternion<f64>; 8] = core::array::from_fn(|lane| {
+            let value = lane as f64;
+            Quaternion::with_wijk(1.0, 0.1 * value, -0.2, 0.05 * value)
+        });
+        let vectors: [Vector<f64>; 8] =
+            core::array::from_fn(|lane| Vector::new(lane as f64, 1.0, -2.0));
+        let quaternions = QuaternionX8::from(rotations);
+        let combined = <[Quaternion<f64>; 8]>::from(quaternions * quaternions);
+        let rotated = <[Vector<f64>; 8]>::from(quaternions.rotate(VectorX8::from(vectors)));
+        for lane in 0..8 {
+            assert_eq!(combined[lane], rotations[lane] * rotations[lane]);
+            assert_eq!(rotated[lane], rotations[lane].rotate(vectors[lane]));
+        }
+        let error = VectorX8::try_from(&vectors[..5]).unwrap_err();
+        assert_eq!(
+            error,
+            ConversionError::TooShort {
+                expected: 8,
+                actual: 5
+            }
+        );
+    }
+
+    #[test]
+    fn equality_ignores_unused_slots() {
+        let pose = Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::<f64>::IDENTITY);
+        let mut container = PoseSoA::<f64, f64, 4>::new();
+        container.push(pose);
+        container.push(pose);
+        let mut other = PoseSoA::<f64, f64, 4>::new();
+        other.push(pose);
+        assert_ne!(container, other);
+        container.clear();
+        container.push(pose);
+        assert_eq!(container, other);
+    }
+}
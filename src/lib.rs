@@ -1,13 +1,96 @@
 #![no_std]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
 
 //! Spatial representation based on the vector-quaternion pairs.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// Dual quaternions representing poses.
+pub mod dual_quaternion;
+/// Error types of fallible operations.
+pub mod error;
+/// Fast approximate `f32` math for explicit use at call sites.
+#[cfg(feature = "fast-math")]
+pub mod fast_math;
+/// Poses and vectors tagged with frames checked at compile time.
+pub mod framed;
+/// WGS84 geodetic coordinates and local tangent frames.
+pub mod geo;
+/// Structure-of-arrays batches of vectors, quaternions and poses.
+pub mod lanes;
+/// Wrappers comparing and hashing poses by bit patterns.
+pub mod ordered;
+/// Multi-threaded batch operations.
+#[cfg(feature = "parallel")]
+pub mod parallel;
 /// Spatial pose, vector-quaternion pair.
 pub mod pose;
+/// Planar pose, vector-rotation pair.
+pub mod pose2;
 /// Spatial rotation.
 pub mod quaternion;
+/// Planar rotation.
+pub mod rotation2;
+/// Assertion macros and fixture poses for tests of downstream crates.
+#[cfg(feature = "testing")]
+pub mod testing;
+/// Spatial velocity.
+pub mod twist;
 /// Spatial translation.
 pub mod vector;
+/// Planar translation.
+pub mod vector2;
+/// Spatial force.
+pub mod wrench;
+
+/// Attitude and heading reference system filters.
+#[cfg(feature = "num")]
+pub mod ahrs;
+/// Hand-eye calibration from corresponding motions.
+#[cfg(feature = "num")]
+pub mod calibration;
+/// Trees of frames connected by poses.
+#[cfg(all(feature = "alloc", feature = "num"))]
+pub mod chain;
+/// State estimation with Kalman filters and pose uncertainty propagation.
+#[cfg(feature = "num")]
+pub mod estimation;
+/// Filters for pose streams.
+#[cfg(feature = "num")]
+pub mod filter;
+/// Robust and least-squares fitting of geometric primitives to point sets.
+#[cfg(feature = "num")]
+pub mod fitting;
+/// Geometric primitives and bounding boxes composing with poses.
+#[cfg(feature = "num")]
+pub mod geometry;
+/// Pose graph optimization over relative pose constraints.
+#[cfg(feature = "num")]
+pub mod optimization;
+/// Rigid registration of corresponding point sets.
+#[cfg(feature = "num")]
+pub mod registration;
+/// Fixed-capacity buffers of timestamped poses.
+#[cfg(feature = "num")]
+pub mod ring;
+/// Interpolation and analysis of timestamped pose sequences.
+#[cfg(feature = "num")]
+pub mod trajectory;
+/// Parsing of poses and joint chains from URDF robot descriptions.
+#[cfg(feature = "urdf")]
+pub mod urdf;
 
+/// Software float functions for builds without `num` or with deterministic results.
+#[cfg(any(not(feature = "num"), feature = "deterministic"))]
+mod math;
 /// Helper math operations.
 pub mod ops;
+/// Pose aliases and helpers for `uom` quantities.
+#[cfg(all(feature = "num", feature = "uom"))]
+pub mod si;
+/// Portable SIMD `f32` operations.
+#[cfg(feature = "simd")]
+mod simd;
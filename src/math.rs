@@ -0,0 +1,306 @@
+//! Software implementations of floating point functions missing from `core`.
+//!
+//! Used for `f32` and `f64` when the `num` feature is disabled or the `deterministic` feature
+//! is enabled, `f32` values are calculated in `f64` precision. Otherwise `num::Float` calls
+//! the `std` functions with the `std` feature and the `libm` crate without it.
+//! Only correctly rounded basic arithmetic is used, so results are bit-identical on
+//! every target. Exponential and cube root are only used by the `num` modules with the
+//! `deterministic` feature.
+
+#[cfg(feature = "num")]
+use core::f64::consts::LOG2_E;
+use core::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_6, PI};
+
+/// High part of `pi / 2`, exact when multiplied by moderately sized integers.
+const FRAC_PI_2_HIGH: f64 = 1.570_796_326_734_125_6;
+/// Remainder of `pi / 2` after [`FRAC_PI_2_HIGH`].
+const FRAC_PI_2_LOW: f64 = 6.077_100_506_506_192e-11;
+/// Tangent of `pi / 12`.
+const TAN_PI_12: f64 = 0.267_949_192_431_122_7;
+/// Square root of three.
+const SQRT_3: f64 = 1.732_050_807_568_877_2;
+/// High part of `ln(2)`, exact when multiplied by integers up to `2^11`.
+#[cfg(feature = "num")]
+const LN_2_HIGH: f64 = 6.931_471_803_691_238e-1;
+/// Remainder of `ln(2)` after [`LN_2_HIGH`].
+#[cfg(feature = "num")]
+const LN_2_LOW: f64 = 1.908_214_929_270_587_7e-10;
+
+/// Taylor coefficients of `sin(x) / x` in powers of `x^2`.
+const SIN: [f64; 8] = [
+    1.0,
+    -1.0 / 6.0,
+    1.0 / 120.0,
+    -1.0 / 5_040.0,
+    1.0 / 362_880.0,
+    -1.0 / 39_916_800.0,
+    1.0 / 6_227_020_800.0,
+    -1.0 / 1_307_674_368_000.0,
+];
+/// Taylor coefficients of `cos(x)` in powers of `x^2`.
+const COS: [f64; 9] = [
+    1.0,
+    -1.0 / 2.0,
+    1.0 / 24.0,
+    -1.0 / 720.0,
+    1.0 / 40_320.0,
+    -1.0 / 3_628_800.0,
+    1.0 / 479_001_600.0,
+    -1.0 / 87_178_291_200.0,
+    1.0 / 20_922_789_888_000.0,
+];
+
+/// Evaluate polynomial with given coefficients, lowest power first.
+fn polynomial(value: f64, coefficients: &[f64]) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |result, coefficient| result * value + coefficient)
+}
+
+/// Square root using Newton iterations started from the exponent-halving estimate.
+pub(crate) fn sqrt(value: f64) -> f64 {
+    if value.is_nan() || value < 0.0 {
+        return f64::NAN;
+    }
+    if value == 0.0 || value.is_infinite() {
+        return value;
+    }
+    let estimate = f64::from_bits((value.to_bits() >> 1) + (1023 << 51));
+    // After the first step estimates approach the root from above.
+    let mut estimate = 0.5 * (estimate + value / estimate);
+    loop {
+        let next = 0.5 * (estimate + value / estimate);
+        if next >= estimate {
+            return estimate;
+        }
+        estimate = next;
+    }
+}
+
+/// Cube root using Newton iterations started from the exponent-dividing estimate.
+#[cfg(feature = "num")]
+pub(crate) fn cbrt(value: f64) -> f64 {
+    if value.is_nan() || value == 0.0 || value.is_infinite() {
+        return value;
+    }
+    if value < 0.0 {
+        return -cbrt(-value);
+    }
+    let estimate = f64::from_bits(value.to_bits() / 3 + 0x2a9f_7893_782d_a1ce);
+    // After the first step estimates approach the root from above.
+    let mut estimate = (2.0 * estimate + value / (estimate * estimate)) / 3.0;
+    loop {
+        let next = (2.0 * estimate + value / (estimate * estimate)) / 3.0;
+        if next >= estimate {
+            return estimate;
+        }
+        estimate = next;
+    }
+}
+
+/// Exponential of value reduced by a multiple of `ln(2)` into the `[-ln(2)/2, ln(2)/2]`
+/// range, by Taylor series scaled by the power of two.
+#[cfg(feature = "num")]
+pub(crate) fn exp(value: f64) -> f64 {
+    if value.is_nan() {
+        return value;
+    }
+    if value > 709.8 {
+        return f64::INFINITY;
+    }
+    if value < -745.2 {
+        return 0.0;
+    }
+    let offset = if value < 0.0 { -0.5 } else { 0.5 };
+    let power = (value * LOG2_E + offset) as i64;
+    let multiple = power as f64;
+    let reduced = (value - multiple * LN_2_HIGH) - multiple * LN_2_LOW;
+    let series = (1..=14).rev().fold(1.0, |result, order| {
+        1.0 + result * reduced / f64::from(order)
+    });
+    // Split the scale so that neither factor overflows nor turns subnormal too early.
+    let half = power / 2;
+    let scale = |power: i64| f64::from_bits(((power + 1023) as u64) << 52);
+    series * scale(half) * scale(power - half)
+}
+
+/// Apply software `f64` function to value of generic floating point type.
+#[cfg(feature = "num")]
+pub(crate) fn via_f64<T: num::Float>(value: T, function: fn(f64) -> f64) -> T {
+    value
+        .to_f64()
+        .and_then(|value| T::from(function(value)))
+        .unwrap_or_else(T::nan)
+}
+
+/// Reduce angle into the `[-pi/4, pi/4]` range, returns reduced angle and quadrant.
+fn reduce(angle: f64) -> (f64, i64) {
+    let offset = if angle < 0.0 { -0.5 } else { 0.5 };
+    let quadrant = (angle * FRAC_2_PI + offset) as i64;
+    let multiple = quadrant as f64;
+    let reduced = (angle - multiple * FRAC_PI_2_HIGH) - multiple * FRAC_PI_2_LOW;
+    (reduced, quadrant)
+}
+
+fn sin_reduced(angle: f64) -> f64 {
+    angle * polynomial(angle * angle, &SIN)
+}
+
+fn cos_reduced(angle: f64) -> f64 {
+    polynomial(angle * angle, &COS)
+}
+
+pub(crate) fn sin(angle: f64) -> f64 {
+    if !angle.is_finite() {
+        return f64::NAN;
+    }
+    let (reduced, quadrant) = reduce(angle);
+    match quadrant & 3 {
+        0 => sin_reduced(reduced),
+        1 => cos_reduced(reduced),
+        2 => -sin_reduced(reduced),
+        _ => -cos_reduced(reduced),
+    }
+}
+
+pub(crate) fn cos(angle: f64) -> f64 {
+    if !angle.is_finite() {
+        return f64::NAN;
+    }
+    let (reduced, quadrant) = reduce(angle);
+    match quadrant & 3 {
+        0 => cos_reduced(reduced),
+        1 => -sin_reduced(reduced),
+        2 => -cos_reduced(reduced),
+        _ => sin_reduced(reduced),
+    }
+}
+
+/// Arctangent of value in the `[0, tan(pi/12)]` range by Taylor series.
+fn atan_reduced(value: f64) -> f64 {
+    let squared = value * value;
+    let series = (0..16).rev().fold(0.0, |result, power| {
+        let coefficient = 1.0 / f64::from(2 * power + 1);
+        let coefficient = if power % 2 == 0 {
+            coefficient
+        } else {
+            -coefficient
+        };
+        result * squared + coefficient
+    });
+    value * series
+}
+
+fn atan(value: f64) -> f64 {
+    if value < 0.0 {
+        -atan(-value)
+    } else if value > 1.0 {
+        FRAC_PI_2 - atan(1.0 / value)
+    } else if value > TAN_PI_12 {
+        FRAC_PI_6 + atan_reduced((value * SQRT_3 - 1.0) / (SQRT_3 + value))
+    } else {
+        atan_reduced(value)
+    }
+}
+
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        f64::NAN
+    } else if x > 0.0 {
+        atan(y / x)
+    } else if x < 0.0 {
+        if y.is_sign_negative() {
+            atan(y / x) - PI
+        } else {
+            atan(y / x) + PI
+        }
+    } else if y > 0.0 {
+        FRAC_PI_2
+    } else if y < 0.0 {
+        -FRAC_PI_2
+    } else if x.is_sign_negative() {
+        if y.is_sign_negative() {
+            -PI
+        } else {
+            PI
+        }
+    } else {
+        y
+    }
+}
+
+pub(crate) fn acos(value: f64) -> f64 {
+    atan2(sqrt((1.0 - value) * (1.0 + value)), value)
+}
+
+#[cfg(test)]
+mod test {
+    use core::f64::consts::{FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, PI};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-14,
+            "{actual} is not close to {expected}"
+        );
+    }
+
+    #[test]
+    fn sqrt_of_squares() {
+        for value in [1e-150, 0.25, 2.0, 9.0, 1e10, 1e150] {
+            assert_close(super::sqrt(value * value) / value, 1.0);
+        }
+        assert_eq!(super::sqrt(0.0), 0.0);
+        assert!(super::sqrt(-1.0).is_nan());
+    }
+
+    #[test]
+    fn trigonometry_at_known_angles() {
+        assert_close(super::sin(FRAC_PI_2), 1.0);
+        assert_close(super::cos(FRAC_PI_3), 0.5);
+        assert_close(super::sin(-PI / 6.0), -0.5);
+        assert_close(super::cos(10.0 * PI), 1.0);
+        assert_close(super::acos(0.5), FRAC_PI_3);
+        assert_close(super::acos(-1.0), PI);
+        assert_close(super::atan2(1.0, 1.0), FRAC_PI_4);
+        assert_close(super::atan2(-1.0, -1.0), -3.0 * FRAC_PI_4);
+        assert_close(super::atan2(1.0, 0.0), FRAC_PI_2);
+    }
+
+    #[test]
+    fn sine_and_cosine_are_consistent() {
+        let mut angle = -20.0;
+        while angle < 20.0 {
+            let (sin, cos) = (super::sin(angle), super::cos(angle));
+            assert_close(sin * sin + cos * cos, 1.0);
+            let restored = super::atan2(sin, cos);
+            assert_close(super::sin(restored), sin);
+            assert_close(super::cos(restored), cos);
+            angle += 0.01;
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num")]
+    fn exponential_and_cube_root() {
+        assert_close(super::exp(0.0), 1.0);
+        assert_close(super::exp(1.0), core::f64::consts::E);
+        assert_close(super::exp(-core::f64::consts::LN_2), 0.5);
+        assert_close(super::exp(700.0) / 1.014_232_054_735_004_5e304, 1.0);
+        assert_eq!(super::exp(1e3), f64::INFINITY);
+        assert_eq!(super::exp(-1e3), 0.0);
+        assert!(super::exp(-740.0) > 0.0);
+        assert_close(super::cbrt(27.0), 3.0);
+        assert_close(super::cbrt(-0.125), -0.5);
+        assert_close(super::cbrt(f64::EPSILON) / 6.055_454_452_393_343e-6, 1.0);
+    }
+
+    #[test]
+    fn results_are_reproducible() {
+        assert_eq!(super::sin(0.5).to_bits(), 0x3fde_aee8_744b_05f0);
+        assert_eq!(super::cos(2.0).to_bits(), 0xbfda_a226_5753_7205);
+        assert_eq!(super::atan2(1.0, 3.0).to_bits(), 0x3fd4_978f_a326_9ee2);
+        assert_eq!(super::acos(0.3).to_bits(), 0x3ff4_41f5_ecbe_ef58);
+        assert_eq!(super::sqrt(2.0).to_bits(), 0x3ff6_a09e_667f_3bcc);
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "num")]
+use num::{One, Zero};
 #[cfg(feature = "uom")]
 use uom::marker::Div;
 #[cfg(feature = "uom")]
@@ -13,70 +15,583 @@ pub trait Sqrt {
     fn sqrt(self) -> Self::Output;
 }
 
+#[cfg(all(feature = "num", not(feature = "deterministic")))]
 impl Sqrt for f32 {
     type Output = f32;
     fn sqrt(self) -> Self::Output {
-        f32::sqrt(self)
+        num::Float::sqrt(self)
     }
 }
 
+#[cfg(any(not(feature = "num"), feature = "deterministic"))]
+impl Sqrt for f32 {
+    type Output = f32;
+    fn sqrt(self) -> Self::Output {
+        crate::math::sqrt(self.into()) as f32
+    }
+}
+
+#[cfg(all(feature = "num", not(feature = "deterministic")))]
 impl Sqrt for f64 {
     type Output = f64;
     fn sqrt(self) -> Self::Output {
-        f64::sqrt(self)
+        num::Float::sqrt(self)
+    }
+}
+
+#[cfg(any(not(feature = "num"), feature = "deterministic"))]
+impl Sqrt for f64 {
+    type Output = f64;
+    fn sqrt(self) -> Self::Output {
+        crate::math::sqrt(self)
+    }
+}
+
+/// Trait for types implementing trigonometric operations, angles are in radians.
+pub trait Trig {
+    /// Calculate sine of this value.
+    fn sin(self) -> Self;
+    /// Calculate cosine of this value.
+    fn cos(self) -> Self;
+    /// Calculate arccosine of this value.
+    fn acos(self) -> Self;
+    /// Calculate four quadrant arctangent of this value (`y`) and `x`.
+    fn atan2(self, x: Self) -> Self;
+}
+
+#[cfg(all(feature = "num", not(feature = "deterministic")))]
+impl Trig for f32 {
+    fn sin(self) -> Self {
+        num::Float::sin(self)
+    }
+    fn cos(self) -> Self {
+        num::Float::cos(self)
+    }
+    fn acos(self) -> Self {
+        num::Float::acos(self)
+    }
+    fn atan2(self, x: Self) -> Self {
+        num::Float::atan2(self, x)
+    }
+}
+
+#[cfg(all(feature = "num", not(feature = "deterministic")))]
+impl Trig for f64 {
+    fn sin(self) -> Self {
+        num::Float::sin(self)
+    }
+    fn cos(self) -> Self {
+        num::Float::cos(self)
+    }
+    fn acos(self) -> Self {
+        num::Float::acos(self)
+    }
+    fn atan2(self, x: Self) -> Self {
+        num::Float::atan2(self, x)
+    }
+}
+
+#[cfg(any(not(feature = "num"), feature = "deterministic"))]
+impl Trig for f32 {
+    fn sin(self) -> Self {
+        crate::math::sin(self.into()) as f32
+    }
+    fn cos(self) -> Self {
+        crate::math::cos(self.into()) as f32
+    }
+    fn acos(self) -> Self {
+        crate::math::acos(self.into()) as f32
+    }
+    fn atan2(self, x: Self) -> Self {
+        crate::math::atan2(self.into(), x.into()) as f32
+    }
+}
+
+#[cfg(any(not(feature = "num"), feature = "deterministic"))]
+impl Trig for f64 {
+    fn sin(self) -> Self {
+        crate::math::sin(self)
+    }
+    fn cos(self) -> Self {
+        crate::math::cos(self)
+    }
+    fn acos(self) -> Self {
+        crate::math::acos(self)
+    }
+    fn atan2(self, x: Self) -> Self {
+        crate::math::atan2(self, x)
     }
 }
 
+/// Trait for types providing the smallest meaningful difference from one.
+pub trait Epsilon {
+    /// Get epsilon value.
+    fn epsilon() -> Self;
+}
+
+impl Epsilon for f32 {
+    fn epsilon() -> Self {
+        f32::EPSILON
+    }
+}
+
+impl Epsilon for f64 {
+    fn epsilon() -> Self {
+        f64::EPSILON
+    }
+}
+
+/// Trait for types having a Euclidean norm.
+/// ```
+/// # use spatial::ops::Norm;
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// fn longest<N: Norm<Output = f64>>(items: impl IntoIterator<Item = N>) -> f64 {
+///     items.into_iter().map(Norm::norm).fold(0.0, f64::max)
+/// }
+/// let vectors = [Vector::new(3.0, 4.0, 0.0), Vector::new(1.0, 0.0, 0.0)];
+/// assert_eq!(longest(vectors), 5.0);
+/// let quaternions = [Quaternion::with_wijk(1.0, 1.0, 1.0, 1.0)];
+/// assert_eq!(longest(quaternions), 2.0);
+/// ```
 pub trait Norm {
+    /// Output type.
     type Output;
+    /// Calculate norm of this value.
     fn norm(self) -> Self::Output;
 }
 
-#[cfg(feature = "uom")]
-impl<D, U> Sqrt for Quantity<D, U, f64>
+/// Translation scalar of poses, implemented for every type implementing the listed traits,
+/// e.g. `f32`, `f64` and `uom` lengths. Together with [`RotationScalar`] it replaces the
+/// bounds of pose composition and point transformation in generic code.
+/// ```
+/// # use spatial::ops::{RotationScalar, Scalar};
+/// # use spatial::pose::Pose;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::vector::Vector;
+/// fn tip<T: Scalar, R: RotationScalar<T>>(links: &[Pose<T, R>], point: Vector<T>) -> Vector<T> {
+///     links.iter().rev().fold(point, |point, link| *link * point)
+/// }
+/// let link: Pose<f64> = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::<f64>::IDENTITY);
+/// assert_eq!(tip(&[link, link], Vector::new(0.0, 0.0, 1.0)), Vector::new(2.0, 0.0, 1.0));
+/// ```
+pub trait Scalar: Copy + core::ops::Add<Output = Self> + core::ops::Sub<Output = Self> {}
+
+impl<T> Scalar for T where T: Copy + core::ops::Add<Output = T> + core::ops::Sub<Output = T> {}
+
+/// Rotation scalar of poses with translation scalar `T`, e.g. `f64` for `f64` translations
+/// or `uom` ratios for lengths, see [`Scalar`].
+pub trait RotationScalar<T>:
+    Copy
+    + core::ops::Mul<Output = Self>
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<T, Output = T>
+{
+}
+
+impl<T, R> RotationScalar<T> for R where
+    R: Copy
+        + core::ops::Mul<Output = R>
+        + core::ops::Add<Output = R>
+        + core::ops::Sub<Output = R>
+        + core::ops::Mul<T, Output = T>
+{
+}
+
+/// Real scalar providing every operation required by rotations and poses, implemented
+/// for every type implementing the listed traits, e.g. `f32` and `f64`. Arithmetic comes
+/// from [`Scalar`] and [`RotationScalar`], so `Pose<T>` of a real `T` composes as well.
+/// ```
+/// # use spatial::ops::Real;
+/// # use spatial::pose::Pose;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::vector::Vector;
+/// fn half_turn<T: Real>(axis: Vector<T>) -> Quaternion<T> {
+///     Quaternion::from_angle_axis(T::acos(-T::one()), axis)
+/// }
+/// fn twice<T: Real>(pose: Pose<T>) -> Pose<T> {
+///     pose * pose
+/// }
+/// let rotation = half_turn(Vector::<f64>::unit_z());
+/// let rotated: Vector<f64> = rotation.rotate(Vector::<f64>::unit_x());
+/// assert!((rotated.x() + 1.0).abs() < 1e-9 && rotated.y().abs() < 1e-9);
+/// let pose = twice(Pose::new(Vector::new(1.0, 0.0, 0.0), rotation));
+/// assert!(pose.translation().norm() < 1e-9);
+/// ```
+#[cfg(feature = "num")]
+pub trait Real:
+    Scalar
+    + RotationScalar<Self>
+    + PartialOrd
+    + One
+    + Zero
+    + core::ops::Neg<Output = Self>
+    + core::ops::Div<Output = Self>
+    + Sqrt<Output = Self>
+    + Trig
+    + Epsilon
+{
+}
+
+#[cfg(feature = "num")]
+impl<T> Real for T where
+    T: Scalar
+        + RotationScalar<T>
+        + PartialOrd
+        + One
+        + Zero
+        + core::ops::Neg<Output = T>
+        + core::ops::Div<Output = T>
+        + Sqrt<Output = T>
+        + Trig
+        + Epsilon
+{
+}
+
+/// Check that value is neither infinite nor `NaN`, which are the values whose product
+/// with zero is not zero.
+#[cfg(feature = "num")]
+pub(crate) fn is_finite<T: Real>(value: T) -> bool {
+    value * T::zero() == T::zero()
+}
+
+/// Normalized interpolation progress, guaranteed to lie in the `[0, 1]` range.
+/// ```
+/// # use spatial::ops::Progress;
+/// # #[cfg(feature = "num")] {
+/// assert_eq!(Progress::new(0.25).value(), 0.25);
+/// assert_eq!(Progress::new(1.5).value(), 1.0);
+/// assert_eq!(Progress::new_checked(-0.5), None);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Progress<T>(T);
+
+#[cfg(feature = "num")]
+impl<T> Progress<T>
 where
-    D: Dimension + ?Sized,
-    U: Units<f64> + ?Sized,
-    D::L: PartialDiv<P2>,
-    <D::L as PartialDiv<P2>>::Output: Integer,
-    D::M: PartialDiv<P2>,
-    <D::M as PartialDiv<P2>>::Output: Integer,
-    D::T: PartialDiv<P2>,
-    <D::T as PartialDiv<P2>>::Output: Integer,
-    D::I: PartialDiv<P2>,
-    <D::I as PartialDiv<P2>>::Output: Integer,
-    D::Th: PartialDiv<P2>,
-    <D::Th as PartialDiv<P2>>::Output: Integer,
-    D::N: PartialDiv<P2>,
-    <D::N as PartialDiv<P2>>::Output: Integer,
-    D::J: PartialDiv<P2>,
-    <D::J as PartialDiv<P2>>::Output: Integer,
-    D::Kind: Div,
+    T: PartialOrd + One + Zero,
 {
-    type Output = Quantity<
-        ISQ<
-            PartialQuot<D::L, P2>,
-            PartialQuot<D::M, P2>,
-            PartialQuot<D::T, P2>,
-            PartialQuot<D::I, P2>,
-            PartialQuot<D::Th, P2>,
-            PartialQuot<D::N, P2>,
-            PartialQuot<D::J, P2>,
-        >,
-        U,
-        f64,
-    >;
-    fn sqrt(self) -> Self::Output {
-        Self::sqrt(self)
+    /// Create progress from given value, clamping it to the `[0, 1]` range.
+    pub fn new(value: T) -> Self {
+        if value > T::one() {
+            Self(T::one())
+        } else if value > T::zero() {
+            Self(value)
+        } else {
+            Self(T::zero())
+        }
+    }
+
+    /// Create progress from given value, returns `None` if it is outside of the `[0, 1]` range.
+    pub fn new_checked(value: T) -> Option<Self> {
+        if value >= T::zero() && value <= T::one() {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Progress<T> {
+    /// Get progress value.
+    pub fn value(self) -> T {
+        self.0
+    }
+}
+
+/// Trait for types accepted as interpolation progress, where `0` is the start and `1` is the end.
+pub trait IntoProgress<T> {
+    /// Convert this value into plain progress.
+    fn into_progress(self) -> T;
+}
+
+impl<T> IntoProgress<T> for T {
+    fn into_progress(self) -> T {
+        self
+    }
+}
+
+impl<T> IntoProgress<T> for Progress<T> {
+    fn into_progress(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(feature = "uom")]
+impl IntoProgress<f32> for uom::si::f32::Ratio {
+    fn into_progress(self) -> f32 {
+        self.get::<uom::si::ratio::ratio>()
     }
 }
 
+#[cfg(feature = "uom")]
+impl IntoProgress<f64> for uom::si::f64::Ratio {
+    fn into_progress(self) -> f64 {
+        self.get::<uom::si::ratio::ratio>()
+    }
+}
+
+/// Trait for types accepted as rotation angle, plain values are in radians.
+/// ```
+/// # #[cfg(all(feature = "num", feature = "uom"))] {
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use uom::si::angle::degree;
+/// # use uom::si::f64::{Angle, Length};
+/// # use uom::si::length::{meter, millimeter};
+/// let turn = Quaternion::from_angle_axis(Angle::new::<degree>(90.0), Vector::unit_z());
+/// let arm = Vector::new(1.0, 0.0, 0.0).map(Length::new::<meter>);
+/// let first = Pose::<Length, f64>::new(arm, turn);
+/// let second = Pose::new(arm, Quaternion::identity());
+/// let tip = (first * second).translation();
+/// let expected = Vector::new(1.0, 1.0, 0.0).map(Length::new::<meter>);
+/// let error: Length = (tip - expected).norm();
+/// assert!(error < Length::new::<millimeter>(1e-9));
+/// # }
+/// ```
+pub trait IntoAngle<T> {
+    /// Convert this value into plain angle in radians.
+    fn into_angle(self) -> T;
+}
+
+impl<T> IntoAngle<T> for T {
+    fn into_angle(self) -> T {
+        self
+    }
+}
+
+#[cfg(feature = "uom")]
+impl IntoAngle<f32> for uom::si::f32::Angle {
+    fn into_angle(self) -> f32 {
+        self.get::<uom::si::angle::radian>()
+    }
+}
+
+#[cfg(feature = "uom")]
+impl IntoAngle<f64> for uom::si::f64::Angle {
+    fn into_angle(self) -> f64 {
+        self.get::<uom::si::angle::radian>()
+    }
+}
+
+/// Implement [`Sqrt`] for `uom` quantities with given storage type, halving the dimension.
+#[cfg(feature = "uom")]
+macro_rules! impl_quantity_sqrt {
+    ($($storage:ty),+) => {$(
+        impl<D, U> Sqrt for Quantity<D, U, $storage>
+        where
+            D: Dimension + ?Sized,
+            U: Units<$storage> + ?Sized,
+            D::L: PartialDiv<P2>,
+            <D::L as PartialDiv<P2>>::Output: Integer,
+            D::M: PartialDiv<P2>,
+            <D::M as PartialDiv<P2>>::Output: Integer,
+            D::T: PartialDiv<P2>,
+            <D::T as PartialDiv<P2>>::Output: Integer,
+            D::I: PartialDiv<P2>,
+            <D::I as PartialDiv<P2>>::Output: Integer,
+            D::Th: PartialDiv<P2>,
+            <D::Th as PartialDiv<P2>>::Output: Integer,
+            D::N: PartialDiv<P2>,
+            <D::N as PartialDiv<P2>>::Output: Integer,
+            D::J: PartialDiv<P2>,
+            <D::J as PartialDiv<P2>>::Output: Integer,
+            D::Kind: Div,
+        {
+            type Output = Quantity<
+                ISQ<
+                    PartialQuot<D::L, P2>,
+                    PartialQuot<D::M, P2>,
+                    PartialQuot<D::T, P2>,
+                    PartialQuot<D::I, P2>,
+                    PartialQuot<D::Th, P2>,
+                    PartialQuot<D::N, P2>,
+                    PartialQuot<D::J, P2>,
+                >,
+                U,
+                $storage,
+            >;
+            fn sqrt(self) -> Self::Output {
+                Self::sqrt(self)
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "uom")]
+impl_quantity_sqrt!(f32, f64);
+
+#[cfg(feature = "uom-complex")]
+impl_quantity_sqrt!(uom::num::complex::Complex32, uom::num::complex::Complex64);
+
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "uom")]
+    use super::Norm;
     use super::Sqrt;
 
     #[test]
     fn no_recursion_during_sqrt() {
         Sqrt::sqrt(4.0);
     }
+
+    /// Signed Q32.32 fixed-point scalar, the kind of type used on targets without FPU.
+    #[cfg(feature = "num")]
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    struct Fixed(i64);
+
+    #[cfg(feature = "num")]
+    mod fixed {
+        use super::super::{Epsilon, Sqrt, Trig};
+        use super::Fixed;
+        use core::ops::{Add, Div, Mul, Neg, Sub};
+
+        const ONE: f64 = (1u64 << 32) as f64;
+
+        impl Fixed {
+            pub(super) fn from_f64(value: f64) -> Self {
+                Self((value * ONE) as i64)
+            }
+
+            pub(super) fn into_f64(self) -> f64 {
+                self.0 as f64 / ONE
+            }
+        }
+
+        impl Add for Fixed {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+
+        impl Sub for Fixed {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+        }
+
+        impl Mul for Fixed {
+            type Output = Self;
+            fn mul(self, other: Self) -> Self {
+                Self(((self.0 as i128 * other.0 as i128) >> 32) as i64)
+            }
+        }
+
+        impl Div for Fixed {
+            type Output = Self;
+            fn div(self, other: Self) -> Self {
+                Self((((self.0 as i128) << 32) / other.0 as i128) as i64)
+            }
+        }
+
+        impl Neg for Fixed {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl num::Zero for Fixed {
+            fn zero() -> Self {
+                Self(0)
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl num::One for Fixed {
+            fn one() -> Self {
+                Self(1 << 32)
+            }
+        }
+
+        impl Sqrt for Fixed {
+            type Output = Self;
+            fn sqrt(self) -> Self {
+                Self(((self.0.max(0) as u128) << 32).isqrt() as i64)
+            }
+        }
+
+        /// Trigonometry through `f64` is enough to check the bounds, real targets use tables
+        /// or CORDIC.
+        impl Trig for Fixed {
+            fn sin(self) -> Self {
+                Self::from_f64(num::Float::sin(self.into_f64()))
+            }
+            fn cos(self) -> Self {
+                Self::from_f64(num::Float::cos(self.into_f64()))
+            }
+            fn acos(self) -> Self {
+                Self::from_f64(num::Float::acos(self.into_f64()))
+            }
+            fn atan2(self, x: Self) -> Self {
+                Self::from_f64(num::Float::atan2(self.into_f64(), x.into_f64()))
+            }
+        }
+
+        impl Epsilon for Fixed {
+            fn epsilon() -> Self {
+                Self(1)
+            }
+        }
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn fixed_point_scalar_builds_and_composes_poses() {
+        use crate::pose::Pose;
+        use crate::quaternion::Quaternion;
+        use crate::vector::Vector;
+        let fixed = Fixed::from_f64;
+        let rotation =
+            Quaternion::from_angle_axis(fixed(core::f64::consts::FRAC_PI_2), Vector::unit_z());
+        let pose = Pose::new(Vector::new(fixed(1.0), fixed(0.0), fixed(0.0)), rotation);
+        let moved = (pose * pose).apply_to(Vector::unit_x());
+        let expected = [0.0, 1.0, 0.0];
+        for (actual, expected) in <[Fixed; 3]>::from(moved).into_iter().zip(expected) {
+            assert!((actual.into_f64() - expected).abs() < 1e-8);
+        }
+        let half = Quaternion::identity().slerp(rotation, fixed(0.5));
+        let (angle, _) = half.into_angle_axis();
+        assert!((angle.into_f64() - core::f64::consts::FRAC_PI_4).abs() < 1e-6);
+        let back = pose.inverse() * pose;
+        assert!(back.translation().norm().into_f64() < 1e-8);
+    }
+
+    #[cfg(feature = "uom")]
+    #[test]
+    fn quantity_sqrt_halves_dimension() {
+        use uom::si::area::square_meter;
+        use uom::si::length::meter;
+        let area = uom::si::f32::Area::new::<square_meter>(4.0);
+        let length: uom::si::f32::Length = Sqrt::sqrt(area);
+        assert_eq!(length.get::<meter>(), 2.0);
+        let vector =
+            crate::vector::Vector::new(3.0, 4.0, 0.0).map(uom::si::f32::Length::new::<meter>);
+        assert_eq!(vector.norm().get::<meter>(), 5.0);
+        assert_eq!(Norm::norm(vector).get::<meter>(), 5.0);
+        let area = uom::si::f64::Area::new::<square_meter>(9.0);
+        let length: uom::si::f64::Length = Sqrt::sqrt(area);
+        assert_eq!(length.get::<meter>(), 3.0);
+    }
+
+    #[cfg(feature = "uom-complex")]
+    #[test]
+    fn complex_quantity_sqrt_halves_dimension() {
+        use uom::num::complex::Complex64;
+        let area = uom::si::complex64::Area {
+            dimension: core::marker::PhantomData,
+            units: core::marker::PhantomData,
+            value: Complex64::new(-4.0, 0.0),
+        };
+        let length: uom::si::complex64::Length = Sqrt::sqrt(area);
+        assert!((length.value - Complex64::new(0.0, 2.0)).norm() < 1e-12);
+    }
 }
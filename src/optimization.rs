@@ -0,0 +1,510 @@
+use crate::error::ConvergenceError;
+use crate::estimation::{identity, multiply};
+use crate::ops::Real;
+use crate::pose::Pose;
+use crate::twist::Twist;
+use crate::vector::Vector;
+
+/// Dense 6x6 matrix acting on `[linear, angular]` twist coordinates.
+type Block<T> = [[T; 6]; 6];
+
+/// Relative pose constraint between two graph nodes.
+///
+/// Residual is the body-frame twist `log(measurement^-1 * from^-1 * to)` weighted by diagonal
+/// information, i.e. inverse variances of its linear and angular components.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Constraint<T> {
+    from: usize,
+    to: usize,
+    measurement: Pose<T, T>,
+    information: [T; 6],
+}
+
+impl<T> Constraint<T>
+where
+    T: Real,
+{
+    /// Create constraint stating that node `to` is seen at `measurement` from node `from`,
+    /// `information` holds weights of linear `x`, `y`, `z` and angular `x`, `y`, `z` residuals.
+    pub fn new(from: usize, to: usize, measurement: Pose<T, T>, information: [T; 6]) -> Self {
+        Self {
+            from,
+            to,
+            measurement,
+            information,
+        }
+    }
+
+    /// Get index of the observing node.
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// Get index of the observed node.
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    /// Get measured pose of the observed node relative to the observing one.
+    pub fn measurement(&self) -> Pose<T, T> {
+        self.measurement
+    }
+
+    /// Get residual weights.
+    pub fn information(&self) -> [T; 6] {
+        self.information
+    }
+
+    /// Calculate residual twist for given node poses.
+    fn residual(&self, from: Pose<T, T>, to: Pose<T, T>) -> Twist<T, T> {
+        (self.measurement.inverse() * from.inverse() * to).log()
+    }
+}
+
+/// Fixed-capacity pose graph of `N` nodes and up to `M` relative pose constraints,
+/// refined by Levenberg-Marquardt optimization without allocation.
+///
+/// Nodes are perturbed on the right, `pose * exp(delta)`, with Jacobians built from the pose
+/// adjoint and the first order approximation of the inverse right Jacobian of SE(3).
+/// The normal equations are dense, the normal matrix holds `36 * N^2` scalars and is factored
+/// in place, so the graph is meant for tens of nodes. The first node is fixed on creation to
+/// remove gauge freedom.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::optimization::{Constraint, PoseGraph};
+/// let step = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::identity());
+/// let guess = Pose::new(Vector::new(2.5, 0.3, 0.0), Quaternion::identity());
+/// let mut graph = PoseGraph::<f64, 3, 3>::new([Pose::identity(), step, guess]);
+/// let information = [1.0; 6];
+/// graph.add_constraint(Constraint::new(0, 1, step, information));
+/// graph.add_constraint(Constraint::new(1, 2, step, information));
+/// graph.add_constraint(Constraint::new(0, 2, step * step, information));
+/// let error = graph.optimize(10);
+/// assert!(error < 1e-18);
+/// assert!((graph.poses()[2].translation() - Vector::new(2.0, 0.0, 0.0)).norm() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PoseGraph<T, const N: usize, const M: usize> {
+    poses: [Pose<T, T>; N],
+    fixed: [bool; N],
+    constraints: [Option<Constraint<T>>; M],
+    len: usize,
+}
+
+impl<T, const N: usize, const M: usize> PoseGraph<T, N, M>
+where
+    T: Real,
+{
+    /// Create graph with given initial node poses and no constraints, the first node is fixed.
+    pub fn new(poses: [Pose<T, T>; N]) -> Self {
+        let mut fixed = [false; N];
+        if let Some(first) = fixed.first_mut() {
+            *first = true;
+        }
+        Self {
+            poses,
+            fixed,
+            constraints: [None; M],
+            len: 0,
+        }
+    }
+
+    /// Get current node poses.
+    pub fn poses(&self) -> &[Pose<T, T>; N] {
+        &self.poses
+    }
+
+    /// Set node pose, returns `false` for invalid index.
+    pub fn set_pose(&mut self, node: usize, pose: Pose<T, T>) -> bool {
+        match self.poses.get_mut(node) {
+            Some(stored) => {
+                *stored = pose;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Check whether node is kept constant during optimization.
+    pub fn is_fixed(&self, node: usize) -> bool {
+        self.fixed.get(node).copied().unwrap_or(false)
+    }
+
+    /// Set whether node is kept constant during optimization, returns `false` for invalid index.
+    pub fn set_fixed(&mut self, node: usize, fixed: bool) -> bool {
+        match self.fixed.get_mut(node) {
+            Some(stored) => {
+                *stored = fixed;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get number of constraints.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether graph has no constraints.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over constraints in insertion order.
+    pub fn constraints(&self) -> impl Iterator<Item = &Constraint<T>> {
+        self.constraints.iter().flatten()
+    }
+
+    /// Add constraint, returns `false` if the graph is full or node indices are invalid
+    /// or equal.
+    pub fn add_constraint(&mut self, constraint: Constraint<T>) -> bool {
+        if constraint.from >= N
+            || constraint.to >= N
+            || constraint.from == constraint.to
+            || self.len == M
+        {
+            return false;
+        }
+        self.constraints[self.len] = Some(constraint);
+        self.len += 1;
+        true
+    }
+
+    /// Calculate sum of weighted squared residuals of all constraints.
+    pub fn error(&self) -> T {
+        total_error(&self.poses, self.constraints())
+    }
+
+    /// Run up to `iterations` Levenberg-Marquardt iterations, returns final error.
+    /// Stops early once the error can no longer be reduced.
+    pub fn optimize(&mut self, iterations: usize) -> T {
+        self.iterate(iterations).0
+    }
+
+    /// Run Levenberg-Marquardt iterations like [`PoseGraph::optimize`], but fail with
+    /// [`ConvergenceError`] if the error was still decreasing after `iterations`. Poses keep
+    /// the last accepted solution in both cases.
+    pub fn try_optimize(&mut self, iterations: usize) -> Result<T, ConvergenceError<T>> {
+        match self.iterate(iterations) {
+            (error, true) => Ok(error),
+            (error, false) => Err(ConvergenceError { iterations, error }),
+        }
+    }
+
+    /// Run up to `iterations` iterations, returns final error and whether it stopped
+    /// decreasing.
+    fn iterate(&mut self, iterations: usize) -> (T, bool) {
+        let two = T::one() + T::one();
+        let ten = (two * two + T::one()) * two;
+        let mut damping = T::epsilon().sqrt();
+        let mut current = self.error();
+        for _ in 0..iterations {
+            let (mut hessian, gradient) = self.linearize();
+            let diagonal = core::array::from_fn(|node| {
+                core::array::from_fn(|index| hessian[node][node][index][index])
+            });
+            let mut improved = false;
+            while damping < T::one() / T::epsilon() {
+                let Some(step) = solve(&mut hessian, &diagonal, &gradient, damping) else {
+                    damping = damping * ten;
+                    continue;
+                };
+                let mut poses = self.poses;
+                for ((pose, delta), fixed) in poses.iter_mut().zip(step).zip(self.fixed) {
+                    if !fixed {
+                        let delta = Twist::new(
+                            Vector::new(-delta[0], -delta[1], -delta[2]),
+                            Vector::new(-delta[3], -delta[4], -delta[5]),
+                        );
+                        *pose *= Pose::exp(delta);
+                    }
+                }
+                let candidate = total_error(&poses, self.constraints());
+                if candidate < current {
+                    self.poses = poses;
+                    current = candidate;
+                    damping = damping / ten;
+                    improved = true;
+                    break;
+                }
+                damping = damping * ten;
+            }
+            if !improved {
+                return (current, true);
+            }
+        }
+        (current, false)
+    }
+
+    /// Build Gauss-Newton approximation of the error Hessian and the error gradient
+    /// in node twist coordinates, rows and columns of fixed nodes are replaced with identity.
+    fn linearize(&self) -> ([[Block<T>; N]; N], [[T; 6]; N]) {
+        let mut hessian = [[[[T::zero(); 6]; 6]; N]; N];
+        let mut gradient = [[T::zero(); 6]; N];
+        for constraint in self.constraints() {
+            let (i, j) = (constraint.from, constraint.to);
+            let (from, to) = (self.poses[i], self.poses[j]);
+            let residual = twist_coordinates(constraint.residual(from, to));
+            let inverse_jacobian = inverse_right_jacobian(&residual);
+            let jacobians = [
+                scale(
+                    &multiply(&inverse_jacobian, &(to.inverse() * from).adjoint()),
+                    -T::one(),
+                ),
+                inverse_jacobian,
+            ];
+            let nodes = [i, j];
+            for (a, first) in nodes.iter().zip(&jacobians) {
+                let weighted = weight(first, &constraint.information);
+                for (k, value) in gradient[*a].iter_mut().enumerate() {
+                    *value = *value + dot_column(&weighted, k, &residual);
+                }
+                for (b, second) in nodes.iter().zip(&jacobians) {
+                    let block = &mut hessian[*a][*b];
+                    for (row, values) in block.iter_mut().enumerate() {
+                        for (column, value) in values.iter_mut().enumerate() {
+                            let mut sum = T::zero();
+                            for k in 0..6 {
+                                sum = sum + weighted[k][row] * second[k][column];
+                            }
+                            *value = *value + sum;
+                        }
+                    }
+                }
+            }
+        }
+        for (node, fixed) in self.fixed.iter().enumerate() {
+            if *fixed {
+                hessian[node] = [[[T::zero(); 6]; 6]; N];
+                for row in hessian.iter_mut() {
+                    row[node] = [[T::zero(); 6]; 6];
+                }
+                hessian[node][node] = identity();
+                gradient[node] = [T::zero(); 6];
+            }
+        }
+        (hessian, gradient)
+    }
+}
+
+/// Graphs are equal when their nodes and added constraints are equal, unused constraint
+/// slots are ignored.
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq for PoseGraph<T, N, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.poses == other.poses
+            && self.fixed == other.fixed
+            && self.constraints[..self.len] == other.constraints[..other.len]
+    }
+}
+
+/// Calculate sum of weighted squared residuals of constraints for given poses.
+fn total_error<'a, T, const N: usize>(
+    poses: &[Pose<T, T>; N],
+    constraints: impl Iterator<Item = &'a Constraint<T>>,
+) -> T
+where
+    T: Real + 'a,
+{
+    constraints.fold(T::zero(), |sum, constraint| {
+        let residual =
+            twist_coordinates(constraint.residual(poses[constraint.from], poses[constraint.to]));
+        residual
+            .iter()
+            .zip(constraint.information)
+            .fold(sum, |sum, (value, weight)| sum + weight * *value * *value)
+    })
+}
+
+/// Solve damped normal equations `(H + damping * max(diag(H), 1)) x = g` by Cholesky
+/// decomposition, returns `None` if the damped matrix is not positive definite. Flooring the
+/// diagonal keeps coordinates without constraints, e.g. of unconstrained nodes, solvable.
+///
+/// The factor overwrites diagonal and lower triangle of the symmetric matrix, its strict upper
+/// triangle and given diagonal are kept, so it can be factored again with other damping.
+fn solve<T, const N: usize>(
+    hessian: &mut [[Block<T>; N]; N],
+    diagonal: &[[T; 6]; N],
+    gradient: &[[T; 6]; N],
+    damping: T,
+) -> Option<[[T; 6]; N]>
+where
+    T: Real,
+{
+    let size = 6 * N;
+    let at = |matrix: &[[Block<T>; N]; N], row: usize, column: usize| {
+        matrix[row / 6][column / 6][row % 6][column % 6]
+    };
+    for column in 0..size {
+        let diagonal = diagonal[column / 6][column % 6];
+        let scale = if diagonal > T::one() {
+            diagonal
+        } else {
+            T::one()
+        };
+        let mut sum = diagonal + damping * scale;
+        for k in 0..column {
+            let value = at(hessian, column, k);
+            sum = sum - value * value;
+        }
+        if sum <= T::zero() {
+            return None;
+        }
+        let pivot = sum.sqrt();
+        hessian[column / 6][column / 6][column % 6][column % 6] = pivot;
+        for row in column + 1..size {
+            let mut sum = at(hessian, column, row);
+            for k in 0..column {
+                sum = sum - at(hessian, row, k) * at(hessian, column, k);
+            }
+            hessian[row / 6][column / 6][row % 6][column % 6] = sum / pivot;
+        }
+    }
+    let mut solution = *gradient;
+    for row in 0..size {
+        let mut sum = solution[row / 6][row % 6];
+        for k in 0..row {
+            sum = sum - at(hessian, row, k) * solution[k / 6][k % 6];
+        }
+        solution[row / 6][row % 6] = sum / at(hessian, row, row);
+    }
+    for row in (0..size).rev() {
+        let mut sum = solution[row / 6][row % 6];
+        for k in row + 1..size {
+            sum = sum - at(hessian, k, row) * solution[k / 6][k % 6];
+        }
+        solution[row / 6][row % 6] = sum / at(hessian, row, row);
+    }
+    Some(solution)
+}
+
+/// Convert twist into `[linear, angular]` coordinates.
+fn twist_coordinates<T: Copy>(twist: Twist<T, T>) -> [T; 6] {
+    let (linear, angular) = (twist.linear(), twist.angular());
+    [
+        linear.x(),
+        linear.y(),
+        linear.z(),
+        angular.x(),
+        angular.y(),
+        angular.z(),
+    ]
+}
+
+/// Calculate first order approximation `I + ad(residual) / 2` of the inverse
+/// right Jacobian of SE(3).
+fn inverse_right_jacobian<T: Real>(residual: &[T; 6]) -> Block<T> {
+    let half = T::one() / (T::one() + T::one());
+    let skew = |x: T, y: T, z: T| {
+        [
+            [T::zero(), -z * half, y * half],
+            [z * half, T::zero(), -x * half],
+            [-y * half, x * half, T::zero()],
+        ]
+    };
+    let linear = skew(residual[0], residual[1], residual[2]);
+    let angular = skew(residual[3], residual[4], residual[5]);
+    let mut result = identity();
+    for row in 0..3 {
+        for column in 0..3 {
+            result[row][column] = result[row][column] + angular[row][column];
+            result[row + 3][column + 3] = result[row + 3][column + 3] + angular[row][column];
+            result[row][column + 3] = linear[row][column];
+        }
+    }
+    result
+}
+
+/// Multiply every matrix element by scalar.
+fn scale<T: Real>(matrix: &Block<T>, factor: T) -> Block<T> {
+    matrix.map(|row| row.map(|value| value * factor))
+}
+
+/// Multiply matrix rows by diagonal weights.
+fn weight<T: Real>(matrix: &Block<T>, weights: &[T; 6]) -> Block<T> {
+    let mut result = *matrix;
+    for (row, weight) in result.iter_mut().zip(weights) {
+        *row = row.map(|value| value * *weight);
+    }
+    result
+}
+
+/// Calculate dot product of matrix column with vector.
+fn dot_column<T: Real>(matrix: &Block<T>, column: usize, vector: &[T; 6]) -> T {
+    matrix
+        .iter()
+        .zip(vector)
+        .fold(T::zero(), |sum, (row, value)| sum + row[column] * *value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Constraint, PoseGraph};
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn loop_closure_recovers_ground_truth() {
+        let truth: [Pose<f64, f64>; 5] = core::array::from_fn(|index| {
+            let angle = core::f64::consts::FRAC_PI_2 * index as f64;
+            Pose::new(
+                Vector::new(angle.cos(), angle.sin(), 0.1 * index as f64),
+                Quaternion::from_angle_axis(angle + 0.3, Vector::new(0.2, -0.1, 1.0)),
+            )
+        });
+        let noise = |index: usize| {
+            let value = 0.05 * (index as f64 + 1.0);
+            Pose::new(
+                Vector::new(value, -value, 0.5 * value),
+                Quaternion::from_angle_axis(value, Vector::new(1.0, 0.5, -0.2)),
+            )
+        };
+        let mut guess = truth;
+        for (index, pose) in guess.iter_mut().enumerate().skip(1) {
+            *pose *= noise(index);
+        }
+        let mut graph = PoseGraph::<f64, 5, 6>::new(guess);
+        let information = [1.0, 1.0, 1.0, 10.0, 10.0, 10.0];
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 4), (4, 0), (1, 3)] {
+            let measurement = truth[from].inverse() * truth[to];
+            assert!(graph.add_constraint(Constraint::new(from, to, measurement, information)));
+        }
+        assert!(!graph.add_constraint(Constraint::new(0, 1, truth[1], information)));
+        assert!(graph.error() > 1e-2);
+        let error = graph.try_optimize(1).unwrap_err();
+        assert_eq!(error.iterations, 1);
+        assert!(error.error < 1e-2);
+        assert!(graph.optimize(50) < 1e-20);
+        assert!(graph.try_optimize(5).unwrap() < 1e-20);
+        for (actual, expected) in graph.poses().iter().zip(truth) {
+            assert!((actual.translation() - expected.translation()).norm() < 1e-9);
+            assert!((actual.rotation().dot(expected.rotation()).abs() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn unconstrained_node_does_not_block_optimization() {
+        let step = Pose::new(
+            Vector::new(1.0_f64, 0.0, 0.0),
+            Quaternion::from_angle_axis(0.3, Vector::unit_z()),
+        );
+        let loose = Pose::new(Vector::new(5.0, 5.0, 5.0), Quaternion::identity());
+        let noise = Pose::new(
+            Vector::new(0.2, -0.3, 0.1),
+            Quaternion::from_angle_axis(0.2, Vector::new(1.0, 0.5, 0.0)),
+        );
+        let guess = [Pose::identity(), step * noise, step * step, loose];
+        let mut graph = PoseGraph::<f64, 4, 3>::new(guess);
+        let information = [1.0; 6];
+        graph.add_constraint(Constraint::new(0, 1, step, information));
+        graph.add_constraint(Constraint::new(1, 2, step, information));
+        graph.add_constraint(Constraint::new(0, 2, step * step, information));
+        assert!(graph.error() > 1e-2);
+        assert!(graph.try_optimize(50).unwrap() < 1e-20);
+        assert!((graph.poses()[1].translation() - step.translation()).norm() < 1e-9);
+        assert_eq!(graph.poses()[3], loose);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Floating point scalar with total order of its bit patterns.
+pub trait TotalOrder: Copy {
+    /// Integer key ordered like [`f64::total_cmp`], with both zeros mapped to the same key.
+    type Key: Copy + Ord + Hash;
+    /// Get ordering key of this value.
+    fn total_key(self) -> Self::Key;
+}
+
+/// Implement total order for given floating point type and its signed and unsigned bit
+/// pattern types.
+macro_rules! impl_total_order {
+    ($($float:ty => $bits:ty, $unsigned:ty);+) => {$(
+        impl TotalOrder for $float {
+            type Key = $bits;
+            fn total_key(self) -> $bits {
+                // Adding zero turns negative zero into positive one and keeps other values.
+                let bits = (self + 0.0).to_bits() as $bits;
+                // Flip magnitude bits of negative values, they are ordered backwards.
+                bits ^ ((((bits >> (<$bits>::BITS - 1)) as $unsigned) >> 1) as $bits)
+            }
+        }
+    )+};
+}
+
+impl_total_order!(f32 => i32, u32; f64 => i64, u64);
+
+/// Vector compared, ordered and hashed by bit patterns of its coordinates, e.g. to use it as
+/// key of hash map. Negative zero equals positive one, `NaN` equals itself.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::ordered::OrderedVector;
+/// let mut cache = std::collections::HashMap::new();
+/// cache.insert(OrderedVector(Vector::new(1.0, -0.0, 2.0)), "marker");
+/// assert_eq!(cache.get(&OrderedVector(Vector::new(1.0, 0.0, 2.0))), Some(&"marker"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedVector<T>(pub Vector<T>);
+
+impl<T: TotalOrder> OrderedVector<T> {
+    fn key(&self) -> [T::Key; 3] {
+        self.0.as_array().map(T::total_key)
+    }
+}
+
+/// Quaternion compared, ordered and hashed by bit patterns of its components, see
+/// [`OrderedVector`]. Opposite quaternions are different keys even though they represent
+/// the same rotation.
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedQuaternion<T>(pub Quaternion<T>);
+
+impl<T: TotalOrder> OrderedQuaternion<T> {
+    fn key(&self) -> [T::Key; 4] {
+        self.0.as_array().map(T::total_key)
+    }
+}
+
+/// Pose compared, ordered and hashed by bit patterns of its translation and rotation, see
+/// [`OrderedVector`] and [`OrderedQuaternion`].
+/// ```
+/// # use spatial::pose::Pose;
+/// # use spatial::ordered::OrderedPose;
+/// let mut lookups = std::collections::HashMap::new();
+/// let pose = Pose::<f64, f64>::from_xyz_rpy(0.1, 0.2, 0.3, 0.0, 0.5, 1.0);
+/// lookups.insert(OrderedPose(pose), pose.inverse());
+/// assert_eq!(lookups[&OrderedPose(pose)], pose.inverse());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OrderedPose<T, R>(pub Pose<T, R>);
+
+impl<T: TotalOrder, R: TotalOrder> OrderedPose<T, R> {
+    fn key(&self) -> ([T::Key; 3], [R::Key; 4]) {
+        (
+            self.0.translation_ref().as_array().map(T::total_key),
+            self.0.rotation_ref().as_array().map(R::total_key),
+        )
+    }
+}
+
+/// Implement comparison and hashing through `key` method for given wrappers.
+macro_rules! impl_ordered {
+    ($($wrapper:ident<$($scalar:ident),+>),+) => {$(
+        impl<$($scalar: TotalOrder),+> PartialEq for $wrapper<$($scalar),+> {
+            fn eq(&self, other: &Self) -> bool {
+                self.key() == other.key()
+            }
+        }
+
+        impl<$($scalar: TotalOrder),+> Eq for $wrapper<$($scalar),+> {}
+
+        impl<$($scalar: TotalOrder),+> PartialOrd for $wrapper<$($scalar),+> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<$($scalar: TotalOrder),+> Ord for $wrapper<$($scalar),+> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.key().cmp(&other.key())
+            }
+        }
+
+        impl<$($scalar: TotalOrder),+> Hash for $wrapper<$($scalar),+> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.key().hash(state)
+            }
+        }
+    )+};
+}
+
+impl_ordered!(OrderedVector<T>, OrderedQuaternion<T>, OrderedPose<T, R>);
+
+#[cfg(test)]
+mod test {
+    use super::{OrderedPose, OrderedQuaternion, OrderedVector, TotalOrder};
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn keys_follow_total_order() {
+        let mut values = [
+            f64::NAN,
+            1.5,
+            -0.0,
+            f64::INFINITY,
+            -2.0,
+            f64::MIN_POSITIVE,
+            -f64::NAN,
+            0.0,
+            f64::NEG_INFINITY,
+        ];
+        values.sort_unstable_by_key(|value| value.total_key());
+        assert_eq!(
+            values[2..8],
+            [-2.0, -0.0, 0.0, f64::MIN_POSITIVE, 1.5, f64::INFINITY]
+        );
+        assert!(values[0].is_nan() && values[8].is_nan());
+        assert_eq!(values[1], f64::NEG_INFINITY);
+        assert_eq!((-0.0_f32).total_key(), 0.0_f32.total_key());
+        assert!((-1.0_f32).total_key() < f32::MIN_POSITIVE.total_key());
+        let nan = OrderedVector(Vector::new(f32::NAN, 0.0, 1.0));
+        assert_eq!(nan, nan);
+        assert!(
+            OrderedVector(Vector::new(1.0, 2.0, 3.0)) < OrderedVector(Vector::new(1.0, 2.5, 0.0))
+        );
+        let rotation = Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0);
+        assert_ne!(OrderedQuaternion(rotation), OrderedQuaternion(-rotation));
+        let pose = Pose::new(Vector::new(-0.0, 1.0, 2.0), rotation);
+        let same = Pose::new(Vector::new(0.0, 1.0, 2.0), rotation);
+        assert_eq!(OrderedPose(pose), OrderedPose(same));
+    }
+}
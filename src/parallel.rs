@@ -0,0 +1,136 @@
+//! Multi-threaded batch operations built on scoped `std` threads.
+//!
+//! Inputs shorter than the configured threshold are processed on the calling thread,
+//! since spawning threads costs more than transforming a few thousand vectors.
+
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use core::num::NonZeroUsize;
+use core::ops::{Add, Mul, Sub};
+use std::thread;
+
+/// Configuration of parallel batch operations.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::parallel::Parallel;
+/// let pose = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0));
+/// let mut points = vec![Vector::new(1.0, 2.0, 3.0); 10_000];
+/// Parallel::new(1000).transform_in_place(&pose, &mut points);
+/// assert!(points.iter().all(|point| *point == Vector::new(0.0, -2.0, 3.0)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Parallel {
+    threshold: usize,
+    threads: Option<NonZeroUsize>,
+}
+
+impl Parallel {
+    /// Create configuration which processes inputs of at least `threshold` elements
+    /// on all available cores.
+    pub const fn new(threshold: usize) -> Self {
+        Self {
+            threshold,
+            threads: None,
+        }
+    }
+
+    /// Limit number of threads used for large inputs.
+    pub const fn with_threads(self, threads: NonZeroUsize) -> Self {
+        Self {
+            threshold: self.threshold,
+            threads: Some(threads),
+        }
+    }
+
+    /// Get minimal number of elements processed in parallel.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Get number of threads used for large inputs.
+    pub fn threads(&self) -> usize {
+        self.threads
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get)
+    }
+
+    /// Apply transform stored in the pose to every vector of the slice in place,
+    /// see [`Pose::transform_in_place`].
+    pub fn transform_in_place<T, R>(&self, pose: &Pose<T, R>, vectors: &mut [Vector<T>])
+    where
+        T: Copy + Add<Output = T> + Sub<Output = T> + Send + Sync,
+        R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T> + Sync,
+    {
+        self.for_each_chunk(vectors, |chunk| pose.transform_in_place(chunk));
+    }
+
+    /// Rotate every vector of the slice in place, see [`Quaternion::transform_in_place`].
+    pub fn rotate_in_place<T>(&self, rotation: &Quaternion<T>, vectors: &mut [Vector<T>])
+    where
+        T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Send + Sync,
+    {
+        let rotation = rotation.prepare();
+        self.for_each_chunk(vectors, |chunk| rotation.rotate_in_place(chunk));
+    }
+
+    /// Split slice into one chunk per thread and process chunks concurrently.
+    fn for_each_chunk<V, F>(&self, values: &mut [V], operation: F)
+    where
+        V: Send,
+        F: Fn(&mut [V]) + Sync,
+    {
+        let threads = self.threads();
+        if values.len() < self.threshold.max(2) || threads < 2 {
+            operation(values);
+            return;
+        }
+        let chunk = values.len().div_ceil(threads);
+        let operation = &operation;
+        thread::scope(|scope| {
+            for values in values.chunks_mut(chunk) {
+                scope.spawn(move || operation(values));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::Parallel;
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+    use core::num::NonZeroUsize;
+    use std::vec::Vec;
+
+    #[test]
+    fn parallel_transform_matches_sequential() {
+        let pose = Pose::new(
+            Vector::new(0.5, -1.0, 2.0),
+            Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5),
+        );
+        let points: Vec<_> = (0..1001)
+            .map(|index| Vector::new(f64::from(index), 1.0, -2.0))
+            .collect();
+        let mut expected = points.clone();
+        pose.transform_in_place(&mut expected);
+        for threads in [1, 3, 8] {
+            let parallel = Parallel::new(10).with_threads(NonZeroUsize::new(threads).unwrap());
+            let mut actual = points.clone();
+            parallel.transform_in_place(&pose, &mut actual);
+            assert_eq!(actual, expected);
+        }
+        let mut rotated = points.clone();
+        Parallel::new(10).rotate_in_place(pose.rotation_ref(), &mut rotated);
+        let translation = pose.translation();
+        assert!(rotated
+            .iter()
+            .zip(&expected)
+            .all(|(rotated, expected)| *rotated + translation == *expected));
+    }
+}
@@ -1,27 +1,60 @@
-use crate::quaternion::Quaternion;
+#[cfg(feature = "num")]
+use crate::error::ValidationError;
+use crate::error::{to_array, ConversionError};
+#[cfg(feature = "num")]
+use crate::geometry::{Line, Plane};
+#[cfg(feature = "num")]
+use crate::ops::{is_finite, IntoProgress, Norm, Real};
+use crate::ops::{Epsilon, Sqrt};
+#[cfg(feature = "num")]
+use crate::quaternion::skew;
+use crate::quaternion::{Quaternion, UnitQuaternion};
+use crate::twist::Twist;
 use crate::vector::Vector;
-use core::ops::{Add, Mul, Neg, Sub};
+use crate::wrench::Wrench;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Div, Mul, MulAssign, Neg, Sub};
 #[cfg(feature = "num")]
 use num::{One, Zero};
 
 /// Spatial pose in 3D space.
 /// Consists of consecutive translation and rotation in parent space.
+/// Rotation scalar defaults to the translation one, so `Pose<f64>` is `Pose<f64, f64>`.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
-pub struct Pose<T, R> {
+#[repr(C)]
+pub struct Pose<T, R = T> {
     translation: Vector<T>,
     rotation: Quaternion<R>,
 }
 
 impl<T, R> Pose<T, R> {
     /// Create new `Pose` with given translation and rotation.
-    pub fn new(translation: Vector<T>, rotation: Quaternion<R>) -> Self {
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// static MOUNT: Pose<f32, f32> = Pose::new(
+    ///     Vector::new(0.1, 0.0, 0.25),
+    ///     Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0),
+    /// );
+    /// assert_eq!(MOUNT.translation().z(), 0.25);
+    /// ```
+    pub const fn new(translation: Vector<T>, rotation: Quaternion<R>) -> Self {
         Self {
             translation,
             rotation,
         }
     }
 
+    /// Create new `Pose` from rotation guaranteed to be unit.
+    pub fn from_unit(translation: Vector<T>, rotation: UnitQuaternion<R>) -> Self {
+        Self {
+            translation,
+            rotation: rotation.into_inner(),
+        }
+    }
+
     /// Create pose representing no translation and no rotation.
     #[cfg(feature = "num")]
     pub fn identity() -> Self
@@ -67,6 +100,47 @@ impl<T, R> Pose<T, R> {
         self.rotation = rotation;
     }
 
+    /// Apply function to every translation component, keeping rotation.
+    pub fn map_translation<F: Fn(T) -> U, U>(self, mapper: F) -> Pose<U, R> {
+        Pose {
+            translation: self.translation.map(mapper),
+            rotation: self.rotation,
+        }
+    }
+
+    /// Apply function to every rotation component, keeping translation.
+    pub fn map_rotation<F: Fn(R) -> S, S>(self, mapper: F) -> Pose<T, S> {
+        Pose {
+            translation: self.translation,
+            rotation: self.rotation.map(mapper),
+        }
+    }
+
+    /// Convert translation and rotation components with `as` semantics, e.g. to hand poses
+    /// computed in `f64` to `f32` rendering.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::new(Vector::new(1.0_f64, 2.0, 3.0), Quaternion::with_wijk(0.0_f64, 0.0, 1.0, 0.0));
+    /// let rendered: Pose<f32, f32> = pose.cast();
+    /// assert_eq!(rendered.translation(), Vector::new(1.0, 2.0, 3.0));
+    /// assert_eq!(rendered.rotation(), Quaternion::with_wijk(0.0, 0.0, 1.0, 0.0));
+    /// ```
+    #[cfg(feature = "num")]
+    pub fn cast<U, S>(self) -> Pose<U, S>
+    where
+        T: num::traits::AsPrimitive<U>,
+        R: num::traits::AsPrimitive<S>,
+        U: Copy + 'static,
+        S: Copy + 'static,
+    {
+        Pose {
+            translation: self.translation.cast(),
+            rotation: self.rotation.cast(),
+        }
+    }
+
     /// Get translation.
     pub fn translation(&self) -> Vector<T>
     where
@@ -84,6 +158,50 @@ impl<T, R> Pose<T, R> {
     }
 }
 
+/// Implement constant poses for given floating point type.
+macro_rules! impl_pose_constants {
+    ($($float:ty),+) => {$(
+        impl Pose<$float, $float> {
+            /// Pose representing no translation and no rotation.
+            pub const IDENTITY: Self = Self::new(Vector::<$float>::ZERO, Quaternion::<$float>::IDENTITY);
+        }
+    )+};
+}
+
+impl_pose_constants!(f32, f64);
+
+/// Pose with `f32` components.
+pub type Posef = Pose<f32, f32>;
+/// Pose with `f64` components.
+pub type Posed = Pose<f64, f64>;
+
+/// Implement convenience methods for given floating point type.
+macro_rules! impl_pose_helpers {
+    ($($float:ty),+) => {$(
+        impl Pose<$float, $float> {
+            /// Create pose from translation components and roll, pitch and yaw angles in radians,
+            /// see [`Quaternion::from_rpy`].
+            pub fn from_xyz_rpy(
+                x: $float,
+                y: $float,
+                z: $float,
+                roll: $float,
+                pitch: $float,
+                yaw: $float,
+            ) -> Self {
+                Self::new(Vector::new(x, y, z), Quaternion::<$float>::from_rpy(roll, pitch, yaw))
+            }
+
+            /// Normalize rotation of this pose, see [`Quaternion::normalize`].
+            pub fn normalize(self) -> Self {
+                Self::new(self.translation, self.rotation.normalize())
+            }
+        }
+    )+};
+}
+
+impl_pose_helpers!(f32, f64);
+
 impl<T, R> Pose<T, R>
 where
     T: Copy + Add<Output = T> + Sub<Output = T>,
@@ -95,6 +213,7 @@ where
     /// # use spatial::quaternion::Quaternion;
     /// # use spatial::pose::Pose;
     /// # use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+    /// # #[cfg(feature = "num")] {
     /// let first = Pose::new(
     ///     Vector::new(0.0, 0.0, 2.0),
     ///     Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()),
@@ -111,6 +230,7 @@ where
     /// );
     /// assert!((result.translation() - expected.translation()).norm() < 1e-3);
     /// assert!((result.rotation().dot(expected.rotation()) - 1.0).abs() < 1e-3);
+    /// # }
     /// ```
     pub fn combine(self, other: Self) -> Self {
         let translation = self.translation + self.rotation.rotate(other.translation);
@@ -127,6 +247,7 @@ where
     /// # use spatial::quaternion::Quaternion;
     /// # use spatial::pose::Pose;
     /// # use core::f64::consts::FRAC_PI_2;
+    /// # #[cfg(feature = "num")] {
     /// let pose = Pose::new(
     ///     Vector::new(1.0, 2.0, 3.0),
     ///     Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()),
@@ -135,17 +256,65 @@ where
     /// let result = pose.apply_to(vector);
     /// let expected = Vector::new(1.0, 3.0, 3.0);
     /// assert!((result - expected).norm() < 1e-3);
+    /// # }
     /// ```
     pub fn apply_to(self, other: Vector<T>) -> Vector<T> {
         self.translation + self.rotation.rotate(other)
     }
 
+    /// Apply transform stored in this pose to every vector of the slice in place,
+    /// see [`Quaternion::transform_in_place`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0));
+    /// let mut points = [Vector::new(1.0, 2.0, 3.0); 20];
+    /// pose.transform_in_place(&mut points);
+    /// assert!(points.iter().all(|point| *point == Vector::new(0.0, -2.0, 3.0)));
+    /// ```
+    pub fn transform_in_place(&self, vectors: &mut [Vector<T>]) {
+        let rotation = self.rotation.prepare();
+        for vector in vectors {
+            *vector = self.translation + rotation.rotate(*vector);
+        }
+    }
+
+    /// Apply transform stored in this pose to every point of the slice in place, the batch
+    /// counterpart of [`Pose::apply_to`]. Rotation matrix is computed once for the whole
+    /// slice, see [`Pose::transform_in_place`].
+    pub fn apply_to_slice(&self, points: &mut [Vector<T>]) {
+        self.transform_in_place(points);
+    }
+
+    /// Replace every pose of the slice with its combination `self * pose`, the batch
+    /// counterpart of [`Pose::combine`]. Rotation matrix is computed once for the whole
+    /// slice and only rotations are multiplied as quaternions.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let base = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0));
+    /// let link = Pose::new(Vector::new(0.0, 2.0, 0.0), Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0));
+    /// let mut poses = [link; 20];
+    /// base.combine_slice(&mut poses);
+    /// assert!(poses.iter().all(|pose| *pose == base * link));
+    /// ```
+    pub fn combine_slice(&self, poses: &mut [Self]) {
+        let rotation = self.rotation.prepare();
+        for pose in poses {
+            pose.translation = self.translation + rotation.rotate(pose.translation);
+            pose.rotation = self.rotation * pose.rotation;
+        }
+    }
+
     /// Calculates inverse translation such that `a * a.inverse() = Pose::identity()`.
     /// ```
     /// # use spatial::vector::Vector;
     /// # use spatial::quaternion::Quaternion;
     /// # use spatial::pose::Pose;
     /// # use core::f64::consts::FRAC_PI_4;
+    /// # #[cfg(feature = "num")] {
     /// let pose = Pose::new(
     ///     Vector::new(1.0, 2.0, 3.0),
     ///     Quaternion::from_angle_axis(FRAC_PI_4, Vector::unit_x()),
@@ -154,6 +323,7 @@ where
     /// let expected = Pose::identity();
     /// assert!((result.translation() - expected.translation()).norm() < 1e-3);
     /// assert!((result.rotation().dot(expected.rotation()) - 1.0).abs() < 1e-3);
+    /// # }
     /// ```
     pub fn inverse(self) -> Self
     where
@@ -166,6 +336,1020 @@ where
             rotation: inverse_rotation,
         }
     }
+
+    /// Calculate pose of other relative to this one, `self.inverse() * other`, e.g. odometry
+    /// measurement between consecutive poses of a graph.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use core::f64::consts::FRAC_PI_2;
+    /// # #[cfg(feature = "num")] {
+    /// let from = Pose::new(
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()),
+    /// );
+    /// let to = Pose::new(Vector::new(1.0, 2.0, 0.0), from.rotation());
+    /// let relative = from.between(to);
+    /// assert!((relative.translation() - Vector::new(2.0, 0.0, 0.0)).norm() < 1e-12);
+    /// assert!(((from * relative).translation() - to.translation()).norm() < 1e-12);
+    /// # }
+    /// ```
+    pub fn between(self, other: Self) -> Self
+    where
+        T: Neg<Output = T>,
+        R: Neg<Output = R>,
+    {
+        self.inverse() * other
+    }
+}
+
+impl<T, R> Pose<T, R>
+where
+    T: Copy,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R>,
+{
+    /// Express twist given in the frame of this pose in the parent frame.
+    ///
+    /// Angular velocity is rotated into the parent frame, linear velocity is rotated and
+    /// complemented with the velocity induced at the parent origin by the angular velocity.
+    /// Rotated angular velocity type `WR` may differ from `W` (e.g. lose `uom` kind),
+    /// it is converted back with `Into`.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::twist::Twist;
+    /// # use core::f64::consts::FRAC_PI_2;
+    /// # #[cfg(feature = "num")] {
+    /// let pose = Pose::new(
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()),
+    /// );
+    /// let twist = Twist::new(Vector::<f64>::unit_x(), Vector::<f64>::unit_x());
+    /// let result = pose.transform_twist(twist);
+    /// assert!((result.angular() - Vector::unit_y()).norm() < 1e-9);
+    /// assert!((result.linear() - Vector::new(0.0, 1.0, 1.0)).norm() < 1e-9);
+    /// # }
+    /// ```
+    pub fn transform_twist<V, W, WR>(self, twist: Twist<V, W>) -> Twist<V, W>
+    where
+        T: Mul<W, Output = V>,
+        R: Mul<V, Output = V> + Mul<W, Output = WR> + Mul<WR, Output = WR>,
+        V: Copy + Add<Output = V> + Sub<Output = V>,
+        W: Copy,
+        WR: Copy + Add<Output = WR> + Sub<Output = WR> + Into<W>,
+    {
+        let angular: Vector<W> = self.rotation.rotate(twist.angular()).into();
+        let linear = self.rotation.rotate(twist.linear()) + self.translation.cross(angular);
+        Twist::new(linear, angular)
+    }
+
+    /// Express wrench given in the frame of this pose in the parent frame.
+    ///
+    /// Force is rotated into the parent frame, torque is rotated and complemented with
+    /// the torque induced at the parent origin by the force.
+    /// Torque type `MR` produced by rotation and lever arm coupling may differ from `M`
+    /// (e.g. `uom` energy instead of torque), it is converted back with `Into`.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::wrench::Wrench;
+    /// # use core::f64::consts::FRAC_PI_2;
+    /// # #[cfg(feature = "num")] {
+    /// let pose = Pose::new(
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()),
+    /// );
+    /// let wrench = Wrench::new(Vector::<f64>::unit_x(), Vector::<f64>::unit_x());
+    /// let result = pose.transform_wrench(wrench);
+    /// assert!((result.force() - Vector::unit_y()).norm() < 1e-9);
+    /// assert!((result.torque() - Vector::new(0.0, 1.0, 1.0)).norm() < 1e-9);
+    /// # }
+    /// ```
+    pub fn transform_wrench<F, M, MR>(self, wrench: Wrench<F, M>) -> Wrench<F, M>
+    where
+        T: Mul<F, Output = MR>,
+        R: Mul<F, Output = F> + Mul<M, Output = MR> + Mul<MR, Output = MR>,
+        F: Copy + Add<Output = F> + Sub<Output = F>,
+        M: Copy,
+        MR: Copy + Add<Output = MR> + Sub<Output = MR> + Into<M>,
+    {
+        let force: Vector<F> = self.rotation.rotate(wrench.force());
+        let torque: Vector<MR> = self.rotation.rotate(wrench.torque());
+        let torque = torque + self.translation.cross(force);
+        Wrench::new(force, torque.into())
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T, R> Pose<T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<R, Output = T>,
+    R: Real,
+{
+    /// Interpolate between this and other pose, translation is interpolated linearly
+    /// and rotation is interpolated spherically. `progress` is either plain value,
+    /// [`Progress`](crate::ops::Progress) or, with the `uom` feature, `Ratio`.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::ops::Progress;
+    /// # use spatial::pose::Pose;
+    /// # use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+    /// let start = Pose::identity();
+    /// let end = Pose::new(
+    ///     Vector::new(2.0, 0.0, 0.0),
+    ///     Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()),
+    /// );
+    /// let result = start.interpolate(end, 0.5);
+    /// let expected = Pose::new(
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Quaternion::from_angle_axis(FRAC_PI_4, Vector::unit_z()),
+    /// );
+    /// assert!((result.translation() - expected.translation()).norm() < 1e-3);
+    /// assert!((result.rotation().dot(expected.rotation()) - 1.0).abs() < 1e-3);
+    /// assert_eq!(start.interpolate(end, Progress::new(0.5)), result);
+    /// ```
+    pub fn interpolate<P: IntoProgress<R>>(self, other: Self, progress: P) -> Self {
+        let progress = progress.into_progress();
+        Self {
+            translation: self.translation.interpolate(other.translation, progress),
+            rotation: self.rotation.slerp(other.rotation, progress),
+        }
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> Pose<T, T>
+where
+    T: Real,
+{
+    /// Exponential map, creates pose reached by moving with given body-frame twist
+    /// for unit time.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::twist::Twist;
+    /// # use core::f64::consts::PI;
+    /// let twist = Twist::new(Vector::new(PI / 2.0, 0.0, 0.0), Vector::new(0.0, 0.0, PI));
+    /// let result = Pose::exp(twist);
+    /// assert!((result.translation() - Vector::new(0.0, 1.0, 0.0)).norm() < 1e-9);
+    /// ```
+    pub fn exp(twist: Twist<T, T>) -> Self {
+        let (velocity, rotation) = (twist.linear(), twist.angular());
+        let angle = rotation.norm();
+        let squared = angle * angle;
+        let (a, b) = if angle < T::epsilon() {
+            let two = T::one() + T::one();
+            let six = two * (two + T::one());
+            let twenty_four = six * two * two;
+            let one_hundred_twenty = twenty_four * (two + two + T::one());
+            (
+                T::one() / two - squared / twenty_four,
+                T::one() / six - squared / one_hundred_twenty,
+            )
+        } else {
+            // Half-angle form of `1 - cos(angle)` avoids cancellation for small angles.
+            let sin = (angle / (T::one() + T::one())).sin();
+            (
+                (sin * sin + sin * sin) / squared,
+                (angle - angle.sin()) / (squared * angle),
+            )
+        };
+        let first = rotation.cross(velocity);
+        let second = rotation.cross(first);
+        Self {
+            translation: velocity + first * a + second * b,
+            rotation: Quaternion::exp(rotation),
+        }
+    }
+
+    /// Calculate distance between translations and geodesic angle between rotations of this
+    /// and other pose, see [`Quaternion::angle_to`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let from = Pose::new(Vector::new(1.0_f64, 0.0, 0.0), Quaternion::identity());
+    /// let to = Pose::new(
+    ///     Vector::new(1.0, 3.0, 4.0),
+    ///     Quaternion::from_angle_axis(0.2, Vector::new(1.0, 1.0, 0.0)),
+    /// );
+    /// let (translation, rotation) = from.distance_to(to);
+    /// assert!((translation - 5.0).abs() < 1e-12);
+    /// assert!((rotation - 0.2).abs() < 1e-12);
+    /// ```
+    pub fn distance_to(self, other: Self) -> (T, T) {
+        (
+            (other.translation - self.translation).norm(),
+            self.rotation.angle_to(other.rotation),
+        )
+    }
+
+    /// Check that the pose is a rigid transform, i.e. its rotation is unit within `tolerance`,
+    /// see [`Quaternion::is_normalized`]. Useful to assert the invariant while hunting drift.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let drifted = Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::with_wijk(0.0, 1.002, 0.0, 0.0));
+    /// assert!(!drifted.is_rigid(1e-3));
+    /// assert!(drifted.renormalized().is_rigid(1e-12));
+    /// ```
+    pub fn is_rigid(self, tolerance: T) -> bool {
+        self.rotation.is_normalized(tolerance)
+    }
+
+    /// Create pose from externally supplied translation and rotation, failing with
+    /// [`ValidationError`] unless all components are finite and the rotation is unit within
+    /// `tolerance`, see [`Pose::is_rigid`].
+    /// ```
+    /// # use spatial::error::ValidationError;
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let mount = Pose::try_new(Vector::new(0.1, 0.0, 0.25), Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0), 1e-6);
+    /// assert!(mount.is_ok());
+    /// let scaled = Pose::try_new(Vector::zero(), Quaternion::with_wijk(2.0_f64, 0.0, 0.0, 0.0), 1e-6);
+    /// assert_eq!(scaled, Err(ValidationError::NotNormalized { norm: 2.0 }));
+    /// let unknown = Pose::try_new(Vector::new(0.0, f64::INFINITY, 0.0), Quaternion::identity(), 1e-6);
+    /// assert_eq!(unknown, Err(ValidationError::NonFinite { component: 1 }));
+    /// ```
+    pub fn try_new(
+        translation: Vector<T>,
+        rotation: Quaternion<T>,
+        tolerance: T,
+    ) -> Result<Self, ValidationError<T>> {
+        let components = [
+            translation.x(),
+            translation.y(),
+            translation.z(),
+            rotation.w(),
+            rotation.i(),
+            rotation.j(),
+            rotation.k(),
+        ];
+        if let Some(component) = components.into_iter().position(|value| !is_finite(value)) {
+            return Err(ValidationError::NonFinite { component });
+        }
+        let pose = Self::new(translation, rotation);
+        if pose.is_rigid(tolerance) {
+            Ok(pose)
+        } else {
+            let norm = Norm::norm(rotation);
+            Err(ValidationError::NotNormalized { norm })
+        }
+    }
+
+    /// Calculate residual of other pose relative to this one as `[x, y, z, rx, ry, rz]`,
+    /// translation of [`Pose::between`] followed by the rotation vector of its rotation, see
+    /// [`Quaternion::log`]. Unlike [`Pose::log`] translation and rotation are decoupled.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let from = Pose::new(Vector::new(1.0_f64, 0.0, 0.0), Quaternion::identity());
+    /// let to = Pose::new(
+    ///     Vector::new(1.0, 2.0, 0.0),
+    ///     Quaternion::from_angle_axis(0.5, Vector::unit_x()),
+    /// );
+    /// let delta = from.delta(to);
+    /// let expected = [0.0, 2.0, 0.0, 0.5, 0.0, 0.0];
+    /// assert!(delta.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-12));
+    /// ```
+    pub fn delta(self, other: Self) -> [T; 6] {
+        let relative = self.between(other);
+        let (translation, rotation) = (relative.translation, relative.rotation.log());
+        [
+            translation.x(),
+            translation.y(),
+            translation.z(),
+            rotation.x(),
+            rotation.y(),
+            rotation.z(),
+        ]
+    }
+
+    /// Mirror pose across the plane, e.g. to get the pose of the opposite arm of a left/right
+    /// symmetric robot. Both the parent and the child frame are reflected, so the result is a
+    /// proper rigid transform again: it maps reflection of any child point to reflection of
+    /// its image, with the child frame reflected across the plane through its origin parallel
+    /// to the given one.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::geometry::Plane;
+    /// let symmetry = Plane::new(Vector::unit_y(), 0.0_f64).unwrap();
+    /// let left = Pose::new(
+    ///     Vector::new(0.2, 0.3, 1.0),
+    ///     Quaternion::from_angle_axis(0.5, Vector::unit_z()),
+    /// );
+    /// let right = left.mirrored(&symmetry);
+    /// assert!((right.translation() - Vector::new(0.2, -0.3, 1.0)).norm() < 1e-12);
+    /// let expected = Quaternion::from_angle_axis(-0.5, Vector::unit_z());
+    /// assert!(right.rotation().angle_to(expected) < 1e-12);
+    /// ```
+    pub fn mirrored(self, plane: &Plane<T>) -> Self {
+        Self {
+            translation: plane.reflect(self.translation),
+            rotation: self.rotation.mirrored(plane.normal()),
+        }
+    }
+
+    /// Map six independent samples from the uniform `[0, 1]` distribution to pose with
+    /// translation uniformly distributed in the box between `lower` and `upper` corners and
+    /// uniformly distributed rotation, see [`Quaternion::from_uniform`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::from_uniform(Vector::new(-1.0_f64, 0.0, 2.0), Vector::new(1.0, 4.0, 2.0), [0.5, 0.25, 0.9, 0.1, 0.2, 0.3]);
+    /// assert!((pose.translation() - Vector::new(0.0, 1.0, 2.0)).norm() < 1e-12);
+    /// ```
+    pub fn from_uniform(lower: Vector<T>, upper: Vector<T>, samples: [T; 6]) -> Self {
+        let [x, y, z, first, second, third] = samples;
+        Self {
+            translation: lower + (upper - lower).hadamard(Vector::new(x, y, z)),
+            rotation: Quaternion::from_uniform([first, second, third]),
+        }
+    }
+
+    /// Average poses, see [`Pose::weighted_average`].
+    pub fn average<I: IntoIterator<Item = Self>>(poses: I) -> Option<Self> {
+        Self::weighted_average(poses.into_iter().map(|pose| (pose, T::one())))
+    }
+
+    /// Average poses with given non-negative weights, translations are averaged as points
+    /// and rotations by [`Quaternion::weighted_average`]. Returns `None` if there are no
+    /// poses, the total weight is below epsilon or the rotations cancel out.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let turn = |angle| Quaternion::from_angle_axis(angle, Vector::unit_z());
+    /// let observations = [
+    ///     (Pose::new(Vector::new(1.0_f64, 0.0, 0.0), turn(0.2)), 3.0),
+    ///     (Pose::new(Vector::new(2.0, 4.0, 0.0), turn(0.6)), 1.0),
+    /// ];
+    /// let mean = Pose::weighted_average(observations).unwrap();
+    /// assert!((mean.translation() - Vector::new(1.25, 1.0, 0.0)).norm() < 1e-12);
+    /// assert!(mean.rotation().angle_to(turn(0.3)) < 1e-3);
+    /// ```
+    pub fn weighted_average<I: IntoIterator<Item = (Self, T)>>(poses: I) -> Option<Self> {
+        let mut translation = Vector::zero();
+        let mut total = T::zero();
+        let rotation = Quaternion::weighted_average(poses.into_iter().map(|(pose, weight)| {
+            translation = translation + pose.translation * weight;
+            total = total + weight;
+            (pose.rotation, weight)
+        }))?;
+        if total < T::epsilon() {
+            return None;
+        }
+        Some(Self {
+            translation: translation / total,
+            rotation,
+        })
+    }
+
+    /// Logarithm map, returns body-frame twist which reaches this pose in unit time.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::new(
+    ///     Vector::new(1.0_f64, 2.0, 3.0),
+    ///     Quaternion::from_angle_axis(1.0, Vector::new(1.0, -1.0, 0.5)),
+    /// );
+    /// let result = Pose::exp(pose.log());
+    /// assert!((result.translation() - pose.translation()).norm() < 1e-9);
+    /// assert!((result.rotation().dot(pose.rotation()) - 1.0).abs() < 1e-9);
+    /// ```
+    pub fn log(self) -> Twist<T, T> {
+        let rotation = self.rotation.log();
+        let angle = rotation.norm();
+        let half = T::one() / (T::one() + T::one());
+        let squared = angle * angle;
+        let c = if squared * squared * squared * squared < T::epsilon() {
+            // Closed form below loses about `epsilon / angle^2` relative precision to
+            // cancellation, truncated series is exact to rounding in this range.
+            let two = T::one() + T::one();
+            let six = two * (two + T::one());
+            let twelve = six * two;
+            let seven_hundred_twenty = twelve * six * (two + two + T::one()) * two;
+            let thirty_thousand_two_hundred_forty = seven_hundred_twenty * six * (six + T::one());
+            T::one() / twelve
+                + squared / seven_hundred_twenty
+                + squared * squared / thirty_thousand_two_hundred_forty
+        } else {
+            let half_angle = angle * half;
+            (T::one() - half_angle * half_angle.cos() / half_angle.sin()) / squared
+        };
+        let first = rotation.cross(self.translation);
+        let second = rotation.cross(first);
+        let velocity = self.translation - first * half + second * c;
+        Twist::new(velocity, rotation)
+    }
+
+    /// Raise pose to given power, moving fraction `exponent` of the way along its screw motion,
+    /// `exp(exponent * log(self))`. `pose.powf(0.5)` applied twice equals the pose.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use core::f64::consts::PI;
+    /// let pose = Pose::new(Vector::new(0.0, 2.0, 4.0), Quaternion::from_angle_axis(PI / 2.0, Vector::unit_z()));
+    /// let half = pose.powf(0.5);
+    /// let (distance, angle) = (half * half).distance_to(pose);
+    /// assert!(distance < 1e-12 && angle < 1e-12);
+    /// assert!((half.translation().z() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn powf(self, exponent: T) -> Self {
+        Self::exp(self.log() * exponent)
+    }
+
+    /// Fold relative poses into absolute pose `poses[0] * poses[1] * ...`, identity for no
+    /// poses, see [`compose_chain`] for the renormalization of long chains.
+    pub fn accumulate<I: IntoIterator<Item = Self>>(poses: I) -> Self {
+        compose_chain(poses)
+    }
+
+    /// Produce running chain `poses[0]`, `poses[0] * poses[1]`, ..., e.g. link frames of
+    /// a serial arm from joint transforms, accumulated as in [`compose_chain`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use core::f64::consts::FRAC_PI_2;
+    /// let joint = Pose::new(
+    ///     Vector::new(1.0, 0.0, 0.0),
+    ///     Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z()),
+    /// );
+    /// let frames: Vec<_> = Pose::scan_accumulate([joint; 3]).collect();
+    /// assert!((frames[1].translation() - Vector::new(1.0, 1.0, 0.0)).norm() < 1e-12);
+    /// assert!((frames[2].translation() - Vector::new(0.0, 1.0, 0.0)).norm() < 1e-12);
+    /// ```
+    pub fn scan_accumulate<I: IntoIterator<Item = Self>>(poses: I) -> impl Iterator<Item = Self> {
+        let mut chain = Chain::new(CHAIN_INTERVAL, true);
+        poses.into_iter().map(move |pose| chain.push(pose))
+    }
+
+    /// Calculate `3x6` Jacobian of `self.apply_to(point)` with respect to body-frame twist
+    /// perturbation `self * exp(twist)` at zero, columns follow `[linear, angular]` twist
+    /// order. This is `[R, -R [point]x]`, see [`Quaternion::rotate_jacobian`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::<f64>::identity());
+    /// let jacobian = pose.jacobian_wrt_twist(Vector::new(0.0, 0.0, 1.0));
+    /// assert_eq!(jacobian[0], [1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+    /// ```
+    pub fn jacobian_wrt_twist(self, point: Vector<T>) -> [[T; 6]; 3] {
+        let rotation = self.rotation.into_rotation_matrix();
+        let angular = self.rotation.rotate_jacobian(point);
+        let mut jacobian = [[T::zero(); 6]; 3];
+        for (row, (linear, angular)) in jacobian.iter_mut().zip(rotation.iter().zip(angular)) {
+            row[..3].copy_from_slice(linear);
+            row[3..].copy_from_slice(&angular);
+        }
+        jacobian
+    }
+
+    /// Calculate `3x6` Jacobian of `self.apply_to(point)` with respect to parent-frame twist
+    /// perturbation `exp(twist) * self` at zero, which is `[I, -[self.apply_to(point)]x]`.
+    pub fn jacobian_wrt_twist_left(self, point: Vector<T>) -> [[T; 6]; 3] {
+        let skew = skew(self.apply_to(point));
+        let mut jacobian = [[T::zero(); 6]; 3];
+        for (index, (row, skew)) in jacobian.iter_mut().zip(skew).enumerate() {
+            row[index] = T::one();
+            for (value, skew) in row[3..].iter_mut().zip(skew) {
+                *value = -skew;
+            }
+        }
+        jacobian
+    }
+
+    /// Extrapolate pose moving with constant body-frame twist for `dt` time.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::twist::Twist;
+    /// let pose = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::identity());
+    /// let twist = Twist::new(Vector::new(0.0, 2.0, 0.0), Vector::zero());
+    /// let result = pose.extrapolate(twist, 0.5);
+    /// assert!((result.translation() - Vector::new(1.0, 1.0, 0.0)).norm() < 1e-9);
+    /// ```
+    pub fn extrapolate(self, twist: Twist<T, T>, dt: T) -> Self {
+        self * Self::exp(twist * dt)
+    }
+
+    /// Integrate body-frame twist over `dt` exactly assuming it is constant, same as
+    /// [`Pose::extrapolate`].
+    pub fn integrate(self, twist: Twist<T, T>, dt: T) -> Self {
+        self.extrapolate(twist, dt)
+    }
+
+    /// Integrate body-frame twist over `dt` by single explicit Euler step, translation moves
+    /// along the initial direction and rotation follows
+    /// [`Quaternion::integrate_first_order`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::twist::Twist;
+    /// let pose = Pose::new(Vector::new(1.0_f64, 0.0, 0.0), Quaternion::from_angle_axis(1.0, Vector::unit_z()));
+    /// let twist = Twist::new(Vector::new(2.0, 0.0, 0.0), Vector::new(0.0, 0.0, 0.1));
+    /// let (exact, approximate) = (pose.integrate(twist, 1e-3), pose.integrate_first_order(twist, 1e-3));
+    /// assert!((exact.translation() - approximate.translation()).norm() < 1e-6);
+    /// assert!((exact.rotation().dot(approximate.rotation()) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn integrate_first_order(self, twist: Twist<T, T>, dt: T) -> Self {
+        Self {
+            translation: self.translation + self.rotation.rotate(twist.linear() * dt),
+            rotation: self.rotation.integrate_first_order(twist.angular(), dt),
+        }
+    }
+
+    /// Convert pose into row-major homogeneous transform matrix, `matrix[row][column]`
+    /// with translation in the last column.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0));
+    /// let matrix = pose.into_homogeneous();
+    /// assert_eq!(matrix[0], [-1.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(matrix[3], [0.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(pose.into_homogeneous_column_major()[3], [1.0, 2.0, 3.0, 1.0]);
+    /// ```
+    pub fn into_homogeneous(self) -> [[T; 4]; 4] {
+        let rotation = self.rotation.into_rotation_matrix();
+        let translation = <[T; 3]>::from(self.translation);
+        let row = |index: usize| {
+            let [x, y, z] = rotation[index];
+            [x, y, z, translation[index]]
+        };
+        [
+            row(0),
+            row(1),
+            row(2),
+            [T::zero(), T::zero(), T::zero(), T::one()],
+        ]
+    }
+
+    /// Convert pose into column-major homogeneous transform matrix, `matrix[column][row]`,
+    /// as expected by OpenGL and wgpu.
+    pub fn into_homogeneous_column_major(self) -> [[T; 4]; 4] {
+        let matrix = self.into_homogeneous();
+        [0, 1, 2, 3].map(|column| matrix.map(|row| row[column]))
+    }
+
+    /// Create pose from row-major homogeneous transform matrix, see
+    /// [`Pose::into_homogeneous`]. The bottom row is ignored and the rotation part is
+    /// replaced with the closest orthonormal matrix, which removes small numerical drift
+    /// accumulated by matrix products or rounding of stored values.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::pose::Pose;
+    /// let drifted = [
+    ///     [1.0 + 1e-6, -2e-6, 0.0, 4.0],
+    ///     [1e-6, 1.0, 0.0, 5.0],
+    ///     [0.0, 0.0, 1.0 - 1e-6, 6.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ];
+    /// let pose = Pose::from_homogeneous(drifted);
+    /// assert_eq!(pose.translation(), Vector::new(4.0, 5.0, 6.0));
+    /// assert!((pose.rotation().w() - 1.0_f64).abs() < 1e-12);
+    /// ```
+    pub fn from_homogeneous(matrix: [[T; 4]; 4]) -> Self {
+        let rotation = [0, 1, 2].map(|row| [matrix[row][0], matrix[row][1], matrix[row][2]]);
+        Self {
+            translation: Vector::new(matrix[0][3], matrix[1][3], matrix[2][3]),
+            rotation: Quaternion::from_rotation_matrix(closest_rotation(rotation)),
+        }
+    }
+
+    /// Create pose from column-major homogeneous transform matrix, see
+    /// [`Pose::from_homogeneous`].
+    pub fn from_homogeneous_column_major(matrix: [[T; 4]; 4]) -> Self {
+        Self::from_homogeneous([0, 1, 2, 3].map(|row| matrix.map(|column| column[row])))
+    }
+
+    /// Create pose of the frame placed at `origin` with axes directions given in the parent
+    /// frame, see [`Quaternion::from_axes`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::pose::Pose;
+    /// let origin = Vector::new(1.0_f64, 2.0, 3.0);
+    /// let pose = Pose::from_axes(origin, Vector::new(0.0, 0.0, 1.0), Vector::new(1.0, 0.0, 0.1)).unwrap();
+    /// assert!((pose.apply_to(Vector::new(2.0, 1.0, 0.0)) - Vector::new(2.0, 2.0, 5.0)).norm() < 1e-12);
+    /// ```
+    pub fn from_axes(origin: Vector<T>, x_axis: Vector<T>, y_axis: Vector<T>) -> Option<Self> {
+        Some(Self {
+            translation: origin,
+            rotation: Quaternion::from_axes(x_axis, y_axis)?,
+        })
+    }
+
+    /// Calculate adjoint matrix mapping twists of the pose child frame into its parent
+    /// frame, twists are stacked as `[linear, angular]`, so multiplying by the matrix equals
+    /// [`Pose::transform_twist`]. Transposed adjoint of the inverse pose maps wrenches
+    /// stacked as `[force, torque]` like [`Pose::transform_wrench`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::twist::Twist;
+    /// let pose = Pose::new(
+    ///     Vector::new(1.0_f64, -2.0, 0.5),
+    ///     Quaternion::from_angle_axis(0.7, Vector::new(1.0, 2.0, -1.0)),
+    /// );
+    /// let twist = [0.3, -0.1, 0.2, 1.0, 0.5, -2.0];
+    /// let mapped = pose.adjoint().map(|row| row.iter().zip(twist).map(|(a, b)| a * b).sum::<f64>());
+    /// let expected = pose.transform_twist(Twist::new(
+    ///     Vector::new(twist[0], twist[1], twist[2]),
+    ///     Vector::new(twist[3], twist[4], twist[5]),
+    /// ));
+    /// assert!((Vector::new(mapped[0], mapped[1], mapped[2]) - expected.linear()).norm() < 1e-12);
+    /// assert!((Vector::new(mapped[3], mapped[4], mapped[5]) - expected.angular()).norm() < 1e-12);
+    /// ```
+    pub fn adjoint(self) -> [[T; 6]; 6] {
+        let rotation = self.rotation.into_rotation_matrix();
+        let t = self.translation;
+        let skew = [
+            [T::zero(), -t.z(), t.y()],
+            [t.z(), T::zero(), -t.x()],
+            [-t.y(), t.x(), T::zero()],
+        ];
+        let mut result = [[T::zero(); 6]; 6];
+        for row in 0..3 {
+            for column in 0..3 {
+                result[row][column] = rotation[row][column];
+                result[row + 3][column + 3] = rotation[row][column];
+                let mut sum = T::zero();
+                for k in 0..3 {
+                    sum = sum + skew[row][k] * rotation[k][column];
+                }
+                result[row][column + 3] = sum;
+            }
+        }
+        result
+    }
+
+    /// Interpolate between this and other pose along the screw motion with constant
+    /// body-frame twist, unlike [`Pose::interpolate`] the translation follows the helix
+    /// around the screw axis. `progress` is either plain value or
+    /// [`Progress`](crate::ops::Progress).
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use core::f64::consts::PI;
+    /// let start = Pose::new(Vector::new(1.0, 0.0, 0.0), Quaternion::identity());
+    /// let end = Pose::new(
+    ///     Vector::new(-1.0, 0.0, 2.0),
+    ///     Quaternion::from_angle_axis(PI, Vector::unit_z()),
+    /// );
+    /// let middle = start.interpolate_screw(end, 0.5);
+    /// assert!((middle.translation() - Vector::new(0.0, 1.0, 1.0)).norm() < 1e-12);
+    /// assert!((start.interpolate(end, 0.5).translation() - Vector::new(0.0, 0.0, 1.0)).norm() < 1e-12);
+    /// ```
+    pub fn interpolate_screw<P: IntoProgress<T>>(self, other: Self, progress: P) -> Self {
+        self * Self::exp((self.inverse() * other).log() * progress.into_progress())
+    }
+
+    /// Decompose pose into screw motion by Chasles' theorem: rotation by angle in the
+    /// `[0, pi]` range around the axis followed by translation along it. Returns the axis
+    /// through its point closest to the parent origin, the angle and the signed translation.
+    /// Pure translations produce axis through the origin along the translation with zero
+    /// angle, `None` for identity.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// # use spatial::geometry::Line;
+    /// # use core::f64::consts::FRAC_PI_2;
+    /// let axis = Line::new(Vector::new(1.0, 2.0, 0.0), Vector::unit_z()).unwrap();
+    /// let pose = Pose::from_screw(axis, FRAC_PI_2, 0.5);
+    /// assert!((pose.apply_to(Vector::new(1.0, 3.0, 0.0)) - Vector::new(0.0, 2.0, 0.5)).norm() < 1e-12);
+    /// let (restored, angle, translation) = pose.into_screw().unwrap();
+    /// assert!((restored.point() - axis.point()).norm() < 1e-12);
+    /// assert!((restored.direction() - axis.direction()).norm() < 1e-12);
+    /// assert!((angle - FRAC_PI_2).abs() < 1e-12 && (translation - 0.5).abs() < 1e-12);
+    /// ```
+    pub fn into_screw(self) -> Option<(Line<T>, T, T)> {
+        let rotation = if self.rotation.w() < T::zero() {
+            -self.rotation
+        } else {
+            self.rotation
+        };
+        let vector = Vector::new(rotation.i(), rotation.j(), rotation.k());
+        let squared = vector.dot(vector);
+        if squared < T::epsilon() * T::epsilon() {
+            let axis = Line::new(Vector::zero(), self.translation)?;
+            return Some((axis, T::zero(), self.translation.norm()));
+        }
+        let sin = squared.sqrt();
+        let direction = vector / sin;
+        let along = direction.dot(self.translation);
+        let across = self.translation - direction * along;
+        let two = T::one() + T::one();
+        // Point `p` solving `(I - R) p = across`, `cot(angle / 2) = w / |v|`.
+        let point = (across + vector.cross(self.translation) * (rotation.w() / squared)) / two;
+        let axis = Line::new(point, direction)?;
+        Some((axis, two * sin.atan2(rotation.w()), along))
+    }
+
+    /// Create pose of screw motion rotating by `angle` around the axis and translating by
+    /// `translation` along it, see [`Pose::into_screw`].
+    pub fn from_screw(axis: Line<T>, angle: T, translation: T) -> Self {
+        let rotation = Quaternion::from_angle_axis(angle, axis.direction());
+        let point = axis.point();
+        Self {
+            translation: point - rotation.rotate(point) + axis.direction() * translation,
+            rotation,
+        }
+    }
+}
+
+impl<T, R> Pose<T, R>
+where
+    T: Copy,
+    R: Copy
+        + PartialOrd
+        + Add<Output = R>
+        + Mul<Output = R>
+        + Div<Output = R>
+        + Sqrt<Output = R>
+        + Epsilon,
+{
+    /// Scale rotation of this pose back to unit norm to correct drift accumulated by
+    /// composing many poses, rotations with norm below epsilon are kept unchanged.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let drifted = Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::with_wijk(0.0, 1.002, 0.0, 0.0));
+    /// let pose = drifted.renormalized();
+    /// assert_eq!(pose.rotation(), Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0));
+    /// assert_eq!(pose.translation(), drifted.translation());
+    /// ```
+    pub fn renormalized(self) -> Self {
+        Self {
+            translation: self.translation,
+            rotation: self.rotation.normalized_checked().unwrap_or(self.rotation),
+        }
+    }
+}
+
+/// Number of polar decomposition iterations in [`closest_rotation`], convergence is
+/// quadratic so drift of `1e-3` is removed to `f64` precision in three of them.
+#[cfg(feature = "num")]
+const POLAR_ITERATIONS: usize = 4;
+
+/// Find orthonormal matrix closest to the nearly orthonormal one by Newton iterations
+/// `R = (R + R^-T) / 2` of the polar decomposition.
+#[cfg(feature = "num")]
+fn closest_rotation<T: Real>(matrix: [[T; 3]; 3]) -> [[T; 3]; 3] {
+    let half = T::one() / (T::one() + T::one());
+    let mut rotation = matrix;
+    for _ in 0..POLAR_ITERATIONS {
+        let r = rotation;
+        let cofactor = |row: usize, column: usize| {
+            let (a, b) = ((row + 1) % 3, (row + 2) % 3);
+            let (c, d) = ((column + 1) % 3, (column + 2) % 3);
+            r[a][c] * r[b][d] - r[a][d] * r[b][c]
+        };
+        let determinant =
+            r[0][0] * cofactor(0, 0) + r[0][1] * cofactor(0, 1) + r[0][2] * cofactor(0, 2);
+        if determinant == T::zero() {
+            break;
+        }
+        for (row, values) in rotation.iter_mut().enumerate() {
+            for (column, value) in values.iter_mut().enumerate() {
+                *value = (r[row][column] + cofactor(row, column) / determinant) * half;
+            }
+        }
+    }
+    rotation
+}
+
+/// Default number of compositions between renormalizations in [`compose_chain`].
+#[cfg(feature = "num")]
+pub const CHAIN_INTERVAL: u32 = 16;
+
+/// Compose long chain of poses, e.g. kinematic links or trajectory segments, as
+/// `poses[0] * poses[1] * ...`, see [`compose_chain_with`] for the error bounds.
+/// Renormalizes rotation every [`CHAIN_INTERVAL`] poses and compensates translation sum.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::{compose_chain, Pose};
+/// let link = Pose::new(
+///     Vector::new(0.1_f32, 0.0, 0.0),
+///     Quaternion::from_angle_axis(core::f32::consts::PI / 500.0, Vector::unit_z()),
+/// );
+/// let pose = compose_chain([link; 1000]);
+/// assert!(pose.translation().norm() < 1e-4);
+/// assert!((pose.rotation().dot(pose.rotation()) - 1.0).abs() < 1e-6);
+/// ```
+#[cfg(feature = "num")]
+pub fn compose_chain<T, I>(poses: I) -> Pose<T, T>
+where
+    T: Real,
+    I: IntoIterator<Item = Pose<T, T>>,
+{
+    compose_chain_with(poses, CHAIN_INTERVAL, true)
+}
+
+/// Compose long chain of poses renormalizing rotation every `interval` poses, optionally
+/// accumulating translation with Kahan compensated summation.
+///
+/// Norm of the composed rotation deviates from one by at most about `interval` machine
+/// epsilons. Plain summation of `n` rotated link translations has error growing as
+/// `n` machine epsilons times the sum of link lengths, compensated summation keeps it at
+/// a few machine epsilons independently of `n`, leaving rotation error as the main source.
+#[cfg(feature = "num")]
+pub fn compose_chain_with<T, I>(poses: I, interval: u32, compensated: bool) -> Pose<T, T>
+where
+    T: Real,
+    I: IntoIterator<Item = Pose<T, T>>,
+{
+    let mut chain = Chain::new(interval, compensated);
+    poses
+        .into_iter()
+        .fold(Pose::identity(), |_, pose| chain.push(pose))
+}
+
+/// Running state of [`compose_chain_with`].
+#[cfg(feature = "num")]
+struct Chain<T> {
+    rotation: UnitQuaternion<T>,
+    interval: u32,
+    operations: u32,
+    translation: Vector<T>,
+    compensation: Option<Vector<T>>,
+}
+
+#[cfg(feature = "num")]
+impl<T: Real> Chain<T> {
+    fn new(interval: u32, compensated: bool) -> Self {
+        Self {
+            rotation: UnitQuaternion::identity(),
+            interval,
+            operations: 0,
+            translation: Vector::zero(),
+            compensation: compensated.then(Vector::zero),
+        }
+    }
+
+    /// Append pose to the chain and get the composition so far.
+    fn push(&mut self, pose: Pose<T, T>) -> Pose<T, T> {
+        let term = self.rotation.rotate(pose.translation());
+        match &mut self.compensation {
+            Some(compensation) => {
+                let term = term - *compensation;
+                let sum = self.translation + term;
+                *compensation = (sum - self.translation) - term;
+                self.translation = sum;
+            }
+            None => self.translation = self.translation + term,
+        }
+        self.rotation = self.rotation * UnitQuaternion::new_unchecked(pose.rotation());
+        self.operations += 1;
+        if self.operations >= self.interval {
+            self.rotation = self.rotation.renormalize();
+            self.operations = 0;
+        }
+        Pose::new(self.translation, self.rotation.quaternion())
+    }
+}
+
+/// Number of `f32` values per pose in the flat buffer layout of [`pack_f32`].
+pub const PACKED_LEN: usize = 7;
+
+/// Pack poses into flat `f32` buffer, e.g. for GPU upload or shared memory exchange.
+///
+/// Every pose occupies [`PACKED_LEN`] consecutive values: translation `x`, `y`, `z` followed
+/// by rotation `w`, `i`, `j`, `k`, same as `[T; 7]` conversion. Buffer may be longer than
+/// needed, returns number of values written.
+/// ```
+/// # use spatial::error::ConversionError;
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::{pack_f32, unpack_f32, Pose};
+/// let poses = [
+///     Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0)),
+///     Pose::new(Vector::new(-1.0, 0.5, 0.0), Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0)),
+/// ];
+/// let mut buffer = [0.0; 16];
+/// assert_eq!(pack_f32(&poses, &mut buffer), Ok(14));
+/// assert_eq!(buffer[7..10], [-1.0, 0.5, 0.0]);
+/// let mut unpacked = [Pose::<f64, f64>::IDENTITY; 2];
+/// assert_eq!(unpack_f32(&buffer[..14], &mut unpacked), Ok(2));
+/// assert_eq!(unpacked, poses);
+/// let error = pack_f32(&poses, &mut buffer[..10]).unwrap_err();
+/// assert_eq!(error, ConversionError::TooShort { expected: 14, actual: 10 });
+/// ```
+pub fn pack_f32(poses: &[Pose<f64, f64>], out: &mut [f32]) -> Result<usize, ConversionError> {
+    let (expected, actual) = (poses.len() * PACKED_LEN, out.len());
+    if actual < expected {
+        return Err(ConversionError::TooShort { expected, actual });
+    }
+    for (pose, chunk) in poses.iter().zip(out.chunks_exact_mut(PACKED_LEN)) {
+        for (value, packed) in chunk.iter_mut().zip(pose.as_flat()) {
+            *value = *packed as f32;
+        }
+    }
+    Ok(expected)
+}
+
+/// Unpack poses from flat `f32` buffer written by [`pack_f32`], returns number of poses read.
+///
+/// Buffer length must be a multiple of [`PACKED_LEN`], and `out` must have room for all poses,
+/// otherwise error with the number of buffer values required or accepted is returned.
+/// ```
+/// # use spatial::error::ConversionError;
+/// # use spatial::pose::{unpack_f32, Pose};
+/// let buffer = [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+/// let mut poses = [Pose::<f64, f64>::IDENTITY; 2];
+/// let error = unpack_f32(&buffer, &mut poses).unwrap_err();
+/// assert_eq!(error, ConversionError::TooShort { expected: 14, actual: 8 });
+/// let error = unpack_f32(&buffer[..7], &mut poses[..0]).unwrap_err();
+/// assert_eq!(error, ConversionError::TooLong { expected: 0, actual: 7 });
+/// ```
+pub fn unpack_f32(data: &[f32], out: &mut [Pose<f64, f64>]) -> Result<usize, ConversionError> {
+    let actual = data.len();
+    if !actual.is_multiple_of(PACKED_LEN) {
+        let expected = actual.next_multiple_of(PACKED_LEN);
+        return Err(ConversionError::TooShort { expected, actual });
+    }
+    let count = actual / PACKED_LEN;
+    if count > out.len() {
+        let expected = out.len() * PACKED_LEN;
+        return Err(ConversionError::TooLong { expected, actual });
+    }
+    for (pose, chunk) in out.iter_mut().zip(data.chunks_exact(PACKED_LEN)) {
+        for (value, packed) in pose.as_mut_flat().iter_mut().zip(chunk) {
+            *value = f64::from(*packed);
+        }
+    }
+    Ok(count)
+}
+
+impl<T, R> Pose<T, R> {
+    /// Combine poses borrowing both of them, for scalar types which are not `Copy`,
+    /// see [`Pose::combine`].
+    pub fn combine_ref(&self, other: &Self) -> Self
+    where
+        for<'a> &'a R: Mul<&'a R, Output = R> + Mul<&'a T, Output = T>,
+        T: Clone + Add<Output = T> + Sub<Output = T>,
+        R: Add<Output = R> + Sub<Output = R>,
+    {
+        Self {
+            translation: self.apply_to_ref(&other.translation),
+            rotation: self.rotation.multiply_ref(&other.rotation),
+        }
+    }
+
+    /// Apply transform borrowing the vector, for scalar types which are not `Copy`,
+    /// see [`Pose::apply_to`].
+    pub fn apply_to_ref(&self, other: &Vector<T>) -> Vector<T>
+    where
+        for<'a> &'a R: Mul<&'a R, Output = R> + Mul<&'a T, Output = T>,
+        T: Clone + Add<Output = T> + Sub<Output = T>,
+        R: Add<Output = R>,
+    {
+        self.translation.clone() + self.rotation.rotate_ref(other)
+    }
+}
+
+#[cfg(feature = "simd")]
+impl Pose<f32, f32> {
+    /// Calculate consecutive application of this and other poses using `f32x4` lanes,
+    /// see the `simd` feature. Rotation must be unit quaternion.
+    pub fn combine_simd(self, other: Self) -> Self {
+        Self {
+            translation: self.translation + self.rotation.rotate_simd(other.translation),
+            rotation: self.rotation.multiply_simd(other.rotation),
+        }
+    }
+
+    /// Apply transform stored in this pose to vector using `f32x4` lanes, see the `simd`
+    /// feature. Rotation must be unit quaternion.
+    pub fn apply_to_simd(self, vector: Vector<f32>) -> Vector<f32> {
+        crate::simd::apply(self.translation, self.rotation, vector)
+    }
+
+    /// Apply transform stored in this pose to every vector of the slice in place using
+    /// `f32x4` lanes, see the `simd` feature. Rotation must be unit quaternion.
+    pub fn apply_to_slice_simd(self, vectors: &mut [Vector<f32>]) {
+        crate::simd::transform(self.translation, self.rotation, vectors);
+    }
 }
 
 /// Calculate result of pose combination operation.
@@ -180,6 +1364,17 @@ where
     }
 }
 
+/// Apply other pose after this one, in its local frame.
+impl<T, R> MulAssign for Pose<T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.combine(other);
+    }
+}
+
 /// Apply transform stored in this pose to given vector.
 impl<T, R> Mul<Vector<T>> for Pose<T, R>
 where
@@ -191,3 +1386,417 @@ where
         self.apply_to(other)
     }
 }
+
+impl<T> Pose<T, T> {
+    /// Apply function to every translation and rotation component.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::new(Vector::new(1.0_f64, 2.0, 3.0), Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0));
+    /// let rounded = pose.map(|value| value as i32);
+    /// assert_eq!(rounded, Pose::new(Vector::new(1, 2, 3), Quaternion::with_wijk(1, 0, 0, 0)));
+    /// ```
+    pub fn map<F: Fn(T) -> U, U>(self, mapper: F) -> Pose<U, U> {
+        Pose {
+            translation: self.translation.map(&mapper),
+            rotation: self.rotation.map(mapper),
+        }
+    }
+
+    /// Create pose from array of translation `x`, `y`, `z` followed by rotation `w`, `i`, `j`,
+    /// `k`, the layout of [`Pose::as_flat`].
+    /// ```
+    /// # use spatial::pose::Pose;
+    /// let pose = Pose::from_array([1.0, 2.0, 3.0, 1.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(pose.to_array(), [1.0, 2.0, 3.0, 1.0, 0.0, 0.0, 0.0]);
+    /// ```
+    pub fn from_array([x, y, z, w, i, j, k]: [T; 7]) -> Self {
+        Self::new(Vector::new(x, y, z), Quaternion::with_wijk(w, i, j, k))
+    }
+
+    /// Convert pose into array of translation `x`, `y`, `z` followed by rotation `w`, `i`,
+    /// `j`, `k`, see [`Pose::from_array`].
+    pub fn to_array(self) -> [T; 7] {
+        self.into()
+    }
+
+    /// View translation `x`, `y`, `z` followed by rotation `w`, `i`, `j`, `k` as array without copying.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let mut pose = Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0));
+    /// pose.as_mut_flat()[0] = 4.0;
+    /// assert_eq!(pose.as_flat(), &[4.0, 2.0, 3.0, 1.0, 0.0, 0.0, 0.0]);
+    /// ```
+    pub fn as_flat(&self) -> &[T; 7] {
+        // SAFETY: `Pose` is `repr(C)` with `[T; 3]`-like translation followed by `[T; 4]`-like
+        // rotation, both aligned as `T`, so there is no padding and the layout is that of `[T; 7]`.
+        unsafe { &*(self as *const Self).cast::<[T; 7]>() }
+    }
+
+    /// View translation `x`, `y`, `z` followed by rotation `w`, `i`, `j`, `k` as mutable array
+    /// without copying.
+    pub fn as_mut_flat(&mut self) -> &mut [T; 7] {
+        // SAFETY: see `as_flat`.
+        unsafe { &mut *(self as *mut Self).cast::<[T; 7]>() }
+    }
+
+    /// View slice of poses as flat slice of consecutive [`Pose::as_flat`] arrays, same layout
+    /// as [`pack_f32`] writes but without conversion and copying.
+    pub fn flatten_slice(poses: &[Self]) -> &[T] {
+        // SAFETY: every pose has the layout of `[T; 7]` and arrays have no padding between
+        // elements, so `n` consecutive poses have the layout of `[T; 7 * n]`.
+        unsafe { core::slice::from_raw_parts(poses.as_ptr().cast::<T>(), poses.len() * 7) }
+    }
+
+    /// View slice of poses as flat mutable slice without copying, see
+    /// [`Pose::flatten_slice`].
+    pub fn flatten_slice_mut(poses: &mut [Self]) -> &mut [T] {
+        // SAFETY: see `flatten_slice`.
+        unsafe { core::slice::from_raw_parts_mut(poses.as_mut_ptr().cast::<T>(), poses.len() * 7) }
+    }
+
+    /// View flat slice of consecutive [`Pose::as_flat`] arrays as slice of poses without
+    /// copying, e.g. to compose poses stored in a serialization buffer in place. Slice length
+    /// must be a multiple of seven, otherwise error with the next multiple is returned.
+    /// ```
+    /// # use spatial::error::ConversionError;
+    /// # use spatial::pose::Pose;
+    /// let mut buffer = [0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0];
+    /// let poses = Pose::from_flat_slice_mut(&mut buffer).unwrap();
+    /// poses[0] *= poses[1];
+    /// assert_eq!(buffer[..7], [2.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    /// let error = Pose::from_flat_slice(&buffer[..8]).unwrap_err();
+    /// assert_eq!(error, ConversionError::TooShort { expected: 14, actual: 8 });
+    /// ```
+    pub fn from_flat_slice(values: &[T]) -> Result<&[Self], ConversionError> {
+        let count = flat_count(values.len())?;
+        // SAFETY: the slice holds `count` consecutive `[T; 7]` arrays, which is the layout of
+        // `count` consecutive poses, and `Pose` is aligned as `T`.
+        Ok(unsafe { core::slice::from_raw_parts(values.as_ptr().cast::<Self>(), count) })
+    }
+
+    /// View flat mutable slice of consecutive [`Pose::as_flat`] arrays as mutable slice of
+    /// poses without copying, see [`Pose::from_flat_slice`].
+    pub fn from_flat_slice_mut(values: &mut [T]) -> Result<&mut [Self], ConversionError> {
+        let count = flat_count(values.len())?;
+        // SAFETY: see `from_flat_slice`.
+        Ok(unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr().cast::<Self>(), count) })
+    }
+}
+
+/// Count poses stored in flat slice of given length, see [`Pose::from_flat_slice`].
+fn flat_count(actual: usize) -> Result<usize, ConversionError> {
+    if actual.is_multiple_of(7) {
+        Ok(actual / 7)
+    } else {
+        let expected = actual.next_multiple_of(7);
+        Err(ConversionError::TooShort { expected, actual })
+    }
+}
+
+/// Format as `pos [x, y, z] rot (w; i, j, k)`, formatter flags such as precision apply to
+/// every component.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// let pose = Pose::new(Vector::new(1.0, 2.0, 3.0), Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0));
+/// assert_eq!(format!("{pose:.1}"), "pos [1.0, 2.0, 3.0] rot (1.0; 0.0, 0.0, 0.0)");
+/// ```
+impl<T: Display, R: Display> Display for Pose<T, R> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("pos ")?;
+        self.translation.fmt(formatter)?;
+        formatter.write_str(" rot ")?;
+        self.rotation.fmt(formatter)
+    }
+}
+
+/// Convert array of translation `x`, `y`, `z` followed by rotation `w`, `i`, `j`, `k`.
+impl<T> From<[T; 7]> for Pose<T, T> {
+    fn from(array: [T; 7]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl<T> From<Pose<T, T>> for [T; 7] {
+    fn from(pose: Pose<T, T>) -> Self {
+        let [x, y, z] = <[T; 3]>::from(pose.translation);
+        let [w, i, j, k] = <[T; 4]>::from(pose.rotation);
+        [x, y, z, w, i, j, k]
+    }
+}
+
+/// Convert slice of translation `x`, `y`, `z` followed by rotation `w`, `i`, `j`, `k`.
+/// ```
+/// # use spatial::error::ConversionError;
+/// # use spatial::pose::Pose;
+/// let packet = [1.0, 2.0, 3.0, 1.0, 0.0, 0.0, 0.0];
+/// let pose = Pose::try_from(&packet[..]).unwrap();
+/// assert_eq!(<[f64; 7]>::from(pose), packet);
+/// let error = Pose::<f64, f64>::try_from(&packet[..6]).unwrap_err();
+/// assert_eq!(error, ConversionError::TooShort { expected: 7, actual: 6 });
+/// ```
+impl<T: Copy> TryFrom<&[T]> for Pose<T, T> {
+    type Error = ConversionError;
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        to_array::<T, 7>(slice).map(Self::from)
+    }
+}
+
+/// Pose with general rotation and skewed translation shared by tests of several modules.
+#[cfg(all(test, feature = "num"))]
+pub(crate) fn test_pose() -> Pose<f64, f64> {
+    Pose::new(
+        Vector::new(0.3, -1.0, 2.0),
+        Quaternion::<f64>::from_rpy(0.4, -0.2, 1.1),
+    )
+}
+
+#[cfg(all(test, feature = "num"))]
+mod test {
+    use super::{test_pose, Pose};
+    use crate::twist::Twist;
+    use crate::vector::Vector;
+
+    #[test]
+    fn log_is_precise_for_small_rotations() {
+        let axis = Vector::new(2.0_f64, -1.0, 2.0) / 3.0;
+        let velocity = Vector::new(0.5, 1.5, -1.0);
+        for angle in [2e-8, 1e-6, 1e-4, 3e-3, 1e-2, 0.1] {
+            let twist = Twist::new(velocity, axis * angle);
+            let restored = Pose::exp(twist).log();
+            assert!((restored.linear() - velocity).norm() < 1e-13);
+            assert!((restored.angular() - twist.angular()).norm() < 1e-15 * angle.max(1.0));
+        }
+    }
+
+    #[test]
+    fn screw_decomposition_round_trips_and_matches_screw_interpolation() {
+        use crate::quaternion::Quaternion;
+        let pose = Pose::new(
+            Vector::new(0.7_f64, -1.2, 2.5),
+            -Quaternion::from_angle_axis(2.9, Vector::new(1.0, -0.4, 0.3)),
+        );
+        let (axis, angle, translation) = pose.into_screw().unwrap();
+        assert!(axis.point().dot(axis.direction()).abs() < 1e-12);
+        let restored = Pose::from_screw(axis, angle, translation);
+        let (distance, rotation) = restored.distance_to(pose);
+        assert!(distance < 1e-12 && rotation < 1e-12);
+        for progress in [0.25, 0.5, 0.8] {
+            let expected = Pose::identity().interpolate_screw(pose, progress);
+            let partial = Pose::from_screw(axis, angle * progress, translation * progress);
+            let (distance, rotation) = partial.distance_to(expected);
+            assert!(distance < 1e-12 && rotation < 1e-12);
+        }
+        let shift = Pose::new(Vector::new(0.0, -3.0, 4.0), Quaternion::identity());
+        let (axis, angle, translation) = shift.into_screw().unwrap();
+        assert_eq!((angle, translation), (0.0, 5.0));
+        assert_eq!(Pose::from_screw(axis, angle, translation), shift);
+        assert!(Pose::<f64, f64>::identity().into_screw().is_none());
+    }
+
+    #[test]
+    fn between_and_delta_invert_composition() {
+        use crate::quaternion::Quaternion;
+        let from = test_pose();
+        let step = Pose::new(
+            Vector::new(0.5, 0.2, -0.1),
+            Quaternion::from_angle_axis(0.3, Vector::new(1.0, 2.0, -1.0)),
+        );
+        let to = from * step;
+        let (distance, rotation) = from.between(to).distance_to(step);
+        assert!(distance < 1e-12 && rotation < 1e-12);
+        let (distance, rotation) = (from * from.between(to)).distance_to(to);
+        assert!(distance < 1e-12 && rotation < 1e-12);
+        assert!(from.delta(from).iter().all(|value| value.abs() < 1e-12));
+        let delta = from.delta(to);
+        let axis = step.rotation().log();
+        let expected = [step.translation(), axis];
+        for (index, value) in delta.into_iter().enumerate() {
+            let vector = expected[index / 3];
+            let component = [vector.x(), vector.y(), vector.z()][index % 3];
+            assert!((value - component).abs() < 1e-12);
+        }
+        let back = to.delta(from);
+        for (index, value) in back.into_iter().enumerate().skip(3) {
+            assert!((value + delta[index]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn fractional_powers_follow_screw_motion() {
+        use crate::quaternion::Quaternion;
+        let pose = Pose::new(
+            Vector::new(0.7_f64, -1.2, 2.5),
+            -Quaternion::from_angle_axis(2.9, Vector::new(1.0, -0.4, 0.3)),
+        );
+        let identity = Pose::identity();
+        for exponent in [0.0, 0.25, 0.6, 1.0, 1.5] {
+            let (distance, angle) = pose
+                .powf(exponent)
+                .distance_to(identity.interpolate_screw(pose, exponent));
+            assert!(distance < 1e-12 && angle < 1e-12);
+            let rotation = pose.rotation().powf(exponent);
+            let expected = Quaternion::identity().slerp(pose.rotation(), exponent);
+            assert!(rotation.angle_to(expected) < 1e-12);
+        }
+        let (distance, angle) = (pose.powf(-1.0) * pose).distance_to(identity);
+        assert!(distance < 1e-12 && angle < 1e-12);
+        let tiny = pose.rotation().powf(1e-9);
+        assert!((tiny.angle_to(Quaternion::identity()) - 2.9e-9).abs() < 1e-20);
+    }
+
+    #[test]
+    fn point_jacobians_match_finite_differences() {
+        let pose = test_pose();
+        let point = Vector::new(0.5, 1.5, -0.7);
+        let step = 1e-6;
+        let right = pose.jacobian_wrt_twist(point);
+        let left = pose.jacobian_wrt_twist_left(point);
+        for column in 0..6 {
+            let mut coordinates = [0.0; 6];
+            coordinates[column] = step;
+            let twist = Twist::new(
+                Vector::new(coordinates[0], coordinates[1], coordinates[2]),
+                Vector::new(coordinates[3], coordinates[4], coordinates[5]),
+            );
+            let derivative = |perturbed: Pose<f64, f64>, negated: Pose<f64, f64>| {
+                (perturbed.apply_to(point) - negated.apply_to(point)) / (2.0 * step)
+            };
+            let numeric = [
+                derivative(pose * Pose::exp(twist), pose * Pose::exp(-twist)),
+                derivative(Pose::exp(twist) * pose, Pose::exp(-twist) * pose),
+            ];
+            for (analytic, numeric) in [right, left].iter().zip(numeric) {
+                let numeric = [numeric.x(), numeric.y(), numeric.z()];
+                for (row, value) in analytic.iter().zip(numeric) {
+                    assert!((row[column] - value).abs() < 1e-8);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scan_accumulate_matches_running_products() {
+        use crate::quaternion::Quaternion;
+        let links = [0.3, -0.5, 1.1, 0.2, 2.4].map(|angle: f64| {
+            Pose::new(
+                Vector::new(0.0, 0.1, 1.0),
+                Quaternion::from_angle_axis(angle, Vector::new(1.0, -1.0, 0.5)),
+            )
+        });
+        let mut expected = Pose::identity();
+        let mut count = 0;
+        for (frame, link) in Pose::scan_accumulate(links).zip(links) {
+            expected *= link;
+            let (distance, angle) = frame.distance_to(expected);
+            assert!(distance < 1e-12 && angle < 1e-12);
+            count += 1;
+        }
+        assert_eq!(count, links.len());
+        let (distance, angle) = Pose::accumulate(links).distance_to(expected);
+        assert!(distance < 1e-12 && angle < 1e-12);
+        assert_eq!(Pose::accumulate([]), Pose::<f64, f64>::identity());
+    }
+
+    #[test]
+    fn homogeneous_matrices_round_trip_and_remove_drift() {
+        use crate::quaternion::Quaternion;
+        let pose = Pose::new(
+            Vector::new(-1.0_f64, 2.5, 0.3),
+            Quaternion::from_angle_axis(2.9, Vector::new(0.2, -1.0, 0.4)),
+        );
+        for restored in [
+            Pose::from_homogeneous(pose.into_homogeneous()),
+            Pose::from_homogeneous_column_major(pose.into_homogeneous_column_major()),
+        ] {
+            assert!((restored.translation() - pose.translation()).norm() < 1e-12);
+            assert!((restored.rotation().dot(pose.rotation()).abs() - 1.0).abs() < 1e-12);
+        }
+        let mut drifted = pose.into_homogeneous();
+        for (row, values) in drifted.iter_mut().take(3).enumerate() {
+            for (column, value) in values.iter_mut().take(3).enumerate() {
+                *value *= 1.0 + 1e-4 * ((row + column) % 3) as f64;
+            }
+        }
+        let restored = Pose::from_homogeneous(drifted);
+        let matrix = restored.rotation().into_rotation_matrix();
+        assert!(Quaternion::try_from_rotation_matrix(matrix, 1e-12).is_ok());
+        assert!((restored.rotation().dot(pose.rotation()).abs() - 1.0).abs() < 1e-8);
+    }
+
+    #[test]
+    fn first_order_integration_converges_to_exact_step() {
+        use crate::quaternion::Quaternion;
+        let start = Pose::new(
+            Vector::new(0.5_f64, -1.0, 2.0),
+            Quaternion::from_angle_axis(0.8, Vector::new(1.0, 0.0, 1.0)),
+        );
+        let twist = Twist::new(Vector::new(1.0, 0.5, -0.2), Vector::new(0.3, -1.2, 0.7));
+        let exact = start.integrate(twist, 1.0);
+        let mut previous = f64::INFINITY;
+        for steps in [10, 100, 1000, 10000] {
+            let dt = 1.0 / steps as f64;
+            let (mut approximate, mut stepped) = (start, start);
+            for _ in 0..steps {
+                approximate = approximate.integrate_first_order(twist, dt);
+                stepped = stepped.integrate(twist, dt);
+            }
+            assert!((stepped.translation() - exact.translation()).norm() < 1e-12);
+            assert!((stepped.rotation().dot(exact.rotation()) - 1.0).abs() < 1e-12);
+            let rotation = approximate.rotation().angle_to(exact.rotation());
+            let error = (approximate.translation() - exact.translation()).norm() + rotation;
+            assert!(error < previous / 5.0);
+            assert!(error < 2.0 / steps as f64);
+            previous = error;
+        }
+    }
+
+    #[test]
+    fn exp_and_log_are_inverse_near_identity_and_half_turn() {
+        let axis = Vector::new(2.0_f64, -1.0, 2.0) / 3.0;
+        let velocity = Vector::new(0.5, 1.5, -1.0);
+        for angle in [
+            0.0,
+            1e-12,
+            1e-7,
+            1e-4,
+            1e-2,
+            1.0,
+            3.0,
+            core::f64::consts::PI - 1e-6,
+        ] {
+            for scale in [1e-9, 1.0, 1e3] {
+                let twist = Twist::new(velocity * scale, axis * angle);
+                let restored = Pose::exp(twist).log();
+                let tolerance = 1e-11 * (1.0 + scale);
+                assert!((restored.linear() - twist.linear()).norm() <= tolerance);
+                assert!((restored.angular() - twist.angular()).norm() <= 1e-12 * angle.max(1e-3));
+            }
+        }
+    }
+
+    #[test]
+    fn mirrored_pose_maps_reflected_points() {
+        use crate::geometry::Plane;
+        let plane = Plane::new(Vector::new(1.0_f64, -2.0, 0.5), 0.7).unwrap();
+        let pose = test_pose();
+        let mirrored = pose.mirrored(&plane);
+        assert!(mirrored.is_rigid(1e-12));
+        for point in [
+            Vector::new(0.0, 0.0, 0.0),
+            Vector::new(1.0, 2.0, 3.0),
+            Vector::new(-0.5, 0.1, 0.9),
+        ] {
+            let local = point.reflect_across(plane.normal());
+            let expected = plane.reflect(pose.apply_to(point));
+            assert!((mirrored.apply_to(local) - expected).norm() < 1e-12);
+        }
+        let (distance, angle) = mirrored.mirrored(&plane).distance_to(pose);
+        assert!(distance < 1e-12 && angle < 1e-12);
+    }
+}
@@ -0,0 +1,215 @@
+#[cfg(feature = "num")]
+use crate::ops::Real;
+#[cfg(feature = "num")]
+use crate::pose::Pose;
+use crate::rotation2::Rotation2;
+use crate::vector2::Vector2;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Mul, Neg, Sub};
+#[cfg(feature = "num")]
+use num::{One, Zero};
+
+/// Planar pose, translation-rotation pair with the same conventions as [`Pose`].
+/// ```
+/// # use spatial::vector2::Vector2;
+/// # use spatial::rotation2::Rotation2;
+/// # use spatial::pose2::Pose2;
+/// let robot = Pose2::new(Vector2::new(1.0, 2.0), Rotation2::with_cos_sin(0.0, 1.0));
+/// let sensor = Pose2::new(Vector2::new(0.5, 0.0), Rotation2::with_cos_sin(1.0, 0.0));
+/// let mounted = robot * sensor;
+/// assert_eq!(mounted.translation(), Vector2::new(1.0, 2.5));
+/// assert_eq!(mounted.apply_to(Vector2::new(1.0, 0.0)), Vector2::new(1.0, 3.5));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Pose2<T, R> {
+    translation: Vector2<T>,
+    rotation: Rotation2<R>,
+}
+
+impl<T, R> Pose2<T, R> {
+    pub const fn new(translation: Vector2<T>, rotation: Rotation2<R>) -> Self {
+        Self {
+            translation,
+            rotation,
+        }
+    }
+
+    /// Create pose representing no translation and no rotation.
+    #[cfg(feature = "num")]
+    pub fn identity() -> Self
+    where
+        T: Zero,
+        R: One + Zero,
+    {
+        Self::new(Vector2::zero(), Rotation2::identity())
+    }
+}
+
+/// Implement constant planar poses for given floating point type.
+macro_rules! impl_pose2_constants {
+    ($($float:ty),+) => {$(
+        impl Pose2<$float, $float> {
+            /// Pose representing no translation and no rotation.
+            pub const IDENTITY: Self = Self::new(Vector2::<$float>::ZERO, Rotation2::<$float>::IDENTITY);
+        }
+    )+};
+}
+
+impl_pose2_constants!(f32, f64);
+
+impl<T, R> Pose2<T, R>
+where
+    T: Copy,
+    R: Copy,
+{
+    pub fn translation(&self) -> Vector2<T> {
+        self.translation
+    }
+
+    pub fn rotation(&self) -> Rotation2<R> {
+        self.rotation
+    }
+}
+
+impl<T, R> Pose2<T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+{
+    /// Calculate new pose based on consecutive application of this and other poses.
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            translation: self.translation + self.rotation.rotate(other.translation),
+            rotation: self.rotation * other.rotation,
+        }
+    }
+
+    /// Apply transform stored in this pose to given vector.
+    pub fn apply_to(self, other: Vector2<T>) -> Vector2<T> {
+        self.translation + self.rotation.rotate(other)
+    }
+
+    /// Calculates inverse pose such that `a * a.inverse()` is identity.
+    pub fn inverse(self) -> Self
+    where
+        T: Neg<Output = T>,
+        R: Neg<Output = R>,
+    {
+        let rotation = self.rotation.inverse();
+        Self {
+            translation: rotation.rotate(-self.translation),
+            rotation,
+        }
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> Pose2<T, T>
+where
+    T: Real,
+{
+    /// Create pose at given position rotated counterclockwise by heading in radians.
+    pub fn from_xy_heading(x: T, y: T, heading: T) -> Self {
+        Self::new(Vector2::new(x, y), Rotation2::from_angle(heading))
+    }
+
+    /// Lift into spatial pose in the `xy` plane rotated around the `z` axis.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::pose2::Pose2;
+    /// let first = Pose2::from_xy_heading(1.0_f64, 2.0, 0.5);
+    /// let second = Pose2::from_xy_heading(-0.5, 1.0, 2.0);
+    /// let lifted = (first * second).into_pose3();
+    /// let expected = first.into_pose3() * second.into_pose3();
+    /// assert!((lifted.translation() - expected.translation()).norm() < 1e-12);
+    /// assert!(lifted.rotation().angle_to(expected.rotation()) < 1e-12);
+    /// ```
+    pub fn into_pose3(self) -> Pose<T, T> {
+        Pose::new(
+            self.translation.into_vector3(T::zero()),
+            self.rotation.into_quaternion(),
+        )
+    }
+}
+
+impl<T, R> Mul for Pose2<T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        self.combine(other)
+    }
+}
+
+impl<T, R> Mul<Vector2<T>> for Pose2<T, R>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T>,
+    R: Copy + Mul<Output = R> + Add<Output = R> + Sub<Output = R> + Mul<T, Output = T>,
+{
+    type Output = Vector2<T>;
+    fn mul(self, other: Vector2<T>) -> Self::Output {
+        self.apply_to(other)
+    }
+}
+
+/// Format as `pos [x, y] rot (cos; sin)`, formatter flags such as precision apply to every
+/// component.
+impl<T: Display, R: Display> Display for Pose2<T, R> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("pos ")?;
+        self.translation.fmt(formatter)?;
+        formatter.write_str(" rot ")?;
+        self.rotation.fmt(formatter)
+    }
+}
+
+#[cfg(all(test, feature = "num"))]
+mod test {
+    use super::Pose2;
+    use crate::rotation2::Rotation2;
+    use crate::vector::Vector;
+    use crate::vector2::Vector2;
+
+    #[test]
+    fn planar_poses_match_lifted_spatial_poses() {
+        let poses = [
+            Pose2::from_xy_heading(1.0_f64, -2.0, 0.3),
+            Pose2::from_xy_heading(0.5, 0.0, -2.8),
+            Pose2::from_xy_heading(-3.0, 1.5, core::f64::consts::PI),
+        ];
+        let point = Vector2::new(0.7, -0.4);
+        for first in poses {
+            let round_trip = first * first.inverse();
+            assert!(round_trip.translation().norm() < 1e-12);
+            assert!(round_trip.rotation().angle().abs() < 1e-12);
+            let lifted = first.into_pose3();
+            let expected = lifted.apply_to(point.into_vector3(0.0));
+            assert!((first.apply_to(point).into_vector3(0.0) - expected).norm() < 1e-12);
+            assert!(
+                (lifted
+                    .rotation()
+                    .angle_to(first.rotation().into_quaternion()))
+                    < 1e-12
+            );
+            for second in poses {
+                let planar = (first * second.inverse()).into_pose3();
+                let spatial = first.into_pose3() * second.into_pose3().inverse();
+                assert!((planar.translation() - spatial.translation()).norm() < 1e-12);
+                assert!(planar.rotation().angle_to(spatial.rotation()) < 1e-12);
+            }
+        }
+        let half = Rotation2::with_cos_sin(-1.0_f64, 0.0).into_quaternion();
+        assert!((half.rotate(Vector::<f64>::unit_x()) + Vector::unit_x()).norm() < 1e-12);
+        let drifted = Rotation2::with_cos_sin(0.0, 1.001)
+            .normalized_checked()
+            .unwrap();
+        assert_eq!(drifted, Rotation2::with_cos_sin(0.0, 1.0));
+        assert_eq!(Pose2::<f64, f64>::IDENTITY, Pose2::identity());
+        let up = Vector2::<f32>::UNIT_Y;
+        assert_eq!(Pose2::<f32, f32>::IDENTITY.apply_to(up), up);
+    }
+}
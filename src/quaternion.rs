@@ -1,12 +1,22 @@
+use crate::error::{to_array, ConversionError, GimbalLockWarning, ZeroNormError};
 #[cfg(feature = "num")]
-use crate::ops::Sqrt;
+use crate::error::{NonOrthonormalError, ValidationError};
+#[cfg(feature = "num")]
+use crate::ops::{is_finite, IntoAngle, IntoProgress, Real};
+use crate::ops::{Epsilon, Norm, Sqrt, Trig};
 use crate::vector::Vector;
-use core::ops::{Add, Mul, Neg, Sub};
+use core::fmt::{Display, Formatter};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 #[cfg(feature = "num")]
-use num::{Float, One, Zero};
+use num::{One, Zero};
+#[cfg(all(feature = "num", feature = "uom"))]
+use uom::si::angle::radian;
 
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct Quaternion<T> {
     w: T,
     i: T,
@@ -15,11 +25,398 @@ pub struct Quaternion<T> {
 }
 
 impl<T> Quaternion<T> {
-    pub fn with_wijk(w: T, i: T, j: T, k: T) -> Self {
+    pub const fn with_wijk(w: T, i: T, j: T, k: T) -> Self {
+        Self { w, i, j, k }
+    }
+
+    /// Create quaternion from `[x, y, z, w]` array with the scalar last, the component order
+    /// of `mint`, `glam` and `nalgebra` quaternions.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// let quaternion = Quaternion::from_xyzw([0.5, -0.5, 0.5, 0.5]);
+    /// assert_eq!(quaternion, Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5));
+    /// assert_eq!(quaternion.into_xyzw(), [0.5, -0.5, 0.5, 0.5]);
+    /// ```
+    pub fn from_xyzw([i, j, k, w]: [T; 4]) -> Self {
         Self { w, i, j, k }
     }
+
+    /// Convert quaternion into `[x, y, z, w]` array with the scalar last, see
+    /// [`Quaternion::from_xyzw`].
+    pub fn into_xyzw(self) -> [T; 4] {
+        [self.i, self.j, self.k, self.w]
+    }
+
+    /// Apply function to every component.
+    pub fn map<F: Fn(T) -> R, R>(self, mapper: F) -> Quaternion<R> {
+        Quaternion {
+            w: mapper(self.w),
+            i: mapper(self.i),
+            j: mapper(self.j),
+            k: mapper(self.k),
+        }
+    }
+
+    /// Convert components with `as` semantics, e.g. from `f64` to `f32`.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::with_wijk(0.5_f64, -0.5, 0.5, 0.5);
+    /// assert_eq!(rotation.cast::<f32>(), Quaternion::with_wijk(0.5_f32, -0.5, 0.5, 0.5));
+    /// ```
+    #[cfg(feature = "num")]
+    pub fn cast<R>(self) -> Quaternion<R>
+    where
+        T: num::traits::AsPrimitive<R>,
+        R: Copy + 'static,
+    {
+        self.map(num::traits::AsPrimitive::as_)
+    }
+
+    /// View components as `[w, i, j, k]` array without copying.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// let mut quaternion = Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0);
+    /// quaternion.as_mut_array()[3] = 1.0;
+    /// assert_eq!(quaternion.as_array(), &[1.0, 0.0, 0.0, 1.0]);
+    /// ```
+    pub fn as_array(&self) -> &[T; 4] {
+        // SAFETY: `Quaternion` is `repr(C)` with four fields of type `T`, which is the layout of `[T; 4]`.
+        unsafe { &*(self as *const Self).cast::<[T; 4]>() }
+    }
+
+    /// View components as mutable `[w, i, j, k]` array without copying.
+    pub fn as_mut_array(&mut self) -> &mut [T; 4] {
+        // SAFETY: `Quaternion` is `repr(C)` with four fields of type `T`, which is the layout of `[T; 4]`.
+        unsafe { &mut *(self as *mut Self).cast::<[T; 4]>() }
+    }
+
+    /// View slice of quaternions as flat `[w, i, j, k, w, i, j, k, ...]` slice without
+    /// copying, e.g. to upload it into uniform buffer.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotations = [Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0), Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0)];
+    /// assert_eq!(Quaternion::flatten_slice(&rotations), &[1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+    /// ```
+    pub fn flatten_slice(quaternions: &[Self]) -> &[T] {
+        // SAFETY: every quaternion has the layout of `[T; 4]` and arrays have no padding
+        // between elements, so `n` consecutive quaternions have the layout of `[T; 4 * n]`.
+        unsafe {
+            core::slice::from_raw_parts(quaternions.as_ptr().cast::<T>(), quaternions.len() * 4)
+        }
+    }
+
+    /// View slice of quaternions as flat mutable slice without copying, see
+    /// [`Quaternion::flatten_slice`].
+    pub fn flatten_slice_mut(quaternions: &mut [Self]) -> &mut [T] {
+        // SAFETY: see `flatten_slice`.
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                quaternions.as_mut_ptr().cast::<T>(),
+                quaternions.len() * 4,
+            )
+        }
+    }
+}
+
+/// Implement constant quaternions for given floating point type.
+macro_rules! impl_quaternion_constants {
+    ($($float:ty),+) => {$(
+        impl Quaternion<$float> {
+            /// Quaternion representing no rotation.
+            pub const IDENTITY: Self = Self::with_wijk(1.0, 0.0, 0.0, 0.0);
+        }
+
+        impl UnitQuaternion<$float> {
+            /// Unit quaternion representing no rotation.
+            pub const IDENTITY: Self = Self::new_unchecked(Quaternion::<$float>::IDENTITY);
+        }
+    )+};
+}
+
+impl_quaternion_constants!(f32, f64);
+
+/// Quaternion with `f32` components.
+pub type Quatf = Quaternion<f32>;
+/// Quaternion with `f64` components.
+pub type Quatd = Quaternion<f64>;
+
+/// Sequence of rotation axes of Euler angles. Tait-Bryan sequences rotate about three
+/// different axes, proper Euler sequences repeat the first axis.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EulerSequence {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+    Xyx,
+    Xzx,
+    Yxy,
+    Yzy,
+    Zxz,
+    Zyz,
+}
+
+impl EulerSequence {
+    /// All twelve sequences, Tait-Bryan ones first.
+    pub const ALL: [Self; 12] = [
+        Self::Xyz,
+        Self::Xzy,
+        Self::Yxz,
+        Self::Yzx,
+        Self::Zxy,
+        Self::Zyx,
+        Self::Xyx,
+        Self::Xzx,
+        Self::Yxy,
+        Self::Yzy,
+        Self::Zxz,
+        Self::Zyz,
+    ];
+
+    /// Get indices of the rotation axes, `0` for x, `1` for y and `2` for z.
+    pub const fn axes(self) -> [usize; 3] {
+        match self {
+            Self::Xyz => [0, 1, 2],
+            Self::Xzy => [0, 2, 1],
+            Self::Yxz => [1, 0, 2],
+            Self::Yzx => [1, 2, 0],
+            Self::Zxy => [2, 0, 1],
+            Self::Zyx => [2, 1, 0],
+            Self::Xyx => [0, 1, 0],
+            Self::Xzx => [0, 2, 0],
+            Self::Yxy => [1, 0, 1],
+            Self::Yzy => [1, 2, 1],
+            Self::Zxz => [2, 0, 2],
+            Self::Zyz => [2, 1, 2],
+        }
+    }
+}
+
+/// Convention of Euler angles, the sequence lists axes in the order the rotations are
+/// applied. Intrinsic rotations are about axes of the rotating frame, extrinsic ones are
+/// about axes of the fixed frame. Intrinsic `Zyx` equals extrinsic `Xyz` with reversed angles.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EulerOrder {
+    Intrinsic(EulerSequence),
+    Extrinsic(EulerSequence),
 }
 
+/// Euler angles in radians together with their convention, angles are listed in the order
+/// of sequence axes.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EulerAngles<T> {
+    pub order: EulerOrder,
+    pub angles: [T; 3],
+}
+
+/// Implement convenience methods for given floating point type.
+macro_rules! impl_quaternion_helpers {
+    ($($float:ty),+) => {$(
+        impl Quaternion<$float> {
+            /// Create rotation from roll, pitch and yaw angles in radians, applied as
+            /// intrinsic rotations around `z` (yaw), then `y` (pitch), then `x` (roll) axes.
+            /// ```
+            /// # use spatial::quaternion::Quatd;
+            /// # use spatial::vector::Vector;
+            /// # use core::f64::consts::FRAC_PI_2;
+            /// let rotation = Quatd::from_rpy(0.0, 0.0, FRAC_PI_2);
+            /// let result = rotation.rotate(Vector::new(1.0, 0.0, 0.0));
+            /// assert!((result - Vector::new(0.0, 1.0, 0.0)).length() < 1e-9);
+            /// let (roll, pitch, yaw) = Quatd::from_rpy(0.1, -0.2, 0.3).into_rpy();
+            /// assert!((roll - 0.1).abs() < 1e-9 && (pitch + 0.2).abs() < 1e-9 && (yaw - 0.3).abs() < 1e-9);
+            /// ```
+            pub fn from_rpy(roll: $float, pitch: $float, yaw: $float) -> Self {
+                let (sin_roll, cos_roll) = (Trig::sin(roll / 2.0), Trig::cos(roll / 2.0));
+                let (sin_pitch, cos_pitch) = (Trig::sin(pitch / 2.0), Trig::cos(pitch / 2.0));
+                let (sin_yaw, cos_yaw) = (Trig::sin(yaw / 2.0), Trig::cos(yaw / 2.0));
+                Self {
+                    w: cos_roll * cos_pitch * cos_yaw + sin_roll * sin_pitch * sin_yaw,
+                    i: sin_roll * cos_pitch * cos_yaw - cos_roll * sin_pitch * sin_yaw,
+                    j: cos_roll * sin_pitch * cos_yaw + sin_roll * cos_pitch * sin_yaw,
+                    k: cos_roll * cos_pitch * sin_yaw - sin_roll * sin_pitch * cos_yaw,
+                }
+            }
+
+            /// Convert unit quaternion into roll, pitch and yaw angles in radians,
+            /// see [`Quaternion::from_rpy`]. Pitch is in the `[-pi/2, pi/2]` range.
+            pub fn into_rpy(self) -> ($float, $float, $float) {
+                let Self { w, i, j, k } = self;
+                let roll = Trig::atan2(2.0 * (w * i + j * k), 1.0 - 2.0 * (i * i + j * j));
+                let sin_pitch = (2.0 * (w * j - k * i)).clamp(-1.0, 1.0);
+                let pitch = Trig::atan2(sin_pitch, Sqrt::sqrt(1.0 - sin_pitch * sin_pitch));
+                let yaw = Trig::atan2(2.0 * (w * k + i * j), 1.0 - 2.0 * (j * j + k * k));
+                (roll, pitch, yaw)
+            }
+
+            /// Convert unit quaternion into roll, pitch and yaw angles like
+            /// [`Quaternion::into_rpy`], but report [`GimbalLockWarning`] when pitch is within
+            /// about `1e-3` radians of a quarter turn. Angles of the warning have zero roll.
+            pub fn into_rpy_checked(self) -> Result<($float, $float, $float), GimbalLockWarning<$float>> {
+                let (roll, pitch, yaw) = self.into_rpy();
+                if Trig::cos(pitch) >= 1e-3 {
+                    return Ok((roll, pitch, yaw));
+                }
+                let half = Trig::atan2(self.i, self.w);
+                let heading = if pitch > 0.0 { -2.0 * half } else { 2.0 * half };
+                Err(GimbalLockWarning {
+                    roll: 0.0,
+                    pitch,
+                    yaw: Trig::atan2(Trig::sin(heading), Trig::cos(heading)),
+                })
+            }
+
+            /// Create rotation from Euler angles in radians, listed in the order of sequence
+            /// axes in given convention.
+            /// ```
+            /// # use spatial::quaternion::{EulerOrder, EulerSequence, Quatd};
+            /// let intrinsic = Quatd::from_euler(EulerOrder::Intrinsic(EulerSequence::Zyx), 0.3, -0.2, 0.1);
+            /// let extrinsic = Quatd::from_euler(EulerOrder::Extrinsic(EulerSequence::Xyz), 0.1, -0.2, 0.3);
+            /// assert!((intrinsic.dot(Quatd::from_rpy(0.1, -0.2, 0.3)) - 1.0).abs() < 1e-12);
+            /// assert!((intrinsic.dot(extrinsic) - 1.0).abs() < 1e-12);
+            /// ```
+            pub fn from_euler(order: EulerOrder, a: $float, b: $float, c: $float) -> Self {
+                let elementary = |axis: usize, angle: $float| {
+                    let mut components = [Trig::cos(angle / 2.0), 0.0, 0.0, 0.0];
+                    components[axis + 1] = Trig::sin(angle / 2.0);
+                    Self::from(components)
+                };
+                match order {
+                    EulerOrder::Intrinsic(sequence) => {
+                        let [first, second, third] = sequence.axes();
+                        elementary(first, a) * elementary(second, b) * elementary(third, c)
+                    }
+                    EulerOrder::Extrinsic(sequence) => {
+                        let [first, second, third] = sequence.axes();
+                        elementary(third, c) * elementary(second, b) * elementary(first, a)
+                    }
+                }
+            }
+
+            /// Convert unit quaternion into Euler angles in given convention, see
+            /// [`Quaternion::from_euler`]. First and third angles are in the `[-pi, pi]` range,
+            /// the middle one is in `[-pi/2, pi/2]` for Tait-Bryan and in `[0, pi]` for proper
+            /// Euler sequences.
+            ///
+            /// Close to gimbal lock the first and third axes coincide and only the sum or
+            /// difference of their angles is defined, then the third angle is set to zero.
+            /// ```
+            /// # use spatial::quaternion::{EulerOrder, EulerSequence, Quatd};
+            /// let order = EulerOrder::Intrinsic(EulerSequence::Zxz);
+            /// let rotation = Quatd::from_euler(order, 0.4, 1.2, -0.7);
+            /// let [a, b, c] = rotation.into_euler(order).angles;
+            /// assert!((a - 0.4).abs() < 1e-12 && (b - 1.2).abs() < 1e-12 && (c + 0.7).abs() < 1e-12);
+            /// let locked = Quatd::from_euler(order, 0.4, 0.0, -0.7).into_euler(order);
+            /// assert!((locked.angles[0] + 0.3).abs() < 1e-12 && locked.angles[2] == 0.0);
+            /// ```
+            pub fn into_euler(self, order: EulerOrder) -> EulerAngles<$float> {
+                const PI: $float = core::f64::consts::PI as $float;
+                // Direct method of Bernardes and Viollet, intrinsic angles are extrinsic
+                // angles of the reversed sequence.
+                let (sequence, extrinsic) = match order {
+                    EulerOrder::Intrinsic(sequence) => (sequence, false),
+                    EulerOrder::Extrinsic(sequence) => (sequence, true),
+                };
+                let [first, q, last] = sequence.axes();
+                let (p, r) = if extrinsic { (first, last) } else { (last, first) };
+                let symmetric = first == last;
+                let r = if symmetric { 3 - p - q } else { r };
+                let sign = if (q + 3 - p) % 3 == 1 { 1.0 } else { -1.0 };
+                let vector = [self.i, self.j, self.k];
+                let (a, b, c, d) = if symmetric {
+                    (self.w, vector[p], vector[q], vector[r] * sign)
+                } else {
+                    (
+                        self.w - vector[q],
+                        vector[p] + vector[r] * sign,
+                        vector[q] + self.w,
+                        vector[r] * sign - vector[p],
+                    )
+                };
+                let hypot = |x: $float, y: $float| Sqrt::sqrt(x * x + y * y);
+                let mut middle = 2.0 * Trig::atan2(hypot(c, d), hypot(a, b));
+                let half_sum = Trig::atan2(b, a);
+                let half_difference = Trig::atan2(d, c);
+                let threshold = Sqrt::sqrt(<$float>::EPSILON);
+                // Angles of the extrinsic sequence, at gimbal lock only their sum or difference
+                // is known and the one which is the third angle of the convention is zeroed.
+                let (outer, mut inner) = match (middle <= threshold, PI - middle <= threshold) {
+                    (true, _) if extrinsic => (2.0 * half_sum, 0.0),
+                    (true, _) => (0.0, 2.0 * half_sum),
+                    (false, true) if extrinsic => (-2.0 * half_difference, 0.0),
+                    (false, true) => (0.0, 2.0 * half_difference),
+                    (false, false) => (half_sum - half_difference, half_sum + half_difference),
+                };
+                if !symmetric {
+                    inner *= sign;
+                    middle -= PI / 2.0;
+                }
+                let wrap = |angle: $float| {
+                    if angle < -PI {
+                        angle + 2.0 * PI
+                    } else if angle > PI {
+                        angle - 2.0 * PI
+                    } else {
+                        angle
+                    }
+                };
+                let angles = if extrinsic {
+                    [wrap(outer), middle, wrap(inner)]
+                } else {
+                    [wrap(inner), middle, wrap(outer)]
+                };
+                EulerAngles { order, angles }
+            }
+
+            /// Calculate norm, with fused multiply-add under the `fma` feature.
+            fn float_norm(self) -> $float {
+                #[cfg(feature = "fma")]
+                {
+                    let fused = |x: $float, y: $float, z: $float| num::Float::mul_add(x, y, z);
+                    let (w, i, j, k) = (self.w, self.i, self.j, self.k);
+                    Sqrt::sqrt(fused(w, w, fused(i, i, fused(j, j, k * k))))
+                }
+                #[cfg(not(feature = "fma"))]
+                Norm::norm(self)
+            }
+
+            /// Scale quaternion to unit norm, quaternions with norm below epsilon are replaced
+            /// with identity.
+            pub fn normalize(self) -> Self {
+                let norm = self.float_norm();
+                if norm < <$float>::EPSILON {
+                    Self::IDENTITY
+                } else {
+                    self * (1.0 / norm)
+                }
+            }
+
+            /// Scale quaternion to unit norm, see [`ZeroNormError`].
+            pub fn try_normalize(self) -> Result<Self, ZeroNormError<$float>> {
+                let norm = self.float_norm();
+                if norm < <$float>::EPSILON {
+                    Err(ZeroNormError { norm })
+                } else {
+                    Ok(self * (1.0 / norm))
+                }
+            }
+        }
+
+        impl From<EulerAngles<$float>> for Quaternion<$float> {
+            fn from(euler: EulerAngles<$float>) -> Self {
+                let [a, b, c] = euler.angles;
+                Self::from_euler(euler.order, a, b, c)
+            }
+        }
+    )+};
+}
+
+impl_quaternion_helpers!(f32, f64);
+
 #[cfg(feature = "num")]
 impl<T> Quaternion<T>
 where
@@ -108,13 +505,22 @@ where
 #[cfg(feature = "num")]
 impl<T> Quaternion<T>
 where
-    T: Float + Sqrt<Output = T>,
+    T: Real,
 {
-    pub fn from_angle_axis(angle: T, axis: Vector<T>) -> Self {
+    /// Create rotation by `angle` around the axis, zero axis produces identity. `angle` is
+    /// either plain value in radians or, with the `uom` feature, `Angle`.
+    pub fn from_angle_axis<A: IntoAngle<T>>(angle: A, axis: Vector<T>) -> Self {
         if let Some(normalized) = axis.normalized_checked() {
-            let angle = angle / (T::one() + T::one());
-            let cos = angle.cos();
-            let sin = angle.sin();
+            let two = T::one() + T::one();
+            let angle = angle.into_angle() / two;
+            let squared = angle * angle;
+            let (cos, sin) = if squared * squared < T::epsilon() {
+                // Truncated Taylor series, the dropped fourth order terms are below epsilon.
+                let six = two * (two + T::one());
+                (T::one() - squared / two, angle * (T::one() - squared / six))
+            } else {
+                (angle.cos(), angle.sin())
+            };
             let i = normalized.x() * sin;
             let j = normalized.y() * sin;
             let k = normalized.z() * sin;
@@ -125,40 +531,638 @@ where
         }
     }
 
+    /// Convert rotation into angle in `[0, 2 pi]` and unit axis, identity yields zero angle
+    /// around the `x` axis, see [`Quaternion::into_angle_axis_or`].
     pub fn into_angle_axis(self) -> (T, Vector<T>) {
-        let half_angle = self.w.max(-T::one()).min(T::one()).acos();
-        if half_angle < T::epsilon() {
-            (T::zero(), Vector::zero())
+        self.into_angle_axis_or(Vector::new(T::one(), T::zero(), T::zero()))
+    }
+
+    /// Convert rotation into angle in `[0, 2 pi]` and unit axis, identity yields zero angle
+    /// around `fallback` axis. Half angle is extracted with `atan2`, so tiny rotations keep
+    /// full relative precision unlike with `acos` of the scalar part.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let tiny = Quaternion::from_angle_axis(1e-10_f64, Vector::unit_y());
+    /// let (angle, axis) = tiny.into_angle_axis_or(Vector::unit_z());
+    /// assert!((angle - 1e-10).abs() < 1e-24 && axis == Vector::unit_y());
+    /// let (angle, axis) = Quaternion::<f64>::identity().into_angle_axis_or(Vector::unit_z());
+    /// assert!(angle == 0.0 && axis == Vector::unit_z());
+    /// ```
+    pub fn into_angle_axis_or(self, fallback: Vector<T>) -> (T, Vector<T>) {
+        let vector = Vector::new(self.i, self.j, self.k);
+        let sin = vector.norm();
+        if sin > T::zero() {
+            let angle = (T::one() + T::one()) * sin.atan2(self.w);
+            (angle, vector / sin)
         } else {
-            let sin = half_angle.sin();
-            let axis = Vector::new(self.i, self.j, self.k) / sin;
-            (half_angle * (T::one() + T::one()), axis)
+            (T::zero(), fallback)
         }
     }
 
+    /// Convert rotation into angle and unit axis, see [`Quaternion::into_angle_axis_or`].
+    /// Returns `None` for rotations by less than about epsilon whose axis is unreliable.
     pub fn into_angle_axis_checked(self) -> Option<(T, Vector<T>)> {
-        let half_angle = self.w.max(-T::one()).min(T::one()).acos();
-        if half_angle < T::epsilon() {
+        let (angle, axis) = self.into_angle_axis_or(Vector::zero());
+        if angle < T::epsilon() {
             None
         } else {
-            let sin = half_angle.sin();
-            let axis = Vector::new(self.i, self.j, self.k) / sin;
-            Some((half_angle * (T::one() + T::one()), axis))
+            Some((angle, axis))
         }
     }
 
-    pub fn slerp(self, other: Self, progress: T) -> Self {
-        let dot = self.dot(other);
-        let (other, dot) = if dot < T::zero() {
-            (-other, -dot)
+    /// Convert rotation into rotation vector, unit axis scaled by angle, of the shortest
+    /// rotation, same as [`Quaternion::log`]. Unlike angle and axis it has no singularity
+    /// at identity.
+    pub fn into_scaled_axis(self) -> Vector<T> {
+        self.log()
+    }
+
+    /// Logarithm map, returns rotation vector (unit axis scaled by angle) of the shortest
+    /// rotation represented by this quaternion. Near identity truncated series keeps full
+    /// relative precision of tiny rotations.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Vector::new(3e-9_f64, -1e-9, 2e-9);
+    /// let error = Quaternion::exp(rotation).log() - rotation;
+    /// assert!(error.norm() < 1e-24);
+    /// let half_turn = -Quaternion::from_angle_axis(3.0_f64, Vector::unit_x());
+    /// assert!((half_turn.log() - Vector::new(3.0, 0.0, 0.0)).norm() < 1e-12);
+    /// ```
+    pub fn log(self) -> Vector<T> {
+        let two = T::one() + T::one();
+        let (w, vector) = if self.w < T::zero() {
+            (-self.w, Vector::new(-self.i, -self.j, -self.k))
+        } else {
+            (self.w, Vector::new(self.i, self.j, self.k))
+        };
+        let squared: T = vector.dot(vector);
+        let ratio = squared / (w * w);
+        if ratio * ratio < T::epsilon() {
+            // Truncated series of `atan(x) / x = 1 - x^2 / 3 + ...` for `x = sin / w`.
+            vector * (two / w * (T::one() - ratio / (two + T::one())))
+        } else {
+            let sin = squared.sqrt();
+            vector * (two * sin.atan2(w) / sin)
+        }
+    }
+
+    /// Exponential map, creates rotation from rotation vector (unit axis scaled by angle).
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use core::f64::consts::FRAC_PI_2;
+    /// let rotation = Quaternion::exp(Vector::new(0.0, 0.0, FRAC_PI_2));
+    /// assert!((rotation.rotate(Vector::<f64>::unit_x()) - Vector::unit_y()).norm() < 1e-12);
+    /// assert_eq!(Quaternion::exp(Vector::<f64>::zero()), Quaternion::identity());
+    /// ```
+    pub fn exp(rotation: Vector<T>) -> Self {
+        let two = T::one() + T::one();
+        let squared: T = rotation.dot(rotation);
+        if squared * squared < T::epsilon() {
+            // Truncated Taylor series, the dropped fourth order terms are below epsilon.
+            let eight = two * two * two;
+            let w = T::one() - squared / eight;
+            let scale = T::one() / two - squared / (eight * (two + T::one()) * two);
+            Self {
+                w,
+                i: rotation.x() * scale,
+                j: rotation.y() * scale,
+                k: rotation.z() * scale,
+            }
+        } else {
+            let angle = squared.sqrt();
+            let half = angle / two;
+            let scale = half.sin() / angle;
+            Self {
+                w: half.cos(),
+                i: rotation.x() * scale,
+                j: rotation.y() * scale,
+                k: rotation.z() * scale,
+            }
+        }
+    }
+
+    /// Raise unit quaternion to given power, scaling angle of the shortest rotation while
+    /// keeping its axis, `exp(exponent * log(self))`.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::from_angle_axis(2.0_f64, Vector::new(1.0, -1.0, 0.5));
+    /// let quarter = rotation.powf(0.25);
+    /// assert!((quarter.angle_to(Quaternion::identity()) - 0.5).abs() < 1e-12);
+    /// assert!((quarter * quarter * quarter * quarter).angle_to(rotation) < 1e-12);
+    /// ```
+    pub fn powf(self, exponent: T) -> Self {
+        Self::exp(self.log() * exponent)
+    }
+
+    /// Calculate Jacobian of `self.rotate(vector)` with respect to body-frame rotation vector
+    /// perturbation `self * exp(delta)` at zero, which is `-R [vector]x` stored rows first.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::<f64>::identity();
+    /// let jacobian = rotation.rotate_jacobian(Vector::new(1.0, 0.0, 0.0));
+    /// assert_eq!(jacobian, [[0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, -1.0, 0.0]]);
+    /// ```
+    pub fn rotate_jacobian(self, vector: Vector<T>) -> [[T; 3]; 3] {
+        let matrix = self.into_rotation_matrix();
+        let skew = skew(vector);
+        let mut jacobian = [[T::zero(); 3]; 3];
+        for (row, rotation) in jacobian.iter_mut().zip(matrix) {
+            for (column, value) in row.iter_mut().enumerate() {
+                *value = -(rotation[0] * skew[0][column]
+                    + rotation[1] * skew[1][column]
+                    + rotation[2] * skew[2][column]);
+            }
+        }
+        jacobian
+    }
+
+    /// Integrate body-frame angular velocity over `dt` exactly assuming it is constant,
+    /// as gyroscope rates are.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rate = Vector::new(0.0_f64, 0.0, 2.0);
+    /// let rotation = Quaternion::identity().integrate(rate, 0.25);
+    /// assert!((rotation.dot(Quaternion::from_angle_axis(0.5, Vector::unit_z())) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn integrate(self, angular_velocity: Vector<T>, dt: T) -> Self {
+        self * Self::exp(angular_velocity * dt)
+    }
+
+    /// Integrate body-frame angular velocity over `dt` by single explicit Euler step of
+    /// `dq/dt = q * w / 2` followed by renormalization. Cheaper than
+    /// [`Quaternion::integrate`], with error growing as `dt^2` per step.
+    pub fn integrate_first_order(self, angular_velocity: Vector<T>, dt: T) -> Self {
+        let half = dt / (T::one() + T::one());
+        let velocity = angular_velocity * half;
+        let rate = Self::with_wijk(T::zero(), velocity.x(), velocity.y(), velocity.z());
+        (self + self * rate).normalized_checked().unwrap_or(self)
+    }
+
+    /// Create rotation with zero yaw that levels given sensor-frame gravity vector, so that
+    /// it is rotated onto the negative z axis. Accelerometer at rest measures the opposite
+    /// of gravity, negate its reading before passing it here. Returns `None` for zero vector.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let gravity = Vector::new(1.0_f64, -2.0, -9.5);
+    /// let level = Quaternion::from_gravity(gravity).unwrap();
+    /// let leveled = level.rotate(gravity.normalized_checked().unwrap());
+    /// assert!((leveled + Vector::unit_z()).norm() < 1e-9);
+    /// assert!(Quaternion::from_gravity(Vector::<f64>::zero()).is_none());
+    /// ```
+    pub fn from_gravity(gravity: Vector<T>) -> Option<Self> {
+        let up = -gravity.normalized_checked()?;
+        let roll = up.y().atan2(up.z());
+        let pitch = (-up.x()).atan2(Vector::new(up.y(), up.z(), T::zero()).norm());
+        Some(
+            Self::from_angle_axis(pitch, Vector::unit_y())
+                * Self::from_angle_axis(roll, Vector::unit_x()),
+        )
+    }
+
+    /// Create the shortest-arc rotation turning direction of `from` into direction of `to`,
+    /// `None` if either vector is zero. Antiparallel vectors are turned by half turn around
+    /// an axis perpendicular to `from`, see [`Vector::any_perpendicular`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let from = Vector::new(0.0_f64, 0.0, 2.0);
+    /// let to = Vector::new(1.0, 1.0, 0.0);
+    /// let rotation = Quaternion::rotation_between(from, to).unwrap();
+    /// assert!((rotation.rotate(from) - to.normalized_checked().unwrap() * 2.0).norm() < 1e-12);
+    /// let flip = Quaternion::rotation_between(from, -from).unwrap();
+    /// assert!((flip.rotate(from) + from).norm() < 1e-12);
+    /// assert!(Quaternion::rotation_between(from, Vector::zero()).is_none());
+    /// ```
+    pub fn rotation_between(from: Vector<T>, to: Vector<T>) -> Option<Self> {
+        let (from, to) = (from.normalized_checked()?, to.normalized_checked()?);
+        let w = T::one() + from.dot(to);
+        if w < T::epsilon() {
+            let axis = from.any_perpendicular()?;
+            return Some(Self::with_wijk(T::zero(), axis.x(), axis.y(), axis.z()));
+        }
+        let axis = from.cross(to);
+        Self::with_wijk(w, axis.x(), axis.y(), axis.z()).normalized_checked()
+    }
+
+    /// Create rotation of the frame with given `x` axis direction and `y` axis in the plane of
+    /// `x_axis` and `y_axis`. Axes are orthonormalized by Gram-Schmidt process, so `y_axis`
+    /// only needs to be roughly perpendicular. `None` if the axes are zero or parallel.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::from_axes(Vector::new(0.0_f64, 2.0, 0.0), Vector::new(-1.0, 0.5, 0.0)).unwrap();
+    /// assert!((rotation.rotate(Vector::<f64>::unit_x()) - Vector::unit_y()).norm() < 1e-12);
+    /// assert!((rotation.rotate(Vector::<f64>::unit_y()) + Vector::unit_x()).norm() < 1e-12);
+    /// assert!(Quaternion::from_axes(Vector::<f64>::unit_x(), Vector::new(3.0, 0.0, 0.0)).is_none());
+    /// ```
+    pub fn from_axes(x_axis: Vector<T>, y_axis: Vector<T>) -> Option<Self> {
+        let x = x_axis.normalized_checked()?;
+        let y = (y_axis - x * x.dot(y_axis)).normalized_checked()?;
+        let z = x.cross(y);
+        Some(Self::from_rotation_matrix([
+            [x.x(), y.x(), z.x()],
+            [x.y(), y.y(), z.y()],
+            [x.z(), y.z(), z.z()],
+        ]))
+    }
+
+    /// Create rotation of the frame with `x` axis pointing along `forward` and `z` axis as
+    /// close to `up` as possible, `None` if the vectors are zero or parallel.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let forward = Vector::new(1.0_f64, 1.0, -1.0);
+    /// let rotation = Quaternion::look_at(forward, Vector::unit_z()).unwrap();
+    /// let x = rotation.rotate(Vector::<f64>::unit_x());
+    /// assert!((x - forward.normalized_checked().unwrap()).norm() < 1e-12);
+    /// assert!(rotation.rotate(Vector::<f64>::unit_y()).z().abs() < 1e-12);
+    /// assert!(rotation.rotate(Vector::<f64>::unit_z()).z() > 0.0);
+    /// ```
+    pub fn look_at(forward: Vector<T>, up: Vector<T>) -> Option<Self> {
+        Self::from_axes(forward, up.cross(forward))
+    }
+
+    /// Create unit quaternion from rotation matrix stored rows first, such that rotating
+    /// vector by the quaternion equals multiplying it by the matrix from the left.
+    ///
+    /// The largest of the trace and the diagonal elements selects the component computed
+    /// from the square root, which keeps the conversion accurate for half-turn rotations
+    /// with trace near `-1`. The matrix is assumed to be orthonormal, see
+    /// [`Quaternion::try_from_rotation_matrix`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let matrix = [[0.0_f64, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+    /// let rotation = Quaternion::from_rotation_matrix(matrix);
+    /// assert!((rotation.rotate(Vector::<f64>::unit_x()) - Vector::unit_y()).norm() < 1e-12);
+    /// let restored = rotation.into_rotation_matrix();
+    /// assert!((0..3).all(|row| (0..3).all(|column| {
+    ///     (restored[row][column] - matrix[row][column]).abs() < 1e-12
+    /// })));
+    /// ```
+    pub fn from_rotation_matrix(matrix: [[T; 3]; 3]) -> Self {
+        let m = matrix;
+        let (one, two) = (T::one(), T::one() + T::one());
+        let four = two * two;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let rotation = if trace >= m[0][0] && trace >= m[1][1] && trace >= m[2][2] {
+            let s = two * (one + trace).sqrt();
+            Self {
+                w: s / four,
+                i: (m[2][1] - m[1][2]) / s,
+                j: (m[0][2] - m[2][0]) / s,
+                k: (m[1][0] - m[0][1]) / s,
+            }
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let s = two * (one + m[0][0] - m[1][1] - m[2][2]).sqrt();
+            Self {
+                w: (m[2][1] - m[1][2]) / s,
+                i: s / four,
+                j: (m[0][1] + m[1][0]) / s,
+                k: (m[0][2] + m[2][0]) / s,
+            }
+        } else if m[1][1] >= m[2][2] {
+            let s = two * (one + m[1][1] - m[0][0] - m[2][2]).sqrt();
+            Self {
+                w: (m[0][2] - m[2][0]) / s,
+                i: (m[0][1] + m[1][0]) / s,
+                j: s / four,
+                k: (m[1][2] + m[2][1]) / s,
+            }
+        } else {
+            let s = two * (one + m[2][2] - m[0][0] - m[1][1]).sqrt();
+            Self {
+                w: (m[1][0] - m[0][1]) / s,
+                i: (m[0][2] + m[2][0]) / s,
+                j: (m[1][2] + m[2][1]) / s,
+                k: s / four,
+            }
+        };
+        rotation * (one / Norm::norm(rotation))
+    }
+
+    /// Create unit quaternion from rotation matrix like [`Quaternion::from_rotation_matrix`],
+    /// but fail if any element of `matrix^T * matrix - I` exceeds `tolerance` or the
+    /// determinant is negative, i.e. the matrix is scaled, sheared or contains reflection.
+    /// ```
+    /// # use spatial::error::NonOrthonormalError;
+    /// # use spatial::quaternion::Quaternion;
+    /// let mirror = [[1.0_f64, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -1.0]];
+    /// let result = Quaternion::try_from_rotation_matrix(mirror, 1e-9).unwrap_err();
+    /// assert_eq!((result.residual, result.determinant), (0.0, -1.0));
+    /// ```
+    pub fn try_from_rotation_matrix(
+        matrix: [[T; 3]; 3],
+        tolerance: T,
+    ) -> Result<Self, NonOrthonormalError<T>> {
+        let columns = [0, 1, 2].map(|column| Vector::from(matrix.map(|row| row[column])));
+        let mut residual = T::zero();
+        for (index, first) in columns.iter().enumerate() {
+            for (other, second) in columns.iter().enumerate() {
+                let expected = if index == other { T::one() } else { T::zero() };
+                let deviation = first.dot(*second) - expected;
+                let deviation = if deviation < T::zero() {
+                    -deviation
+                } else {
+                    deviation
+                };
+                if deviation > residual || !is_finite(deviation) {
+                    residual = deviation;
+                }
+            }
+        }
+        let determinant = columns[0].cross(columns[1]).dot(columns[2]);
+        if residual > tolerance || !is_finite(residual) || determinant <= T::zero() {
+            return Err(NonOrthonormalError {
+                residual,
+                determinant,
+            });
+        }
+        Ok(Self::from_rotation_matrix(matrix))
+    }
+
+    /// Calculate geodesic angle in `[0, pi]` of the shortest rotation from this to other unit
+    /// quaternion, `q` and `-q` are the same rotation. The `atan2` form stays accurate for
+    /// tiny angles where `acos` of the dot product loses half of the digits.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let from = Quaternion::from_angle_axis(0.5_f64, Vector::unit_z());
+    /// let to = Quaternion::from_angle_axis(-0.25, Vector::unit_z());
+    /// assert!((from.angle_to(to) - 0.75).abs() < 1e-12);
+    /// assert!((from.angle_to(-to) - 0.75).abs() < 1e-12);
+    /// let close = Quaternion::from_angle_axis(0.5 + 1e-10, Vector::unit_z());
+    /// assert!((from.angle_to(close) - 1e-10).abs() < 1e-15);
+    /// ```
+    pub fn angle_to(self, other: Self) -> T {
+        let difference = self.inverse() * other;
+        let sin = Vector::new(difference.i, difference.j, difference.k).norm();
+        let cos = if difference.w < T::zero() {
+            -difference.w
+        } else {
+            difference.w
+        };
+        (T::one() + T::one()) * sin.atan2(cos)
+    }
+
+    /// Check that the norm differs from one by at most `tolerance`, which holds for
+    /// quaternions representing rotations. `NaN` components fail the check.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// assert!(Quaternion::with_wijk(0.0, 1.0005, 0.0, 0.0).is_normalized(1e-3));
+    /// assert!(!Quaternion::with_wijk(0.0, 1.0005, 0.0, 0.0).is_normalized(1e-4));
+    /// assert!(!Quaternion::with_wijk(f64::NAN, 0.0, 0.0, 0.0).is_normalized(1.0));
+    /// ```
+    pub fn is_normalized(self, tolerance: T) -> bool {
+        let norm: T = Norm::norm(self);
+        norm - T::one() <= tolerance && T::one() - norm <= tolerance
+    }
+
+    /// Create unit quaternion from externally supplied components, normalizing them. Fails
+    /// with [`ValidationError`] instead of producing `NaN` or silently falling back to
+    /// identity.
+    /// ```
+    /// # use spatial::error::ValidationError;
+    /// # use spatial::quaternion::Quaternion;
+    /// let parsed = Quaternion::try_from_components(0.0_f64, 0.0, 0.0, 2.0);
+    /// assert_eq!(parsed, Ok(Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0)));
+    /// let zero = Quaternion::try_from_components(0.0_f64, 0.0, 0.0, 0.0);
+    /// assert_eq!(zero, Err(ValidationError::ZeroNorm { norm: 0.0 }));
+    /// ```
+    pub fn try_from_components(w: T, i: T, j: T, k: T) -> Result<Self, ValidationError<T>> {
+        if let Some(component) = [w, i, j, k].into_iter().position(|value| !is_finite(value)) {
+            return Err(ValidationError::NonFinite { component });
+        }
+        let norm: T = Norm::norm(Self::with_wijk(w, i, j, k));
+        if norm < T::epsilon() {
+            return Err(ValidationError::ZeroNorm { norm });
+        }
+        let scale = T::one() / norm;
+        Ok(Self::with_wijk(w * scale, i * scale, j * scale, k * scale))
+    }
+
+    /// Map three independent samples from the uniform `[0, 1]` distribution to unit quaternion
+    /// of uniformly distributed rotation by the subgroup algorithm of Shoemake, e.g. to
+    /// scatter orientations with any random number generator.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::ops::Norm;
+    /// let rotation = Quaternion::from_uniform([0.3_f64, 0.7, 0.1]);
+    /// assert!((Norm::norm(rotation) - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn from_uniform(samples: [T; 3]) -> Self {
+        let [first, second, third] = samples;
+        let root = |value: T| {
+            if value > T::zero() {
+                value.sqrt()
+            } else {
+                T::zero()
+            }
+        };
+        let (low, high) = (root(T::one() - first), root(first));
+        let turn = (T::one() + T::one()) * (-T::one()).acos();
+        let (a, b) = (turn * second, turn * third);
+        Self::with_wijk(high * b.cos(), low * a.sin(), low * a.cos(), high * b.sin())
+    }
+
+    /// Rotate towards `target` along the shortest arc by at most `max_angle`, reaching the
+    /// target when it is closer. Non-positive `max_angle` keeps this rotation.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let target = Quaternion::from_angle_axis(1.0_f64, Vector::unit_y());
+    /// let step = Quaternion::identity().rotate_towards(target, 0.25);
+    /// assert!(step.angle_to(Quaternion::from_angle_axis(0.25, Vector::unit_y())) < 1e-12);
+    /// assert_eq!(step.rotate_towards(target, 2.0), target);
+    /// ```
+    pub fn rotate_towards<A: IntoAngle<T>>(self, target: Self, max_angle: A) -> Self {
+        let max_angle = max_angle.into_angle();
+        if max_angle <= T::zero() {
+            return self;
+        }
+        let angle = self.angle_to(target);
+        if angle <= max_angle {
+            target
+        } else {
+            self.slerp(target, max_angle / angle)
+        }
+    }
+
+    /// Limit rotation angle relative to identity to `max_angle`, keeping the axis. Rotations
+    /// within the limit are returned unchanged, larger ones are replaced by the shortest
+    /// rotation by `max_angle`, non-positive `max_angle` produces identity.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::from_angle_axis(2.0_f64, Vector::new(1.0, 1.0, 0.0));
+    /// let clamped = rotation.clamp_angle(0.5);
+    /// assert!(clamped.angle_to(Quaternion::from_angle_axis(0.5, Vector::new(1.0, 1.0, 0.0))) < 1e-12);
+    /// assert_eq!(clamped.clamp_angle(1.0), clamped);
+    /// ```
+    pub fn clamp_angle<A: IntoAngle<T>>(self, max_angle: A) -> Self {
+        let max_angle = max_angle.into_angle();
+        if max_angle <= T::zero() {
+            return Self::identity();
+        }
+        let rotation = self.log();
+        let angle = rotation.norm();
+        if angle <= max_angle {
+            self
+        } else {
+            Self::exp(rotation * (max_angle / angle))
+        }
+    }
+
+    /// Mirror rotation across the plane through origin with given non-zero normal, i.e.
+    /// conjugate it by the reflection, producing rotation by the same angle in the opposite
+    /// direction about the reflected axis. Mirroring keeps rotations proper, the handedness
+    /// flip of the reflection is applied on both sides and cancels out.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let left = Quaternion::from_angle_axis(0.5_f64, Vector::new(1.0, 1.0, 0.0));
+    /// let right = left.mirrored(Vector::unit_y());
+    /// let expected = Quaternion::from_angle_axis(-0.5, Vector::new(1.0, -1.0, 0.0));
+    /// assert!(right.angle_to(expected) < 1e-12);
+    /// ```
+    pub fn mirrored(self, plane_normal: Vector<T>) -> Self {
+        let axis = Vector::new(self.i, self.j, self.k).reflect_across(plane_normal);
+        Self::with_wijk(self.w, -axis.x(), -axis.y(), -axis.z())
+    }
+
+    /// Decompose unit quaternion into `(swing, twist)` with `self = swing * twist`, where
+    /// twist rotates about the axis and swing rotates about an axis perpendicular to it.
+    /// Half turn about a perpendicular axis has no twist, `None` for zero axis.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let bend = Quaternion::from_angle_axis(0.6_f64, Vector::unit_x());
+    /// let roll = Quaternion::from_angle_axis(1.1, Vector::unit_z());
+    /// let (swing, twist) = (bend * roll).swing_twist(Vector::unit_z()).unwrap();
+    /// assert!(swing.angle_to(bend) < 1e-12 && twist.angle_to(roll) < 1e-12);
+    /// ```
+    pub fn swing_twist(self, axis: Vector<T>) -> Option<(Self, Self)> {
+        let axis = axis.normalized_checked()?;
+        let projection = axis * axis.dot(Vector::new(self.i, self.j, self.k));
+        let twist = Self::with_wijk(self.w, projection.x(), projection.y(), projection.z())
+            .normalized_checked()
+            .unwrap_or_else(Self::identity);
+        Some((self * twist.inverse(), twist))
+    }
+
+    /// Average rotations, see [`Quaternion::weighted_average`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotations = [0.1_f64, 0.3, 0.5].map(|angle| Quaternion::from_angle_axis(angle, Vector::unit_x()));
+    /// let mean = Quaternion::average([rotations[0], -rotations[1], rotations[2]]).unwrap();
+    /// assert!(mean.angle_to(rotations[1]) < 1e-12);
+    /// assert_eq!(Quaternion::<f64>::average([]), None);
+    /// ```
+    pub fn average<I: IntoIterator<Item = Self>>(rotations: I) -> Option<Self> {
+        Self::weighted_average(rotations.into_iter().map(|rotation| (rotation, T::one())))
+    }
+
+    /// Average rotations with given non-negative weights by summing them flipped into the
+    /// hemisphere of the running sum and normalizing, which approximates the chordal mean
+    /// well for rotations within a quarter turn of each other. Returns `None` if there are no
+    /// rotations or the sum cancels out.
+    pub fn weighted_average<I: IntoIterator<Item = (Self, T)>>(rotations: I) -> Option<Self> {
+        let zero = T::zero();
+        let mut sum = Self::with_wijk(zero, zero, zero, zero);
+        for (rotation, weight) in rotations {
+            sum = if sum.dot(rotation) < zero {
+                sum - rotation * weight
+            } else {
+                sum + rotation * weight
+            };
+        }
+        sum.normalized_checked()
+    }
+
+    /// Spherical quadrangle interpolation between this and other quaternion using inner
+    /// control quaternions `a` and `b`.
+    pub fn squad<P: IntoProgress<T>>(self, a: Self, b: Self, other: Self, progress: P) -> Self {
+        let progress = progress.into_progress();
+        let two = T::one() + T::one();
+        let outer = self.slerp(other, progress);
+        let inner = a.slerp(b, progress);
+        outer.slerp(inner, two * progress * (T::one() - progress))
+    }
+
+    /// Spherical linear interpolation between this and other quaternion, `progress` is either
+    /// plain value or [`Progress`](crate::ops::Progress).
+    pub fn slerp<P: IntoProgress<T>>(self, other: Self, progress: P) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            -other
+        } else {
+            other
+        };
+        self.slerp_arc(other, progress.into_progress())
+    }
+
+    /// Spherical linear interpolation along the arc between the quaternions as given, without
+    /// flipping `other` into the hemisphere of this one. Rotation angle winds the long way
+    /// when the quaternions lie in opposite hemispheres, which keeps interpolation of
+    /// joint rotations beyond half turn consistent. Opposite quaternions have no unique arc
+    /// and produce this quaternion.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let end = Quaternion::from_angle_axis(3.0 * core::f64::consts::FRAC_PI_2, Vector::unit_z());
+    /// let middle = Quaternion::identity().slerp_signed(end, 0.5);
+    /// let (angle, axis) = middle.into_angle_axis();
+    /// assert!((angle - 3.0 * core::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    /// assert!((axis - Vector::unit_z()).norm() < 1e-12);
+    /// ```
+    pub fn slerp_signed<P: IntoProgress<T>>(self, other: Self, progress: P) -> Self {
+        self.slerp_arc(other, progress.into_progress())
+    }
+
+    /// Spherical linear interpolation along the longer of the two arcs representing the
+    /// rotation between the quaternions, the counterpart of [`Quaternion::slerp`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let end = Quaternion::from_angle_axis(core::f64::consts::FRAC_PI_2, Vector::unit_z());
+    /// let middle = Quaternion::identity().slerp_longest(end, 0.5);
+    /// let expected = Quaternion::from_angle_axis(-3.0 * core::f64::consts::FRAC_PI_4, Vector::unit_z());
+    /// assert!((middle.dot(expected).abs() - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn slerp_longest<P: IntoProgress<T>>(self, other: Self, progress: P) -> Self {
+        let other = if self.dot(other) > T::zero() {
+            -other
         } else {
-            (other, dot)
+            other
         };
-        if dot >= T::one() {
+        self.slerp_arc(other, progress.into_progress())
+    }
+
+    /// Interpolate along the great arc from this to other quaternion.
+    fn slerp_arc(self, other: Self, progress: T) -> Self {
+        let dot = self.dot(other);
+        let two = T::one() + T::one();
+        let squared = two * (T::one() - dot);
+        if squared * squared < T::epsilon() {
+            // Truncated series of `sin(p * omega) / sin(omega) = p * (1 + (1 - p^2) * omega^2 / 6)`,
+            // with `omega^2` estimated from `cos(omega) = 1 - omega^2 / 2`.
+            let six = two * (two + T::one());
+            let rest = T::one() - progress;
+            let a = rest * (T::one() + (T::one() - rest * rest) * squared / six);
+            let b = progress * (T::one() + (T::one() - progress * progress) * squared / six);
+            return self * a + other * b;
+        }
+        if dot >= T::one() || dot <= -T::one() {
             return self;
         }
-        let dot = dot.min(T::one());
-        let omega = dot.acos();
+        let omega = clamp_unit(dot).acos();
         let sin_omega = omega.sin();
         let a = ((T::one() - progress) * omega).sin() / sin_omega;
         let b = (progress * omega).sin() / sin_omega;
@@ -166,6 +1170,186 @@ where
     }
 }
 
+/// Quaternion known to have unit norm, so it always represents a rotation.
+///
+/// Constructors either build unit quaternions directly or normalize given quaternion, and
+/// compositions of unit quaternions stay unit up to rounding. Use [`UnitQuaternion::renormalize`]
+/// after long chains of compositions, e.g. every few ones to amortize the square root cost
+/// as [`compose_chain_with`](crate::pose::compose_chain_with) does.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::{Quaternion, UnitQuaternion};
+/// let drifted = Quaternion::with_wijk(0.0, 0.0, 2.0, 0.0);
+/// let rotation = UnitQuaternion::new_normalize(drifted).unwrap();
+/// assert_eq!(rotation.rotate(Vector::new(1.0, 0.0, 0.0)), Vector::new(-1.0, 0.0, 0.0));
+/// assert!(UnitQuaternion::new_normalize(Quaternion::with_wijk(0.0, 0.0, 0.0, 0.0)).is_none());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+pub struct UnitQuaternion<T>(Quaternion<T>);
+
+impl<T> UnitQuaternion<T> {
+    /// Wrap quaternion without checking its norm, the caller guarantees it is unit.
+    pub const fn new_unchecked(quaternion: Quaternion<T>) -> Self {
+        Self(quaternion)
+    }
+
+    /// Get reference to the wrapped quaternion.
+    pub const fn quaternion_ref(&self) -> &Quaternion<T> {
+        &self.0
+    }
+
+    /// Unwrap the quaternion.
+    pub fn into_inner(self) -> Quaternion<T> {
+        self.0
+    }
+}
+
+impl<T: Copy> UnitQuaternion<T> {
+    /// Get the wrapped quaternion.
+    pub fn quaternion(&self) -> Quaternion<T> {
+        self.0
+    }
+}
+
+impl<T> UnitQuaternion<T>
+where
+    T: Copy
+        + PartialOrd
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sqrt<Output = T>
+        + Epsilon,
+{
+    /// Normalize quaternion, `None` if its norm is below epsilon.
+    pub fn new_normalize(quaternion: Quaternion<T>) -> Option<Self> {
+        quaternion.normalized_checked().map(Self)
+    }
+
+    /// Scale back to unit norm to remove rounding drift.
+    pub fn renormalize(self) -> Self {
+        Self::new_normalize(self.0).unwrap_or(self)
+    }
+}
+
+impl<T> UnitQuaternion<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Neg<Output = T>,
+{
+    /// Get opposite rotation, which is the conjugate for unit quaternions.
+    pub fn inverse(self) -> Self {
+        Self(self.0.inverse())
+    }
+
+    /// Rotate vector, see [`Quaternion::rotate`].
+    pub fn rotate(self, vector: Vector<T>) -> Vector<T> {
+        self.0.rotate(vector)
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T: Real> UnitQuaternion<T> {
+    /// Unit quaternion representing no rotation.
+    pub fn identity() -> Self {
+        Self(Quaternion::identity())
+    }
+
+    /// Create rotation around the axis, zero axis produces identity.
+    pub fn from_angle_axis<A: IntoAngle<T>>(angle: A, axis: Vector<T>) -> Self {
+        Self(Quaternion::from_angle_axis(angle, axis))
+    }
+
+    /// Create rotation from rotation vector, see [`Quaternion::exp`].
+    pub fn exp(rotation: Vector<T>) -> Self {
+        Self(Quaternion::exp(rotation))
+    }
+
+    /// Raise rotation to given power, see [`Quaternion::powf`].
+    pub fn powf(self, exponent: T) -> Self {
+        Self(self.0.powf(exponent))
+    }
+
+    /// Create rotation from orthonormal matrix, see [`Quaternion::from_rotation_matrix`].
+    pub fn from_rotation_matrix(matrix: [[T; 3]; 3]) -> Self {
+        Self(Quaternion::from_rotation_matrix(matrix))
+    }
+
+    /// Interpolate along the shortest arc, see [`Quaternion::slerp`].
+    pub fn slerp<P: IntoProgress<T>>(self, other: Self, progress: P) -> Self {
+        Self(self.0.slerp(other.0, progress))
+    }
+}
+
+impl<T> Mul for UnitQuaternion<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        Self(self.0 * other.0)
+    }
+}
+
+impl<T> From<UnitQuaternion<T>> for Quaternion<T> {
+    fn from(rotation: UnitQuaternion<T>) -> Self {
+        rotation.0
+    }
+}
+
+impl<T> AsRef<Quaternion<T>> for UnitQuaternion<T> {
+    fn as_ref(&self) -> &Quaternion<T> {
+        &self.0
+    }
+}
+
+/// Clamp value to the `[-1, 1]` range accepted by `acos`.
+#[cfg(feature = "num")]
+fn clamp_unit<T>(value: T) -> T
+where
+    T: PartialOrd + One + Neg<Output = T>,
+{
+    if value > T::one() {
+        T::one()
+    } else if value < -T::one() {
+        -T::one()
+    } else {
+        value
+    }
+}
+
+/// Implement dimension-checked angle-axis conversions for given storage type.
+#[cfg(all(feature = "num", feature = "uom"))]
+macro_rules! impl_uom_angle_axis {
+    ($($storage:ident),+) => {$(
+        impl Quaternion<$storage> {
+            /// Create rotation by dimension-checked angle around given axis.
+            pub fn from_angle_axis_uom(angle: uom::si::$storage::Angle, axis: Vector<$storage>) -> Self {
+                Self::from_angle_axis(angle, axis)
+            }
+
+            /// Convert rotation into dimension-checked angle and axis,
+            /// see [`Self::into_angle_axis`].
+            pub fn into_angle_axis_uom(self) -> (uom::si::$storage::Angle, Vector<$storage>) {
+                let (angle, axis) = self.into_angle_axis();
+                (uom::si::$storage::Angle::new::<radian>(angle), axis)
+            }
+
+            /// Convert rotation into dimension-checked angle and axis,
+            /// see [`Self::into_angle_axis_checked`].
+            pub fn into_angle_axis_uom_checked(
+                self,
+            ) -> Option<(uom::si::$storage::Angle, Vector<$storage>)> {
+                self.into_angle_axis_checked()
+                    .map(|(angle, axis)| (uom::si::$storage::Angle::new::<radian>(angle), axis))
+            }
+        }
+    )+};
+}
+
+#[cfg(all(feature = "num", feature = "uom"))]
+impl_uom_angle_axis!(f32, f64);
+
 impl<T> Quaternion<T>
 where
     T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Copy,
@@ -184,6 +1368,10 @@ where
 }
 
 impl<T> Quaternion<T> {
+    /// Rotate vector as `|q|²v + 2w(u × v) + 2u × (u × v)`, where `u` is the vector part of
+    /// the quaternion. This equals the full sandwich product `q v q*` and needs about half of
+    /// its multiplications, the squared norm scaling keeps `U` and `R` unrelated types.
+    #[inline]
     pub fn rotate<U, R>(self, vector: Vector<U>) -> Vector<R>
     where
         T: Copy
@@ -197,13 +1385,48 @@ impl<T> Quaternion<T> {
     {
         let (w, i, j, k) = (self.w, self.i, self.j, self.k);
         let (x, y, z) = (vector.x(), vector.y(), vector.z());
-        let prep_x = i * j * y + i * k * z + j * w * z - k * w * y;
-        let result_x = prep_x + prep_x + (i * i - j * j - k * k + w * w) * x;
-        let prep_y = i * j * x - i * w * z + j * k * z + k * w * x;
-        let result_y = prep_y + prep_y + (j * j - i * i - k * k + w * w) * y;
-        let prep_z = i * k * x + i * w * y + j * k * y - j * w * x;
-        let result_z = prep_z + prep_z + (w * w - i * i - j * j + k * k) * z;
-        Vector::new(result_x, result_y, result_z)
+        let norm = w * w + i * i + j * j + k * k;
+        let (cross_x, cross_y, cross_z) = (j * z - k * y, k * x - i * z, i * y - j * x);
+        let (x2, y2, z2) = (cross_x + cross_x, cross_y + cross_y, cross_z + cross_z);
+        Vector::new(
+            norm * x + (w * x2 + (j * z2 - k * y2)),
+            norm * y + (w * y2 + (k * x2 - i * z2)),
+            norm * z + (w * z2 + (i * y2 - j * x2)),
+        )
+    }
+
+    /// Rotate vector by the inverse rotation, equals `self.inverse().rotate(vector)` without
+    /// negating the quaternion components first.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5);
+    /// let vector = Vector::new(1.0, 2.0, 3.0);
+    /// assert_eq!(rotation.rotate_inverse(vector), rotation.inverse().rotate(vector));
+    /// assert_eq!(rotation.rotate_inverse(rotation * vector), vector);
+    /// ```
+    #[inline]
+    pub fn rotate_inverse<U, R>(self, vector: Vector<U>) -> Vector<R>
+    where
+        T: Copy
+            + Mul<U, Output = R>
+            + Mul<R, Output = R>
+            + Mul<Output = T>
+            + Add<Output = T>
+            + Sub<Output = T>,
+        U: Copy,
+        R: Copy + Add<Output = R> + Sub<Output = R>,
+    {
+        let (w, i, j, k) = (self.w, self.i, self.j, self.k);
+        let (x, y, z) = (vector.x(), vector.y(), vector.z());
+        let norm = w * w + i * i + j * j + k * k;
+        let (cross_x, cross_y, cross_z) = (j * z - k * y, k * x - i * z, i * y - j * x);
+        let (x2, y2, z2) = (cross_x + cross_x, cross_y + cross_y, cross_z + cross_z);
+        Vector::new(
+            norm * x + ((j * z2 - k * y2) - w * x2),
+            norm * y + ((k * x2 - i * z2) - w * y2),
+            norm * z + ((i * y2 - j * x2) - w * z2),
+        )
     }
 
     pub fn inverse(self) -> Self
@@ -217,6 +1440,249 @@ impl<T> Quaternion<T> {
             k: -self.k,
         }
     }
+
+    /// Rotate every vector of the slice in place, see [`Quaternion::prepare`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0);
+    /// let mut points = [Vector::new(1.0, 2.0, 3.0); 20];
+    /// rotation.transform_in_place(&mut points);
+    /// assert!(points.iter().all(|point| *point == Vector::new(-1.0, -2.0, 3.0)));
+    /// ```
+    pub fn transform_in_place(&self, vectors: &mut [Vector<T>])
+    where
+        T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.prepare().rotate_in_place(vectors);
+    }
+
+    /// Rotate every vector of the slice in place, the batch counterpart of
+    /// [`Quaternion::rotate`], see [`Quaternion::transform_in_place`].
+    pub fn rotate_slice(&self, vectors: &mut [Vector<T>])
+    where
+        T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.transform_in_place(vectors);
+    }
+
+    /// Precompute rotation matrix for repeated application to many vectors.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// let rotation = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5);
+    /// let prepared = rotation.prepare();
+    /// let vector = Vector::new(1.0, 2.0, 3.0);
+    /// assert_eq!(prepared.rotate(vector), rotation.rotate(vector));
+    /// ```
+    pub fn prepare(self) -> PreparedRotation<T>
+    where
+        T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        let (w, i, j, k) = (self.w, self.i, self.j, self.k);
+        let double = |value: T| value + value;
+        let matrix = [
+            [
+                w * w + i * i - j * j - k * k,
+                double(i * j - w * k),
+                double(i * k + w * j),
+            ],
+            [
+                double(i * j + w * k),
+                w * w - i * i + j * j - k * k,
+                double(j * k - w * i),
+            ],
+            [
+                double(i * k - w * j),
+                double(j * k + w * i),
+                w * w - i * i - j * j + k * k,
+            ],
+        ];
+        PreparedRotation { matrix }
+    }
+
+    /// Convert quaternion into rotation matrix stored rows first, see
+    /// [`Quaternion::from_rotation_matrix`]. Non-unit quaternions produce scaled matrices.
+    pub fn into_rotation_matrix(self) -> [[T; 3]; 3]
+    where
+        T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+    {
+        self.prepare().matrix()
+    }
+}
+
+impl<T> Quaternion<T> {
+    /// Multiply quaternions borrowing both of them, for scalar types which are not `Copy`.
+    pub fn multiply_ref(&self, other: &Self) -> Self
+    where
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+        T: Add<Output = T> + Sub<Output = T>,
+    {
+        let (a, b) = (self, other);
+        Self {
+            w: &a.w * &b.w - &a.i * &b.i - &a.j * &b.j - &a.k * &b.k,
+            i: &a.w * &b.i + &a.i * &b.w + &a.j * &b.k - &a.k * &b.j,
+            j: &a.w * &b.j - &a.i * &b.k + &a.j * &b.w + &a.k * &b.i,
+            k: &a.w * &b.k + &a.i * &b.j - &a.j * &b.i + &a.k * &b.w,
+        }
+    }
+
+    /// Calculate dot product borrowing both quaternions, for scalar types which are not `Copy`.
+    pub fn dot_ref(&self, other: &Self) -> T
+    where
+        for<'a> &'a T: Mul<&'a T, Output = T>,
+        T: Add<Output = T>,
+    {
+        &self.w * &other.w + &self.i * &other.i + &self.j * &other.j + &self.k * &other.k
+    }
+
+    /// Rotate vector borrowing it, for scalar types which are not `Copy`,
+    /// see [`Quaternion::rotate`].
+    pub fn rotate_ref<U, R>(&self, vector: &Vector<U>) -> Vector<R>
+    where
+        for<'a> &'a T: Mul<&'a T, Output = T> + Mul<&'a U, Output = R> + Mul<&'a R, Output = R>,
+        T: Add<Output = T>,
+        R: Clone + Add<Output = R> + Sub<Output = R>,
+    {
+        let (w, i, j, k) = (&self.w, &self.i, &self.j, &self.k);
+        let (x, y, z) = (vector.x_ref(), vector.y_ref(), vector.z_ref());
+        let norm = w * w + i * i + j * j + k * k;
+        let twice = |value: R| value.clone() + value;
+        let x2 = twice(j * z - k * y);
+        let y2 = twice(k * x - i * z);
+        let z2 = twice(i * y - j * x);
+        Vector::new(
+            &norm * x + (w * &x2 + (j * &z2 - k * &y2)),
+            &norm * y + (w * &y2 + (k * &x2 - i * &z2)),
+            &norm * z + (w * &z2 + (i * &y2 - j * &x2)),
+        )
+    }
+}
+
+/// Rotation matrix precomputed from quaternion by [`Quaternion::prepare`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PreparedRotation<T> {
+    matrix: [[T; 3]; 3],
+}
+
+impl<T: Copy> PreparedRotation<T> {
+    /// Get rotation matrix, rows first.
+    pub fn matrix(&self) -> [[T; 3]; 3] {
+        self.matrix
+    }
+
+    /// Rotate every vector of the slice in place with the precomputed matrix.
+    pub fn rotate_in_place(&self, vectors: &mut [Vector<T>])
+    where
+        T: Mul<Output = T> + Add<Output = T>,
+    {
+        for vector in vectors {
+            *vector = self.rotate(*vector);
+        }
+    }
+
+    /// Rotate vector, same as [`Quaternion::rotate`] with the source quaternion.
+    pub fn rotate<U, R>(&self, vector: Vector<U>) -> Vector<R>
+    where
+        T: Mul<U, Output = R>,
+        U: Copy,
+        R: Add<Output = R>,
+    {
+        let (x, y, z) = (vector.x(), vector.y(), vector.z());
+        let [row_x, row_y, row_z] = self.matrix;
+        Vector::new(
+            row_x[0] * x + row_x[1] * y + row_x[2] * z,
+            row_y[0] * x + row_y[1] * y + row_y[2] * z,
+            row_z[0] * x + row_z[1] * y + row_z[2] * z,
+        )
+    }
+}
+
+#[cfg(feature = "simd")]
+impl Quaternion<f32> {
+    /// Rotate vector by unit quaternion using `f32x4` lanes, see the `simd` feature.
+    pub fn rotate_simd(self, vector: Vector<f32>) -> Vector<f32> {
+        crate::simd::rotate(self, vector)
+    }
+
+    /// Multiply quaternions using `f32x4` lanes, see the `simd` feature.
+    pub fn multiply_simd(self, other: Self) -> Self {
+        crate::simd::multiply(self, other)
+    }
+}
+
+#[cfg(feature = "fast-math")]
+impl Quaternion<f32> {
+    /// Normalize quaternion using approximate inverse square root, see the `fast-math` feature.
+    /// Quaternion must have non-zero finite norm.
+    pub fn normalized_fast(self) -> Self {
+        self * crate::fast_math::inverse_sqrt(self.dot(self))
+    }
+}
+
+impl<T> Quaternion<T> {
+    /// Calculate Euclidean norm of the four components, unit quaternions represent rotations.
+    pub fn norm<I, R>(self) -> R
+    where
+        T: Copy + Mul<Output = I>,
+        I: Sqrt<Output = R> + Add<Output = I>,
+    {
+        (self.w * self.w + self.i * self.i + self.j * self.j + self.k * self.k).sqrt()
+    }
+
+    /// Scale quaternion to unit norm, zero quaternion produces non-finite components.
+    pub fn normalized_unchecked<I, U, R>(self) -> Quaternion<R>
+    where
+        T: Copy + Mul<Output = I> + Div<U, Output = R>,
+        I: Sqrt<Output = U> + Add<Output = I>,
+        U: Copy,
+    {
+        let norm = self.norm();
+        Quaternion {
+            w: self.w / norm,
+            i: self.i / norm,
+            j: self.j / norm,
+            k: self.k / norm,
+        }
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy
+        + PartialOrd
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sqrt<Output = T>
+        + Epsilon,
+{
+    /// Scale quaternion to unit norm, `None` if the norm is below epsilon.
+    /// ```
+    /// # use spatial::quaternion::Quaternion;
+    /// let drifted = Quaternion::with_wijk(0.0, 0.0, 0.0, 1.001);
+    /// assert_eq!(drifted.normalized_checked(), Some(Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0)));
+    /// assert!(Quaternion::with_wijk(0.0, 0.0, 0.0, 0.0).normalized_checked().is_none());
+    /// ```
+    pub fn normalized_checked(self) -> Option<Self> {
+        let norm = self.norm();
+        if norm < T::epsilon() {
+            None
+        } else {
+            Some(self.normalized_unchecked())
+        }
+    }
+}
+
+impl<T> Norm for Quaternion<T>
+where
+    T: Copy + Mul,
+    T::Output: Sqrt + Add<Output = T::Output>,
+{
+    type Output = <T::Output as Sqrt>::Output;
+    fn norm(self) -> Self::Output {
+        Quaternion::norm(self)
+    }
 }
 
 impl<T> Add for Quaternion<T>
@@ -234,6 +1700,45 @@ where
     }
 }
 
+impl<T> Sub for Quaternion<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            w: self.w - other.w,
+            i: self.i - other.i,
+            j: self.j - other.j,
+            k: self.k - other.k,
+        }
+    }
+}
+
+impl<T> AddAssign for Quaternion<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.w += other.w;
+        self.i += other.i;
+        self.j += other.j;
+        self.k += other.k;
+    }
+}
+
+impl<T> SubAssign for Quaternion<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.w -= other.w;
+        self.i -= other.i;
+        self.j -= other.j;
+        self.k -= other.k;
+    }
+}
+
 impl<T> Neg for Quaternion<T>
 where
     T: Neg<Output = T>,
@@ -273,3 +1778,637 @@ where
         }
     }
 }
+
+/// Apply other rotation after this one, in its local frame.
+impl<T> MulAssign<Quaternion<T>> for Quaternion<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Copy,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.multiply(other);
+    }
+}
+
+impl<T> MulAssign<T> for Quaternion<T>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, scalar: T) {
+        self.w *= scalar;
+        self.i *= scalar;
+        self.j *= scalar;
+        self.k *= scalar;
+    }
+}
+
+impl<T> Div<T> for Quaternion<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Self;
+    fn div(self, scalar: T) -> Self::Output {
+        Quaternion {
+            w: self.w / scalar,
+            i: self.i / scalar,
+            j: self.j / scalar,
+            k: self.k / scalar,
+        }
+    }
+}
+
+impl<T> DivAssign<T> for Quaternion<T>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, scalar: T) {
+        self.w /= scalar;
+        self.i /= scalar;
+        self.j /= scalar;
+        self.k /= scalar;
+    }
+}
+
+/// Implement rotation of vectors by multiplication and scalar multiplication from the left
+/// for given floating point type. Generic rotation would overlap with scalar multiplication
+/// by `T = Vector<U>` and generic left multiplication is not allowed for foreign types.
+/// Rotation accumulates products with fused multiply-add under the `fma` feature.
+macro_rules! impl_quaternion_rotate_operator {
+    ($($float:ty),+) => {$(
+        impl Mul<Vector<$float>> for Quaternion<$float> {
+            type Output = Vector<$float>;
+            fn mul(self, other: Vector<$float>) -> Self::Output {
+                #[cfg(feature = "fma")]
+                {
+                    // Same formula as `rotate` with every product accumulated by `mul_add`.
+                    let fused = |x: $float, y: $float, z: $float| num::Float::mul_add(x, y, z);
+                    let (w, i, j, k) = (self.w, self.i, self.j, self.k);
+                    let (x, y, z) = (other.x(), other.y(), other.z());
+                    let norm = fused(w, w, fused(i, i, fused(j, j, k * k)));
+                    let x2 = 2.0 * fused(j, z, -(k * y));
+                    let y2 = 2.0 * fused(k, x, -(i * z));
+                    let z2 = 2.0 * fused(i, y, -(j * x));
+                    Vector::new(
+                        fused(norm, x, fused(w, x2, fused(j, z2, -(k * y2)))),
+                        fused(norm, y, fused(w, y2, fused(k, x2, -(i * z2)))),
+                        fused(norm, z, fused(w, z2, fused(i, y2, -(j * x2)))),
+                    )
+                }
+                #[cfg(not(feature = "fma"))]
+                self.rotate(other)
+            }
+        }
+
+        impl Mul<Quaternion<$float>> for $float {
+            type Output = Quaternion<$float>;
+            fn mul(self, quaternion: Quaternion<$float>) -> Self::Output {
+                quaternion * self
+            }
+        }
+    )+};
+}
+
+impl_quaternion_rotate_operator!(f32, f64);
+
+/// Format as `(w; i, j, k)`, formatter flags such as precision apply to every component.
+/// ```
+/// # use spatial::quaternion::Quaternion;
+/// assert_eq!(format!("{:.1}", Quaternion::with_wijk(1.0, 0.0, -0.25, 0.5)), "(1.0; 0.0, -0.2, 0.5)");
+/// ```
+impl<T: Display> Display for Quaternion<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("(")?;
+        self.w.fmt(formatter)?;
+        formatter.write_str("; ")?;
+        self.i.fmt(formatter)?;
+        formatter.write_str(", ")?;
+        self.j.fmt(formatter)?;
+        formatter.write_str(", ")?;
+        self.k.fmt(formatter)?;
+        formatter.write_str(")")
+    }
+}
+
+impl<T> From<[T; 4]> for Quaternion<T> {
+    fn from([w, i, j, k]: [T; 4]) -> Self {
+        Self { w, i, j, k }
+    }
+}
+
+impl<T> From<Quaternion<T>> for [T; 4] {
+    fn from(quaternion: Quaternion<T>) -> Self {
+        [quaternion.w, quaternion.i, quaternion.j, quaternion.k]
+    }
+}
+
+/// Convert slice of `w`, `i`, `j` and `k` components.
+impl<T: Copy> TryFrom<&[T]> for Quaternion<T> {
+    type Error = ConversionError;
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        to_array::<T, 4>(slice).map(Self::from)
+    }
+}
+
+/// Access components by index in `w, i, j, k` order, panics if the index is out of bounds.
+/// ```
+/// # use spatial::quaternion::Quaternion;
+/// let mut quaternion = Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0);
+/// quaternion[3] = 2.0;
+/// assert_eq!(quaternion.k(), 2.0);
+/// assert_eq!((&quaternion).into_iter().copied().sum::<f64>(), 3.0);
+/// ```
+impl<T> Index<usize> for Quaternion<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_array()[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Quaternion<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut_array()[index]
+    }
+}
+
+/// Iterate over components in `w, i, j, k` order.
+impl<T> IntoIterator for Quaternion<T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, 4>;
+    fn into_iter(self) -> Self::IntoIter {
+        <[T; 4]>::from(self).into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Quaternion<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_array().iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Quaternion<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_array().iter_mut()
+    }
+}
+
+/// Build cross product matrix `[vector]x` with `[vector]x * other = vector x other`.
+#[cfg(feature = "num")]
+pub(crate) fn skew<T>(vector: Vector<T>) -> [[T; 3]; 3]
+where
+    T: Copy + Zero + Neg<Output = T>,
+{
+    let zero = T::zero();
+    [
+        [zero, -vector.z(), vector.y()],
+        [vector.z(), zero, -vector.x()],
+        [-vector.y(), vector.x(), zero],
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    /// Scalar which is `Clone` but not `Copy`.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Scalar(f64);
+
+    impl core::ops::Add for Scalar {
+        type Output = Self;
+        fn add(self, other: Self) -> Self {
+            Scalar(self.0 + other.0)
+        }
+    }
+
+    impl core::ops::Sub for Scalar {
+        type Output = Self;
+        fn sub(self, other: Self) -> Self {
+            Scalar(self.0 - other.0)
+        }
+    }
+
+    impl core::ops::Mul for &Scalar {
+        type Output = Scalar;
+        fn mul(self, other: Self) -> Scalar {
+            Scalar(self.0 * other.0)
+        }
+    }
+
+    #[test]
+    fn reference_operations_accept_non_copy_scalars() {
+        use super::Quaternion;
+        use crate::pose::Pose;
+        use crate::vector::Vector;
+        let wrap = |rotation: Quaternion<f64>| {
+            Quaternion::with_wijk(
+                Scalar(rotation.w()),
+                Scalar(rotation.i()),
+                Scalar(rotation.j()),
+                Scalar(rotation.k()),
+            )
+        };
+        let rotation = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5);
+        let other = Quaternion::with_wijk(0.0, 0.6, 0.0, 0.8);
+        let vector = Vector::new(1.0, -2.0, 3.0);
+        let wrapped = Pose::new(vector.map(Scalar), wrap(rotation));
+        let combined = wrapped.combine_ref(&Pose::new(vector.map(Scalar), wrap(other)));
+        let expected = Pose::new(vector, rotation).combine(Pose::new(vector, other));
+        assert_eq!(
+            *combined.translation_ref(),
+            expected.translation().map(Scalar)
+        );
+        assert_eq!(*combined.rotation_ref(), wrap(expected.rotation()));
+        assert_eq!(
+            wrap(rotation).dot_ref(&wrap(other)),
+            Scalar(rotation.dot(other))
+        );
+        let vector = vector.map(Scalar);
+        assert_eq!(vector.dot_ref(&vector), Scalar(14.0));
+    }
+
+    #[cfg(feature = "fma")]
+    #[test]
+    fn fused_operations_match_plain_ones() {
+        use super::Quaternion;
+        use crate::ops::Norm;
+        use crate::vector::Vector;
+        let rotation = Quaternion::with_wijk(0.5_f64, -0.1, 0.7, 0.3);
+        let vector = Vector::new(1.0, -2.0, 3.0);
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+        let error = rotation * vector - rotation.rotate(vector);
+        assert!(close(error.dot(error), 0.0));
+        let fused = <[f64; 4]>::from(rotation.normalize());
+        let plain = <[f64; 4]>::from(rotation * (1.0 / Norm::norm(rotation)));
+        assert!(fused.into_iter().zip(plain).all(|(a, b)| close(a, b)));
+        assert!(close(vector.length(), 14.0_f64.sqrt()));
+    }
+
+    #[test]
+    fn rotation_matches_sandwich_product() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let rotation = Quaternion::with_wijk(1.5, -0.5, 2.0, 0.25);
+        let vector = Vector::new(1.0, -2.0, 3.0);
+        let pure = Quaternion::with_wijk(0.0, vector.x(), vector.y(), vector.z());
+        let product = rotation * pure * rotation.inverse();
+        let expected = Vector::new(product.i(), product.j(), product.k());
+        let error = rotation.rotate(vector) - expected;
+        assert!(error.dot(error) < 1e-24);
+        assert_eq!(rotation.prepare().rotate(vector), expected);
+    }
+
+    #[test]
+    fn gimbal_lock_angles_reproduce_rotation() {
+        use super::Quatd;
+        use core::f64::consts::FRAC_PI_2;
+        for pitch in [FRAC_PI_2, -FRAC_PI_2, FRAC_PI_2 - 1e-4, 1e-4 - FRAC_PI_2] {
+            let rotation = Quatd::from_rpy(2.5, pitch, -2.0);
+            let warning = rotation.into_rpy_checked().unwrap_err();
+            assert!(warning.roll == 0.0 && (warning.pitch - pitch).abs() < 1e-6);
+            let locked = Quatd::from_rpy(warning.roll, warning.pitch, warning.yaw);
+            assert!(locked.dot(rotation).abs() > 1.0 - 1e-8);
+        }
+        let (roll, pitch, yaw) = Quatd::from_rpy(0.1, 1.5, 0.2).into_rpy_checked().unwrap();
+        assert!(
+            (roll - 0.1).abs() < 1e-9 && (pitch - 1.5).abs() < 1e-9 && (yaw - 0.2).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn euler_angles_round_trip_in_all_conventions() {
+        use super::{EulerOrder, EulerSequence, Quatd, Quaternion};
+        use core::f64::consts::PI;
+        for sequence in EulerSequence::ALL {
+            let [first, _, last] = sequence.axes();
+            let offset = if first == last { PI / 2.0 } else { 0.0 };
+            for order in [
+                EulerOrder::Intrinsic(sequence),
+                EulerOrder::Extrinsic(sequence),
+            ] {
+                for (a, b, c) in [(0.3, 0.7, -2.9), (-3.0, 1.3, 1.1), (1.7, -0.2, 0.05)] {
+                    let rotation = Quatd::from_euler(order, a, b + offset, c);
+                    let euler = rotation.into_euler(order);
+                    let expected = [a, b + offset, c];
+                    assert!(euler
+                        .angles
+                        .iter()
+                        .zip(expected)
+                        .all(|(actual, expected)| (actual - expected).abs() < 1e-9));
+                    assert!((Quaternion::from(euler).dot(rotation) - 1.0).abs() < 1e-12);
+                }
+                for lock in [-PI / 2.0, PI / 2.0] {
+                    let rotation = Quatd::from_euler(order, 0.4, lock + offset, -1.3);
+                    let euler = rotation.into_euler(order);
+                    assert_eq!(euler.angles[2], 0.0);
+                    assert!((Quaternion::from(euler).dot(rotation).abs() - 1.0).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn small_angle_series_match_exact_formulas() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let axis = Vector::new(1.0_f64, -2.0, 2.0) / 3.0;
+        for angle in [1e-12_f64, 1e-6, 1e-4, 1.3e-4, 1e-2] {
+            let half = angle / 2.0;
+            let expected = Quaternion::with_wijk(
+                half.cos(),
+                axis.x() * half.sin(),
+                axis.y() * half.sin(),
+                axis.z() * half.sin(),
+            );
+            let close = |rotation: Quaternion<f64>| {
+                <[f64; 4]>::from(rotation)
+                    .into_iter()
+                    .zip(<[f64; 4]>::from(expected))
+                    .all(|(a, b)| (a - b).abs() <= 1e-15 * b.abs())
+            };
+            assert!(close(Quaternion::from_angle_axis(angle, axis)));
+            assert!(close(Quaternion::exp(axis * angle)));
+            let target = Quaternion::from_angle_axis(angle / 0.3, axis);
+            assert!(close(Quaternion::identity().slerp(target, 0.3)));
+            let error = expected.log() - axis * angle;
+            assert!(error.norm() <= 1e-15 * angle);
+        }
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn rotation_matrix_round_trip_covers_half_turns() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let diagonal = Vector::new(1.0_f64, 1.0, 0.0);
+        let rotations = [
+            Quaternion::identity(),
+            Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0),
+            Quaternion::with_wijk(0.0, 0.0, 1.0, 0.0),
+            Quaternion::with_wijk(0.0, 0.0, 0.0, 1.0),
+            Quaternion::from_angle_axis(core::f64::consts::PI, diagonal),
+            Quaternion::from_angle_axis(core::f64::consts::PI - 1e-9, Vector::new(1.0, -2.0, 3.0)),
+            Quaternion::from_angle_axis(2.0, Vector::new(-0.3, 0.2, 0.9)),
+            Quaternion::from_angle_axis(1e-9, Vector::new(0.0, 1.0, 1.0)),
+        ];
+        for rotation in rotations {
+            let matrix = rotation.into_rotation_matrix();
+            let restored = Quaternion::try_from_rotation_matrix(matrix, 1e-12).unwrap();
+            assert!((restored.dot(rotation).abs() - 1.0).abs() < 1e-12);
+            for axis in [Vector::<f64>::unit_x(), Vector::unit_y(), Vector::unit_z()] {
+                let column = Vector::from(
+                    matrix.map(|row| row[0] * axis.x() + row[1] * axis.y() + row[2] * axis.z()),
+                );
+                assert!((column - rotation.rotate(axis)).norm() < 1e-12);
+            }
+        }
+        let scaled = [[2.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 2.0]];
+        assert!(Quaternion::try_from_rotation_matrix(scaled, 1e-9).is_err());
+        let sheared = [[1.0, 0.1, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        assert!(Quaternion::try_from_rotation_matrix(sheared, 1e-9).is_err());
+    }
+
+    #[test]
+    fn assignment_and_left_scalar_operators_match_binary_operators() {
+        use super::Quaternion;
+        use crate::twist::Twist;
+        use crate::vector::Vector;
+        use crate::wrench::Wrench;
+        let (a, b) = (Vector::new(1.0, -2.0, 0.5), Vector::new(0.25, 4.0, -1.0));
+        let mut vector = a;
+        vector += b;
+        vector *= 2.0;
+        vector -= a;
+        vector /= 4.0;
+        assert_eq!(vector, (2.0 * (a + b) - a) / 4.0);
+        let p = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5);
+        let q = Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0);
+        let mut quaternion = p;
+        quaternion *= q;
+        assert_eq!(quaternion, p * q);
+        quaternion += p;
+        quaternion -= q;
+        quaternion *= 3.0;
+        quaternion /= 2.0;
+        assert_eq!(quaternion, 3.0 * (p * q + p - q) / 2.0);
+        assert_eq!(p * b, p.rotate(b));
+        let mut twist = Twist::new(a, b);
+        twist += Twist::new(b, a);
+        twist *= 0.5;
+        assert_eq!(twist, 0.5 * Twist::new(a + b, b + a));
+        let mut wrench = Wrench::new(a, b);
+        wrench -= Wrench::new(b, b);
+        wrench /= 2.0;
+        assert_eq!(wrench, Wrench::new(a - b, b - b) / 2.0);
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn rotation_between_handles_parallel_and_antiparallel_vectors() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let directions = [
+            Vector::new(1.0_f64, 0.0, 0.0),
+            Vector::new(0.0, -3.0, 0.0),
+            Vector::new(0.0, 0.0, 0.5),
+            Vector::new(1.0, 2.0, -2.0),
+            Vector::new(-1e-3, 1.0, 1e-3),
+        ];
+        for from in directions {
+            let unit = from.normalized_checked().unwrap();
+            for to in directions.into_iter().flat_map(|to| [to, -to, from, -from]) {
+                let rotation = Quaternion::rotation_between(from, to).unwrap();
+                assert!((rotation.dot(rotation) - 1.0).abs() < 1e-12);
+                let expected = to.normalized_checked().unwrap();
+                assert!((rotation.rotate(unit) - expected).norm() < 1e-12);
+                let angle = unit.dot(expected).clamp(-1.0, 1.0).acos();
+                assert!((Quaternion::identity().angle_to(rotation) - angle).abs() < 1e-7);
+            }
+        }
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn unit_quaternion_stays_unit_through_compositions() {
+        use super::{Quaternion, UnitQuaternion};
+        use crate::ops::Norm;
+        use crate::pose::Pose;
+        use crate::vector::Vector;
+        let step = UnitQuaternion::from_angle_axis(0.001_f32, Vector::new(1.0, 2.0, 3.0));
+        let mut rotation = UnitQuaternion::identity();
+        for _ in 0..100_000 {
+            rotation = rotation * step;
+        }
+        let drift = (Norm::norm(rotation.quaternion()) - 1.0).abs();
+        let rotation = rotation.renormalize();
+        assert!((Norm::norm(rotation.quaternion()) - 1.0).abs() <= drift);
+        assert!((Norm::norm(rotation.quaternion()) - 1.0).abs() < 1e-6);
+        let point = Vector::new(0.5, -1.0, 2.0);
+        let restored = rotation.inverse().rotate(rotation.rotate(point));
+        assert!((restored - point).norm() < 1e-5);
+        let pose = Pose::from_unit(Vector::new(1.0, 0.0, 0.0), rotation);
+        assert_eq!(pose.rotation(), Quaternion::from(rotation));
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn angle_limited_rotations_handle_tiny_and_flipped_rotations() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let axis = Vector::new(0.3_f64, -1.0, 0.2);
+        let start = Quaternion::from_angle_axis(0.4, axis);
+        let target = -Quaternion::from_angle_axis(1.9, axis);
+        let mut current = start;
+        for _ in 0..14 {
+            let next = current.rotate_towards(target, 0.1);
+            assert!((current.angle_to(next) - 0.1).abs() < 1e-12);
+            current = next;
+        }
+        assert!((current.angle_to(target) - 0.1).abs() < 1e-12);
+        assert!(current.rotate_towards(target, 0.1).angle_to(target) < 1e-12);
+        assert_eq!(start.rotate_towards(target, -1.0), start);
+        let tiny = Quaternion::from_angle_axis(1e-10, axis);
+        assert_eq!(tiny.clamp_angle(1e-3), tiny);
+        assert_eq!(Quaternion::identity().rotate_towards(tiny, 1e-3), tiny);
+        let clamped = tiny.clamp_angle(1e-12);
+        let expected = Quaternion::from_angle_axis(1e-12, axis);
+        assert!((clamped.angle_to(expected)) < 1e-20);
+        assert!((target.clamp_angle(0.5).angle_to(start) - 0.1).abs() < 1e-12);
+        assert_eq!(target.clamp_angle(0.0), Quaternion::identity());
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn swing_twist_recomposes_and_separates_axes() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let axis = Vector::new(1.0_f64, 2.0, -0.5);
+        let rotations = [
+            Quaternion::from_angle_axis(2.3, Vector::new(-0.2, 0.4, 1.0)),
+            -Quaternion::from_angle_axis(0.7, Vector::new(1.0, 0.0, 0.3)),
+            Quaternion::from_angle_axis(1.5, axis),
+        ];
+        for rotation in rotations {
+            let (swing, twist) = rotation.swing_twist(axis).unwrap();
+            assert!((swing * twist).angle_to(rotation) < 1e-12);
+            assert!(Vector::new(swing.i(), swing.j(), swing.k()).dot(axis).abs() < 1e-12);
+            let (_, twist_axis) = twist.into_angle_axis();
+            assert!(twist_axis.cross(axis).norm() < 1e-12);
+        }
+        let perpendicular = Vector::new(2.0, -1.0, 0.0);
+        let flip = Quaternion::from_angle_axis(core::f64::consts::PI, perpendicular);
+        let (swing, twist) = flip.swing_twist(axis).unwrap();
+        assert_eq!(twist, Quaternion::identity());
+        assert!(swing.angle_to(flip) < 1e-12);
+        assert!(flip.swing_twist(Vector::zero()).is_none());
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn uniform_rotations_and_directions_have_uniform_moments() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let mut state = 12_345_u64;
+        let mut sample = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (state >> 11) as f64 / (1_u64 << 53) as f64
+        };
+        let count = 20_000;
+        let (mut squares, mut mean, mut spread) = ([0.0; 4], Vector::<f64>::zero(), 0.0);
+        for _ in 0..count {
+            let rotation = Quaternion::from_uniform([sample(), sample(), sample()]);
+            for (sum, value) in squares.iter_mut().zip(<[f64; 4]>::from(rotation)) {
+                *sum += value * value / count as f64;
+            }
+            mean += rotation.rotate(Vector::<f64>::unit_z()) / count as f64;
+            let direction = Vector::from_uniform_sphere([sample(), sample()]);
+            assert!((direction.norm() - 1.0).abs() < 1e-12);
+            spread += direction.z() * direction.z() / count as f64;
+        }
+        assert!(squares.iter().all(|square| (square - 0.25).abs() < 0.01));
+        assert!(mean.norm() < 0.02);
+        assert!((spread - 1.0 / 3.0).abs() < 0.01);
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn weighted_average_aligns_hemispheres_and_ignores_order() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let center = Quaternion::from_angle_axis(2.5_f64, Vector::new(1.0, -1.0, 0.5));
+        let offsets = [
+            Vector::new(0.1, 0.0, 0.0),
+            Vector::new(-0.1, 0.0, 0.0),
+            Vector::new(0.0, 0.05, -0.02),
+            Vector::new(0.0, -0.05, 0.02),
+        ];
+        let rotations = offsets.map(|offset| center * Quaternion::exp(offset));
+        let flipped = [rotations[0], -rotations[1], rotations[2], -rotations[3]];
+        for observations in [rotations, flipped] {
+            let mean = Quaternion::average(observations).unwrap();
+            assert!(mean.angle_to(center) < 1e-12);
+            let reversed = Quaternion::average(observations.into_iter().rev()).unwrap();
+            assert!(mean.angle_to(reversed) < 1e-12);
+        }
+        let weighted =
+            Quaternion::weighted_average(rotations.into_iter().zip([3.0, 1.0, 0.0, 0.0])).unwrap();
+        // Chordal mean of rotations by 0.1 and -0.1 around the same axis weighted 3 to 1.
+        let angle = 2.0 * (0.5 * 0.05_f64.tan()).atan();
+        let expected = center * Quaternion::from_angle_axis(angle, Vector::unit_x());
+        assert!(weighted.angle_to(expected) < 1e-12);
+        let opposite = [(rotations[0], 1.0), (-rotations[0], -1.0)];
+        assert_eq!(Quaternion::weighted_average(opposite), None);
+    }
+
+    #[cfg(feature = "num")]
+    #[test]
+    fn angle_axis_extraction_is_stable_near_identity() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        let axis = Vector::new(1.0_f64, -2.0, 0.5)
+            .normalized_checked()
+            .unwrap();
+        for angle in [1e-150_f64, 1e-12, 1e-6, 0.3, 3.0, 3.2, 6.0] {
+            let rotation = Quaternion::from_angle_axis(angle, axis);
+            let (extracted, unit) = rotation.into_angle_axis();
+            assert!((extracted - angle).abs() <= angle * 1e-12);
+            assert!((unit - axis).norm() < 1e-12);
+            let checked = rotation.into_angle_axis_checked();
+            assert_eq!(checked.is_some(), angle > f64::EPSILON);
+            let shortest = if angle > core::f64::consts::PI {
+                angle - 2.0 * core::f64::consts::PI
+            } else {
+                angle
+            };
+            let scaled = rotation.into_scaled_axis();
+            assert!((scaled - axis * shortest).norm() <= angle * 1e-12);
+        }
+        let (angle, unit) = Quaternion::<f64>::identity().into_angle_axis();
+        assert_eq!((angle, unit), (0.0, Vector::unit_x()));
+        let (_, unit) = Quaternion::<f64>::identity().into_angle_axis_or(Vector::unit_z());
+        assert_eq!(unit, Vector::unit_z());
+        let (angle, unit) = Quaternion::with_wijk(2.0, 0.0, 0.0, 2.0).into_angle_axis();
+        assert!((angle - core::f64::consts::FRAC_PI_2).abs() < 1e-15);
+        assert_eq!(unit, Vector::unit_z());
+    }
+
+    #[cfg(all(feature = "num", feature = "uom"))]
+    #[test]
+    fn uom_angle_axis_round_trip() {
+        use super::Quaternion;
+        use crate::vector::Vector;
+        use uom::si::angle::{degree, radian};
+        use uom::si::f64::Angle;
+        let rotation =
+            Quaternion::<f64>::from_angle_axis_uom(Angle::new::<degree>(90.0), Vector::unit_z());
+        let (angle, axis) = rotation.into_angle_axis_uom();
+        assert!((angle.get::<radian>() - core::f64::consts::FRAC_PI_2).abs() < 1e-9);
+        assert!((axis - Vector::unit_z()).norm() < 1e-9);
+        assert!(Quaternion::<f32>::identity()
+            .into_angle_axis_uom_checked()
+            .is_none());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::ops::Real;
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+
+/// Maximal number of Jacobi sweeps of the 4x4 eigenvalue problem.
+const SWEEPS: usize = 16;
+
+impl<T> Pose<T, T>
+where
+    T: Real,
+{
+    /// Find pose which maps `from` points onto corresponding `to` points with the least sum
+    /// of squared distances, i.e. pose of the `from` frame in the `to` frame, by Horn's
+    /// quaternion-based absolute orientation. Returns `None` if lengths differ, there are
+    /// fewer than three points or the rotation is ambiguous because points are collinear.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::pose::Pose;
+    /// let marker = [
+    ///     Vector::new(0.0_f64, 0.0, 0.0),
+    ///     Vector::new(0.1, 0.0, 0.0),
+    ///     Vector::new(0.0, 0.2, 0.0),
+    ///     Vector::new(0.0, 0.0, 0.3),
+    /// ];
+    /// let truth = Pose::new(
+    ///     Vector::new(1.0, 2.0, -0.5),
+    ///     Quaternion::from_angle_axis(2.0, Vector::new(1.0, -1.0, 2.0)),
+    /// );
+    /// let observed = marker.map(|point| truth.apply_to(point));
+    /// let pose = Pose::align(&marker, &observed).unwrap();
+    /// assert!((pose.translation() - truth.translation()).norm() < 1e-12);
+    /// assert!(pose.rotation().angle_to(truth.rotation()) < 1e-12);
+    /// assert_eq!(Pose::align(&marker[..2], &observed[..2]), None);
+    /// ```
+    pub fn align(from: &[Vector<T>], to: &[Vector<T>]) -> Option<Self> {
+        if from.len() != to.len() || from.len() < 3 {
+            return None;
+        }
+        let centroid = |points: &[Vector<T>]| {
+            let (sum, count) = points
+                .iter()
+                .fold((Vector::zero(), T::zero()), |(sum, count), point| {
+                    (sum + *point, count + T::one())
+                });
+            sum / count
+        };
+        let (from_center, to_center) = (centroid(from), centroid(to));
+        let mut s = [[T::zero(); 3]; 3];
+        for (a, b) in from.iter().zip(to) {
+            let (a, b) = (
+                <[T; 3]>::from(*a - from_center),
+                <[T; 3]>::from(*b - to_center),
+            );
+            for (row, value) in s.iter_mut().zip(a) {
+                for (cell, other) in row.iter_mut().zip(b) {
+                    *cell = *cell + value * other;
+                }
+            }
+        }
+        let [[xx, xy, xz], [yx, yy, yz], [zx, zy, zz]] = s;
+        let matrix = [
+            [xx + yy + zz, yz - zy, zx - xz, xy - yx],
+            [yz - zy, xx - yy - zz, xy + yx, zx + xz],
+            [zx - xz, xy + yx, yy - xx - zz, yz + zy],
+            [xy - yx, zx + xz, yz + zy, zz - xx - yy],
+        ];
+        let [w, i, j, k] = largest_eigenvector(matrix)?;
+        let rotation = Quaternion::with_wijk(w, i, j, k).normalized_checked()?;
+        Some(Self::new(
+            to_center - rotation.rotate(from_center),
+            rotation,
+        ))
+    }
+}
+
+/// Calculate unit eigenvector of the largest eigenvalue of symmetric 4x4 matrix by cyclic
+/// Jacobi rotations, `None` if the largest eigenvalue is not distinct.
+fn largest_eigenvector<T>(matrix: [[T; 4]; 4]) -> Option<[T; 4]>
+where
+    T: Real,
+{
+    let mut a = matrix;
+    let mut vectors = [[T::zero(); 4]; 4];
+    for (index, row) in vectors.iter_mut().enumerate() {
+        row[index] = T::one();
+    }
+    let two = T::one() + T::one();
+    let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+    for _ in 0..SWEEPS {
+        let off = pairs
+            .iter()
+            .fold(T::zero(), |sum, &(p, q)| sum + a[p][q] * a[p][q]);
+        let diagonal = (0..4).fold(T::zero(), |sum, index| {
+            sum + a[index][index] * a[index][index]
+        });
+        if off <= T::epsilon() * T::epsilon() * diagonal {
+            break;
+        }
+        for (p, q) in pairs {
+            if a[p][q] == T::zero() {
+                continue;
+            }
+            let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+            let magnitude = if theta < T::zero() { -theta } else { theta };
+            let t = T::one() / (magnitude + (theta * theta + T::one()).sqrt());
+            let t = if theta < T::zero() { -t } else { t };
+            let c = T::one() / (t * t + T::one()).sqrt();
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (x, y) = (row[p], row[q]);
+                row[p] = c * x - s * y;
+                row[q] = s * x + c * y;
+            }
+            let (x, y) = (a[p], a[q]);
+            for column in 0..4 {
+                a[p][column] = c * x[column] - s * y[column];
+                a[q][column] = s * x[column] + c * y[column];
+            }
+            for row in vectors.iter_mut() {
+                let (x, y) = (row[p], row[q]);
+                row[p] = c * x - s * y;
+                row[q] = s * x + c * y;
+            }
+        }
+    }
+    let mut order = [0, 1, 2, 3];
+    order.sort_unstable_by(|first, second| {
+        a[*second][*second]
+            .partial_cmp(&a[*first][*first])
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    let (largest, next) = (a[order[0]][order[0]], a[order[1]][order[1]]);
+    let scale = (0..4).fold(T::zero(), |sum, index| {
+        let value = a[index][index];
+        sum + if value < T::zero() { -value } else { value }
+    });
+    if largest - next <= scale * T::epsilon().sqrt() {
+        return None;
+    }
+    Some(vectors.map(|row| row[order[0]]))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn align_recovers_pose_from_noisy_and_degenerate_sets() {
+        let truth = Pose::new(
+            Vector::new(-0.4_f64, 1.5, 3.0),
+            Quaternion::from_angle_axis(3.1, Vector::new(0.2, 1.0, -0.3)),
+        );
+        let mut from = [Vector::zero(); 20];
+        let mut to = [Vector::zero(); 20];
+        for (index, (a, b)) in from.iter_mut().zip(to.iter_mut()).enumerate() {
+            let x = index as f64;
+            *a = Vector::new((x * 0.7).sin(), (x * 1.3).cos(), x * 0.1 - 1.0);
+            let noise = Vector::new((x * 5.1).sin(), (x * 3.7).cos(), (x * 2.3).sin()) * 1e-4;
+            *b = truth.apply_to(*a) + noise;
+        }
+        let pose = Pose::align(&from, &to).unwrap();
+        let (translation, rotation) = pose.distance_to(truth);
+        assert!(translation < 1e-3 && rotation < 1e-3);
+        let residual = |pose: Pose<f64, f64>| {
+            from.iter()
+                .zip(&to)
+                .map(|(a, b)| {
+                    let error = pose.apply_to(*a) - *b;
+                    error.dot(error)
+                })
+                .sum::<f64>()
+        };
+        assert!(residual(pose) <= residual(truth));
+        let exact = from.map(|point| truth.apply_to(point));
+        let pose = Pose::align(&from, &exact).unwrap();
+        assert!(pose.distance_to(truth).0 < 1e-12 && pose.distance_to(truth).1 < 1e-12);
+        let line = [0.0, 1.0, 2.0, 3.0].map(|x| Vector::new(x, 2.0 * x, -x));
+        assert_eq!(
+            Pose::align(&line, &line.map(|point| truth.apply_to(point))),
+            None
+        );
+        assert_eq!(Pose::align(&from, &to[1..]), None);
+        let planar = [Vector::unit_x(), Vector::unit_y(), -Vector::unit_x()];
+        let pose = Pose::align(&planar, &planar.map(|point| truth.apply_to(point))).unwrap();
+        assert!(pose.distance_to(truth).1 < 1e-12);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::ops::Real;
+use crate::pose::Pose;
+use num::Float;
+
+/// Fixed-capacity ring buffer of the most recent timestamped poses.
+///
+/// Pushing a sample is constant time and overwrites the oldest sample once the buffer is
+/// full, lookup at arbitrary time interpolates between the two enclosing samples.
+/// No allocation is performed, so the buffer may be filled from an interrupt handler.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::ring::PoseRing;
+/// let mut ring = PoseRing::<f64, 2>::new();
+/// for step in 0..3 {
+///     let time = f64::from(step);
+///     let pose = Pose::new(Vector::new(time, 0.0, 0.0), Quaternion::identity());
+///     assert!(ring.push(time, pose));
+/// }
+/// assert_eq!(ring.len(), 2);
+/// assert_eq!(ring.oldest().unwrap().0, 1.0);
+/// assert!(ring.at(0.5).is_none());
+/// let pose = ring.at(1.25).unwrap();
+/// assert!((pose.translation() - Vector::new(1.25, 0.0, 0.0)).norm() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PoseRing<T, const N: usize> {
+    samples: [Option<(T, Pose<T, T>)>; N],
+    start: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> PoseRing<T, N>
+where
+    T: Float + Real,
+{
+    /// Create empty buffer.
+    pub fn new() -> Self {
+        Self {
+            samples: [None; N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Get maximum number of stored samples.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Get number of stored samples.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Check whether buffer has no samples.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all samples.
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.len = 0;
+    }
+
+    /// Store new sample, overwriting the oldest one when the buffer is full.
+    /// Returns `false` and keeps the buffer unchanged if time is not strictly greater
+    /// than time of the latest sample or the buffer has zero capacity.
+    pub fn push(&mut self, time: T, pose: Pose<T, T>) -> bool {
+        if N == 0 || time.is_nan() {
+            return false;
+        }
+        if let Some((latest, _)) = self.latest() {
+            if time <= latest {
+                return false;
+            }
+        }
+        if self.len < N {
+            self.samples[(self.start + self.len) % N] = Some((time, pose));
+            self.len += 1;
+        } else {
+            self.samples[self.start] = Some((time, pose));
+            self.start = (self.start + 1) % N;
+        }
+        true
+    }
+
+    /// Get sample by index counted from the oldest stored sample.
+    pub fn get(&self, index: usize) -> Option<(T, Pose<T, T>)> {
+        if index < self.len {
+            self.samples[(self.start + index) % N]
+        } else {
+            None
+        }
+    }
+
+    /// Get the oldest stored sample.
+    pub fn oldest(&self) -> Option<(T, Pose<T, T>)> {
+        self.get(0)
+    }
+
+    /// Get the latest stored sample.
+    pub fn latest(&self) -> Option<(T, Pose<T, T>)> {
+        self.get(self.len.checked_sub(1)?)
+    }
+
+    /// Iterate over stored samples from the oldest to the latest one.
+    pub fn iter(&self) -> impl Iterator<Item = (T, Pose<T, T>)> + '_ {
+        (0..self.len).filter_map(move |index| self.get(index))
+    }
+
+    /// Get pose at given time interpolated between the enclosing samples,
+    /// `None` if time is outside of the stored time range.
+    pub fn at(&self, time: T) -> Option<Pose<T, T>> {
+        let (start_time, _) = self.oldest()?;
+        let (end_time, end) = self.latest()?;
+        if time.is_nan() || time < start_time || time > end_time {
+            return None;
+        }
+        if time == end_time {
+            return Some(end);
+        }
+        let (mut lower, mut upper) = (0, self.len - 1);
+        while upper - lower > 1 {
+            let middle = (lower + upper) / 2;
+            if self.get(middle)?.0 <= time {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+        let (start_time, start) = self.get(lower)?;
+        let (end_time, end) = self.get(upper)?;
+        let progress = (time - start_time) / (end_time - start_time);
+        Some(start.interpolate(end, progress))
+    }
+}
+
+impl<T, const N: usize> Default for PoseRing<T, N>
+where
+    T: Float + Real,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Buffers are equal when they store equal samples in the same order, regardless of their
+/// position in the underlying array and of the overwritten samples.
+impl<T: PartialEq, const N: usize> PartialEq for PoseRing<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        let slot = |ring: &Self, index: usize| (ring.start + index) % N;
+        self.len == other.len
+            && (0..self.len)
+                .all(|index| self.samples[slot(self, index)] == other.samples[slot(other, index)])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PoseRing;
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn wrapped_buffer_keeps_order_and_interpolates() {
+        let mut ring = PoseRing::<f64, 4>::new();
+        for step in 0..10 {
+            let time = f64::from(step);
+            let pose = Pose::new(Vector::new(0.0, time * 2.0, 0.0), Quaternion::identity());
+            assert!(ring.push(time, pose));
+        }
+        assert!(!ring.push(9.0, Pose::identity()));
+        let times = ring.iter().map(|(time, _)| time);
+        assert!(times.eq([6.0, 7.0, 8.0, 9.0]));
+        let pose = ring.at(8.5).unwrap();
+        assert!((pose.translation() - Vector::new(0.0, 17.0, 0.0)).norm() < 1e-9);
+        assert_eq!(ring.at(9.0), ring.latest().map(|(_, pose)| pose));
+        assert!(ring.at(9.5).is_none());
+        let mut shifted = PoseRing::<f64, 4>::new();
+        for (time, pose) in ring.iter() {
+            shifted.push(time, pose);
+        }
+        assert_eq!(shifted, ring);
+        shifted.push(10.0, Pose::identity());
+        assert_ne!(shifted, ring);
+        ring.clear();
+        assert!(ring.is_empty() && ring.at(8.5).is_none());
+    }
+}
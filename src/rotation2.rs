@@ -0,0 +1,185 @@
+use crate::ops::{Epsilon, Sqrt};
+#[cfg(feature = "num")]
+use crate::ops::{IntoAngle, Real};
+#[cfg(feature = "num")]
+use crate::quaternion::Quaternion;
+use crate::vector2::Vector2;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(feature = "num")]
+use num::{One, Zero};
+
+/// Planar rotation stored as unit complex number `cos + i sin`, counterpart of
+/// [`Quaternion`](crate::quaternion::Quaternion). Multiplication composes rotations.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Rotation2<T> {
+    cos: T,
+    sin: T,
+}
+
+impl<T> Rotation2<T> {
+    /// Create rotation from cosine and sine of its angle, they must form unit vector.
+    pub const fn with_cos_sin(cos: T, sin: T) -> Self {
+        Self { cos, sin }
+    }
+}
+
+/// Implement constant planar rotations for given floating point type.
+macro_rules! impl_rotation2_constants {
+    ($($float:ty),+) => {$(
+        impl Rotation2<$float> {
+            /// Rotation by zero angle.
+            pub const IDENTITY: Self = Self::with_cos_sin(1.0, 0.0);
+        }
+    )+};
+}
+
+impl_rotation2_constants!(f32, f64);
+
+impl<T> Rotation2<T>
+where
+    T: Copy,
+{
+    pub fn cos(&self) -> T {
+        self.cos
+    }
+
+    pub fn sin(&self) -> T {
+        self.sin
+    }
+}
+
+impl<T> Rotation2<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    /// Calculate inverse of unit rotation, i.e. complex conjugate.
+    pub fn inverse(self) -> Self {
+        Self::with_cos_sin(self.cos, -self.sin)
+    }
+}
+
+impl<T> Rotation2<T>
+where
+    T: Copy,
+{
+    /// Rotate vector counterclockwise by this rotation.
+    /// ```
+    /// # use spatial::vector2::Vector2;
+    /// # use spatial::rotation2::Rotation2;
+    /// let quarter = Rotation2::with_cos_sin(0.0, 1.0);
+    /// assert_eq!(quarter.rotate(Vector2::new(2.0, 1.0)), Vector2::new(-1.0, 2.0));
+    /// ```
+    pub fn rotate<U>(self, vector: Vector2<U>) -> Vector2<U>
+    where
+        T: Mul<U, Output = U>,
+        U: Copy + Add<Output = U> + Sub<Output = U>,
+    {
+        Vector2::new(
+            self.cos * vector.x() - self.sin * vector.y(),
+            self.sin * vector.x() + self.cos * vector.y(),
+        )
+    }
+}
+
+impl<T> Rotation2<T>
+where
+    T: Copy
+        + PartialOrd
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sqrt<Output = T>
+        + Epsilon,
+{
+    /// Scale back to unit norm, `None` if the norm is below epsilon.
+    pub fn normalized_checked(self) -> Option<Self> {
+        let direction = Vector2::new(self.cos, self.sin).normalized_checked()?;
+        Some(Self::with_cos_sin(direction.x(), direction.y()))
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> Rotation2<T>
+where
+    T: One + Zero,
+{
+    /// Rotation by zero angle.
+    pub fn identity() -> Self {
+        Self::with_cos_sin(T::one(), T::zero())
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> Rotation2<T>
+where
+    T: Real,
+{
+    /// Create counterclockwise rotation by `angle`, either plain value in radians or, with
+    /// the `uom` feature, `Angle`.
+    pub fn from_angle<A: IntoAngle<T>>(angle: A) -> Self {
+        let angle = angle.into_angle();
+        Self::with_cos_sin(angle.cos(), angle.sin())
+    }
+
+    /// Get rotation angle in the `[-pi, pi]` range.
+    pub fn angle(self) -> T {
+        self.sin.atan2(self.cos)
+    }
+
+    /// Lift into spatial rotation around the `z` axis. Half-angle components are computed
+    /// from cosine and sine directly, so no precision is lost through the angle.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::quaternion::Quaternion;
+    /// # use spatial::rotation2::Rotation2;
+    /// let rotation = Rotation2::from_angle(2.5_f64);
+    /// let expected = Quaternion::from_angle_axis(2.5, Vector::unit_z());
+    /// assert!(rotation.into_quaternion().angle_to(expected) < 1e-12);
+    /// ```
+    pub fn into_quaternion(self) -> Quaternion<T> {
+        let two = T::one() + T::one();
+        let half = |value: T| {
+            let value = value / two;
+            if value > T::zero() {
+                value.sqrt()
+            } else {
+                T::zero()
+            }
+        };
+        let (w, k) = if self.cos < T::zero() {
+            let k = half(T::one() - self.cos);
+            (self.sin / (two * k), k)
+        } else {
+            let w = half(T::one() + self.cos);
+            (w, self.sin / (two * w))
+        };
+        Quaternion::with_wijk(w, T::zero(), T::zero(), k)
+    }
+}
+
+impl<T> Mul for Rotation2<T>
+where
+    T: Copy + Mul<Output = T> + Add<Output = T> + Sub<Output = T>,
+{
+    type Output = Self;
+    fn mul(self, other: Self) -> Self::Output {
+        Self::with_cos_sin(
+            self.cos * other.cos - self.sin * other.sin,
+            self.sin * other.cos + self.cos * other.sin,
+        )
+    }
+}
+
+/// Format as `(cos; sin)`, formatter flags such as precision apply to every component.
+impl<T: Display> Display for Rotation2<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("(")?;
+        self.cos.fmt(formatter)?;
+        formatter.write_str("; ")?;
+        self.sin.fmt(formatter)?;
+        formatter.write_str(")")
+    }
+}
@@ -0,0 +1,436 @@
+//! With the `serde` feature enabled, quantities are (de)serialized as plain numbers in SI base
+//! units: translations in meters, linear velocities in meters per second, angular velocities
+//! in radians per second, forces in newtons, torques in newton meters and rotation components
+//! as ratios.
+
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::twist::Twist;
+use crate::vector::Vector;
+use crate::wrench::Wrench;
+use num::Zero;
+use uom::si::angle::radian;
+use uom::si::angular_velocity::radian_per_second;
+use uom::si::f64::{Angle, AngularVelocity, Force, Length, Ratio, Time, Torque, Velocity};
+use uom::si::force::newton;
+use uom::si::length::meter;
+use uom::si::ratio::ratio;
+use uom::si::torque::newton_meter;
+use uom::si::velocity::meter_per_second;
+
+/// Translation with dimension-checked length components.
+pub type VectorSI = Vector<Length>;
+/// Rotation with dimensionless components.
+pub type QuaternionSI = Quaternion<Ratio>;
+/// Pose with dimension-checked translation and dimensionless rotation.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::si::PoseSI;
+/// # use uom::si::angle::degree;
+/// # use uom::si::f64::{Angle, Length};
+/// # use uom::si::length::{meter, millimeter};
+/// let first = PoseSI::from_si(
+///     Length::new::<meter>(1.0),
+///     Length::new::<meter>(0.0),
+///     Length::new::<meter>(0.0),
+///     Angle::new::<degree>(90.0),
+///     Vector::unit_z(),
+/// );
+/// let second = PoseSI::from_si(
+///     Length::new::<millimeter>(500.0),
+///     Length::new::<meter>(0.0),
+///     Length::new::<meter>(0.0),
+///     Angle::new::<degree>(0.0),
+///     Vector::unit_z(),
+/// );
+/// let result = first * second;
+/// assert!((result.translation().y() - Length::new::<meter>(0.5)).abs() < Length::new::<millimeter>(1e-6));
+/// ```
+pub type PoseSI = Pose<Length, Ratio>;
+/// Twist with dimension-checked linear and angular velocities.
+pub type TwistSI = Twist<Velocity, AngularVelocity>;
+/// Wrench with dimension-checked forces and torques.
+pub type WrenchSI = Wrench<Force, Torque>;
+
+impl Quaternion<Ratio> {
+    /// Create identity rotation with dimensionless components.
+    pub fn identity_si() -> Self {
+        let zero = Ratio::zero();
+        Self::with_wijk(Ratio::new::<ratio>(1.0), zero, zero, zero)
+    }
+
+    /// Create rotation by dimension-checked angle around given axis.
+    pub fn from_angle_axis_si(angle: Angle, axis: Vector<f64>) -> Self {
+        Self::from_plain(Quaternion::<f64>::from_angle_axis_uom(angle, axis))
+    }
+
+    /// Convert rotation into dimension-checked angle and axis,
+    /// see [`Quaternion::into_angle_axis`].
+    pub fn into_angle_axis_si(self) -> (Angle, Vector<f64>) {
+        let (angle, axis) = self.into_plain().into_angle_axis();
+        (Angle::new::<radian>(angle), axis)
+    }
+
+    /// Create rotation from quaternion with plain components.
+    pub fn from_plain(rotation: Quaternion<f64>) -> Self {
+        Self::with_wijk(
+            Ratio::new::<ratio>(rotation.w()),
+            Ratio::new::<ratio>(rotation.i()),
+            Ratio::new::<ratio>(rotation.j()),
+            Ratio::new::<ratio>(rotation.k()),
+        )
+    }
+
+    /// Convert rotation into quaternion with plain components.
+    pub fn into_plain(self) -> Quaternion<f64> {
+        Quaternion::with_wijk(
+            self.w().get::<ratio>(),
+            self.i().get::<ratio>(),
+            self.j().get::<ratio>(),
+            self.k().get::<ratio>(),
+        )
+    }
+}
+
+impl Pose<Length, Ratio> {
+    /// Create pose with no translation and no rotation.
+    pub fn identity_si() -> Self {
+        Self::new(Vector::zero(), Quaternion::identity_si())
+    }
+
+    /// Create pose from dimension-checked translation components and rotation
+    /// by dimension-checked angle around given axis.
+    pub fn from_si(x: Length, y: Length, z: Length, angle: Angle, axis: Vector<f64>) -> Self {
+        Self::new(
+            Vector::new(x, y, z),
+            Quaternion::from_angle_axis_si(angle, axis),
+        )
+    }
+
+    /// Exponential map of displacement twist, e.g. twist multiplied by time,
+    /// see [`Pose::exp`].
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// # use spatial::si::{PoseSI, TwistSI};
+    /// # use uom::si::angular_velocity::radian_per_second;
+    /// # use uom::si::f64::{AngularVelocity, Length, Time, Velocity};
+    /// # use uom::si::length::meter;
+    /// # use uom::si::time::second;
+    /// # use uom::si::velocity::meter_per_second;
+    /// let twist = TwistSI::new(
+    ///     Vector::new(0.5, 0.0, 0.0).map(Velocity::new::<meter_per_second>),
+    ///     Vector::new(0.0, 0.0, 0.0).map(AngularVelocity::new::<radian_per_second>),
+    /// );
+    /// let pose = PoseSI::exp_si(twist * Time::new::<second>(4.0));
+    /// assert!((pose.translation().x() - Length::new::<meter>(2.0)).abs() < Length::new::<meter>(1e-9));
+    /// ```
+    pub fn exp_si(twist: Twist<Length, Ratio>) -> Self {
+        let twist = Twist::new(
+            twist.linear().map(|value| value.get::<meter>()),
+            twist.angular().map(|value| value.get::<ratio>()),
+        );
+        Self::from_plain(Pose::exp(twist))
+    }
+
+    /// Logarithm map into displacement twist, see [`Pose::log`].
+    pub fn log_si(self) -> Twist<Length, Ratio> {
+        let twist = self.into_plain().log();
+        Twist::new(
+            twist.linear().map(Length::new::<meter>),
+            twist.angular().map(Ratio::new::<ratio>),
+        )
+    }
+
+    /// Interpolate between this and other pose at `time` of motion lasting `duration`,
+    /// see [`Pose::interpolate`].
+    pub fn interpolate_at(self, other: Self, time: Time, duration: Time) -> Self {
+        let progress: Ratio = time / duration;
+        let pose = self.into_plain().interpolate(other.into_plain(), progress);
+        Self::from_plain(pose)
+    }
+
+    /// Create pose from plain pose with translation in meters.
+    pub fn from_plain(pose: Pose<f64, f64>) -> Self {
+        Self::new(
+            pose.translation().map(Length::new::<meter>),
+            Quaternion::from_plain(pose.rotation()),
+        )
+    }
+
+    /// Convert pose into plain pose with translation in meters.
+    pub fn into_plain(self) -> Pose<f64, f64> {
+        Pose::new(
+            self.translation().map(|value| value.get::<meter>()),
+            self.rotation().into_plain(),
+        )
+    }
+}
+
+impl Twist<Velocity, AngularVelocity> {
+    /// Convert twist into plain twist in meters and radians per second.
+    pub fn into_plain(self) -> Twist<f64, f64> {
+        Twist::new(
+            self.linear().map(|value| value.get::<meter_per_second>()),
+            self.angular().map(|value| value.get::<radian_per_second>()),
+        )
+    }
+
+    /// Create twist from plain twist in meters and radians per second.
+    pub fn from_plain(twist: Twist<f64, f64>) -> Self {
+        Twist::new(
+            twist.linear().map(Velocity::new::<meter_per_second>),
+            twist
+                .angular()
+                .map(AngularVelocity::new::<radian_per_second>),
+        )
+    }
+
+    /// Calculate pose reached by moving with this body-frame twist for given time,
+    /// see [`Pose::exp`].
+    pub fn integrate(self, time: Time) -> PoseSI {
+        PoseSI::exp_si(self * time)
+    }
+}
+
+impl Wrench<Force, Torque> {
+    /// Convert wrench into plain wrench in newtons and newton meters.
+    pub fn into_plain(self) -> Wrench<f64, f64> {
+        Wrench::new(
+            self.force().map(|value| value.get::<newton>()),
+            self.torque().map(|value| value.get::<newton_meter>()),
+        )
+    }
+
+    /// Create wrench from plain wrench in newtons and newton meters.
+    pub fn from_plain(wrench: Wrench<f64, f64>) -> Self {
+        Wrench::new(
+            wrench.force().map(Force::new::<newton>),
+            wrench.torque().map(Torque::new::<newton_meter>),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PoseSI, QuaternionSI, VectorSI};
+    use crate::ops::{RotationScalar, Scalar};
+    use crate::pose::Pose;
+    use crate::vector::Vector;
+    use uom::si::angle::{degree, radian};
+    use uom::si::f64::{Angle, Length};
+    use uom::si::length::meter;
+
+    fn length(value: f64) -> Length {
+        Length::new::<meter>(value)
+    }
+
+    fn assert_close(actual: VectorSI, expected: VectorSI) {
+        assert!((actual - expected).norm() < length(1e-9));
+    }
+
+    #[test]
+    fn apply_to_rotates_and_translates() {
+        let pose = PoseSI::from_si(
+            length(1.0),
+            length(2.0),
+            length(3.0),
+            Angle::new::<degree>(90.0),
+            Vector::unit_z(),
+        );
+        let result = pose.apply_to(Vector::new(length(1.0), length(0.0), length(0.0)));
+        assert_close(result, Vector::new(length(1.0), length(3.0), length(3.0)));
+        fn transform<T: Scalar, R: RotationScalar<T>>(
+            pose: Pose<T, R>,
+            point: Vector<T>,
+        ) -> Vector<T> {
+            pose * point
+        }
+        let generic = transform(pose, Vector::new(length(1.0), length(0.0), length(0.0)));
+        assert_close(generic, result);
+    }
+
+    #[test]
+    fn combine_with_inverse_is_identity() {
+        let pose = PoseSI::from_si(
+            length(1.0),
+            length(-2.0),
+            length(0.5),
+            Angle::new::<degree>(30.0),
+            Vector::new(1.0, 1.0, 0.0),
+        );
+        let result = pose.combine(pose.inverse());
+        let identity = PoseSI::identity_si();
+        assert_close(result.translation(), identity.translation());
+        let dot = result
+            .rotation()
+            .into_plain()
+            .dot(identity.rotation().into_plain());
+        assert!((dot - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn twist_transform_and_integration() {
+        use super::TwistSI;
+        use uom::si::angular_velocity::radian_per_second;
+        use uom::si::f64::{AngularVelocity, Time, Velocity};
+        use uom::si::time::second;
+        use uom::si::velocity::meter_per_second;
+        let pose = PoseSI::from_si(
+            length(1.0),
+            length(0.0),
+            length(0.0),
+            Angle::new::<degree>(90.0),
+            Vector::unit_z(),
+        );
+        let twist = TwistSI::new(
+            Vector::new(1.0, 0.0, 0.0).map(Velocity::new::<meter_per_second>),
+            Vector::new(1.0, 0.0, 0.0).map(AngularVelocity::new::<radian_per_second>),
+        );
+        let result = pose.transform_twist(twist);
+        let linear = result.linear().map(|value| value.get::<meter_per_second>());
+        assert!((linear - Vector::new(0.0, 1.0, 1.0)).norm() < 1e-9);
+        let angular = result
+            .angular()
+            .map(|value| value.get::<radian_per_second>());
+        assert!((angular - Vector::unit_y()).norm() < 1e-9);
+        let moved = twist.integrate(Time::new::<second>(2.0));
+        assert_close(
+            moved.translation(),
+            Vector::new(length(2.0), length(0.0), length(0.0)),
+        );
+        let (angle, axis) = moved.rotation().into_angle_axis_si();
+        assert!((angle.get::<radian>() - 2.0).abs() < 1e-9);
+        assert!((axis - Vector::unit_x()).norm() < 1e-9);
+    }
+
+    #[test]
+    fn wrench_transform_couples_force_into_torque() {
+        use super::WrenchSI;
+        use uom::si::f64::{Force, Torque};
+        use uom::si::force::newton;
+        use uom::si::torque::newton_meter;
+        let pose = PoseSI::from_si(
+            length(0.0),
+            length(0.5),
+            length(0.0),
+            Angle::new::<degree>(0.0),
+            Vector::unit_z(),
+        );
+        let wrench = WrenchSI::new(
+            Vector::new(2.0, 0.0, 0.0).map(Force::new::<newton>),
+            Vector::new(0.0, 0.0, 0.0).map(Torque::new::<newton_meter>),
+        );
+        let result = pose.transform_wrench(wrench).into_plain();
+        assert!((result.force() - Vector::new(2.0, 0.0, 0.0)).norm() < 1e-9);
+        assert!((result.torque() - Vector::new(0.0, 0.0, -1.0)).norm() < 1e-9);
+    }
+
+    #[test]
+    fn lever_arm_cross_force_is_torque() {
+        use uom::si::f64::{Force, Torque};
+        use uom::si::force::newton;
+        use uom::si::torque::newton_meter;
+        let arm = Vector::new(0.0, 0.5, 0.0).map(length);
+        let force = Vector::new(2.0, 0.0, 0.0).map(Force::new::<newton>);
+        let torque: Vector<Torque> = arm.cross(force).into();
+        let torque = torque.map(|value| value.get::<newton_meter>());
+        assert!((torque - Vector::new(0.0, 0.0, -1.0)).norm() < 1e-9);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn quantities_are_serializable() {
+        fn assert_serde<T: serde::Serialize + serde::de::DeserializeOwned>() {}
+        assert_serde::<VectorSI>();
+        assert_serde::<QuaternionSI>();
+        assert_serde::<PoseSI>();
+        assert_serde::<super::TwistSI>();
+        assert_serde::<super::WrenchSI>();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn pose_round_trips_in_base_units() {
+        use crate::quaternion::Quaternion;
+        use serde_test::{assert_tokens, Token};
+        use uom::si::f64::Ratio;
+        use uom::si::length::kilometer;
+        use uom::si::ratio::ratio;
+        let pose = PoseSI::new(
+            Vector::new(Length::new::<kilometer>(0.5), length(-2.0), length(0.25)),
+            Quaternion::with_wijk(0.0, 0.6, 0.0, 0.8).map(Ratio::new::<ratio>),
+        );
+        assert_tokens(
+            &pose,
+            &[
+                Token::Struct {
+                    name: "Pose",
+                    len: 2,
+                },
+                Token::Str("translation"),
+                Token::Struct {
+                    name: "Vector",
+                    len: 3,
+                },
+                Token::Str("x"),
+                Token::F64(500.0),
+                Token::Str("y"),
+                Token::F64(-2.0),
+                Token::Str("z"),
+                Token::F64(0.25),
+                Token::StructEnd,
+                Token::Str("rotation"),
+                Token::Struct {
+                    name: "Quaternion",
+                    len: 4,
+                },
+                Token::Str("w"),
+                Token::F64(0.0),
+                Token::Str("i"),
+                Token::F64(0.6),
+                Token::Str("j"),
+                Token::F64(0.0),
+                Token::Str("k"),
+                Token::F64(0.8),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn time_based_interpolation_and_log() {
+        use uom::si::f64::Time;
+        use uom::si::time::{millisecond, second};
+        let start = PoseSI::identity_si();
+        let end = PoseSI::from_si(
+            length(2.0),
+            length(0.0),
+            length(0.0),
+            Angle::new::<degree>(90.0),
+            Vector::unit_z(),
+        );
+        let result = start.interpolate_at(
+            end,
+            Time::new::<millisecond>(500.0),
+            Time::new::<second>(2.0),
+        );
+        assert_close(
+            result.translation(),
+            Vector::new(length(0.5), length(0.0), length(0.0)),
+        );
+        let (angle, _) = result.rotation().into_angle_axis_si();
+        assert!((angle.get::<degree>() - 22.5).abs() < 1e-9);
+        let restored = PoseSI::exp_si(end.log_si());
+        assert_close(restored.translation(), end.translation());
+    }
+
+    #[test]
+    fn angle_axis_round_trip() {
+        let rotation =
+            QuaternionSI::from_angle_axis_si(Angle::new::<degree>(45.0), Vector::unit_x());
+        let (angle, axis) = rotation.into_angle_axis_si();
+        assert!((angle.get::<radian>() - core::f64::consts::FRAC_PI_4).abs() < 1e-9);
+        assert!((axis - Vector::unit_x()).norm() < 1e-9);
+    }
+}
@@ -0,0 +1,137 @@
+//! Portable SIMD implementations of `f32` rotation and pose operations, requires nightly
+//! compiler for the `portable_simd` language feature.
+//!
+//! Vectors are packed as `[x, y, z, 0]` and quaternions as `[i, j, k, w]` lanes.
+
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use core::simd::num::SimdFloat;
+use core::simd::{f32x4, simd_swizzle};
+
+fn pack_vector(vector: Vector<f32>) -> f32x4 {
+    f32x4::from_array([vector.x(), vector.y(), vector.z(), 0.0])
+}
+
+fn unpack_vector(lanes: f32x4) -> Vector<f32> {
+    let [x, y, z, _] = lanes.to_array();
+    Vector::new(x, y, z)
+}
+
+fn pack_quaternion(quaternion: Quaternion<f32>) -> f32x4 {
+    f32x4::from_array([
+        quaternion.i(),
+        quaternion.j(),
+        quaternion.k(),
+        quaternion.w(),
+    ])
+}
+
+fn unpack_quaternion(lanes: f32x4) -> Quaternion<f32> {
+    let [i, j, k, w] = lanes.to_array();
+    Quaternion::with_wijk(w, i, j, k)
+}
+
+/// Cross product of the first three lanes, the last lane is zero for packed vectors.
+fn cross(left: f32x4, right: f32x4) -> f32x4 {
+    simd_swizzle!(left, [1, 2, 0, 3]) * simd_swizzle!(right, [2, 0, 1, 3])
+        - simd_swizzle!(left, [2, 0, 1, 3]) * simd_swizzle!(right, [1, 2, 0, 3])
+}
+
+fn rotate_packed(quaternion: f32x4, vector: f32x4) -> f32x4 {
+    let imaginary = quaternion * f32x4::from_array([1.0, 1.0, 1.0, 0.0]);
+    let real = simd_swizzle!(quaternion, [3, 3, 3, 3]);
+    let twice = cross(imaginary, vector) * f32x4::splat(2.0);
+    vector + real * twice + cross(imaginary, twice)
+}
+
+pub(crate) fn dot(left: Vector<f32>, right: Vector<f32>) -> f32 {
+    (pack_vector(left) * pack_vector(right)).reduce_sum()
+}
+
+pub(crate) fn cross_vectors(left: Vector<f32>, right: Vector<f32>) -> Vector<f32> {
+    unpack_vector(cross(pack_vector(left), pack_vector(right)))
+}
+
+pub(crate) fn rotate(quaternion: Quaternion<f32>, vector: Vector<f32>) -> Vector<f32> {
+    unpack_vector(rotate_packed(
+        pack_quaternion(quaternion),
+        pack_vector(vector),
+    ))
+}
+
+pub(crate) fn multiply(left: Quaternion<f32>, right: Quaternion<f32>) -> Quaternion<f32> {
+    let (left, right) = (pack_quaternion(left), pack_quaternion(right));
+    let sign = f32x4::from_array([1.0, 1.0, 1.0, -1.0]);
+    let result = simd_swizzle!(left, [3, 3, 3, 3]) * right
+        + simd_swizzle!(left, [0, 1, 2, 0]) * simd_swizzle!(right, [3, 3, 3, 0]) * sign
+        + simd_swizzle!(left, [1, 2, 0, 1]) * simd_swizzle!(right, [2, 0, 1, 1]) * sign
+        - simd_swizzle!(left, [2, 0, 1, 2]) * simd_swizzle!(right, [1, 2, 0, 2]);
+    unpack_quaternion(result)
+}
+
+pub(crate) fn apply(
+    translation: Vector<f32>,
+    rotation: Quaternion<f32>,
+    vector: Vector<f32>,
+) -> Vector<f32> {
+    let rotated = rotate_packed(pack_quaternion(rotation), pack_vector(vector));
+    unpack_vector(pack_vector(translation) + rotated)
+}
+
+/// Rotate and translate every vector of the slice in place.
+pub(crate) fn transform(
+    translation: Vector<f32>,
+    rotation: Quaternion<f32>,
+    vectors: &mut [Vector<f32>],
+) {
+    let (translation, rotation) = (pack_vector(translation), pack_quaternion(rotation));
+    for vector in vectors {
+        *vector = unpack_vector(translation + rotate_packed(rotation, pack_vector(*vector)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    fn assert_close(actual: Vector<f32>, expected: Vector<f32>) {
+        let error = actual - expected;
+        assert!(
+            error.dot(error) < 1e-10,
+            "{actual:?} is not close to {expected:?}"
+        );
+    }
+
+    #[test]
+    fn lane_operations_match_scalar_ones() {
+        let rotation = Quaternion::with_wijk(0.5_f32, -0.5, 0.5, 0.5);
+        let other = Quaternion::with_wijk(0.0_f32, 0.6, 0.0, 0.8);
+        let vector = Vector::new(1.0_f32, -2.0, 3.0);
+        assert_close(rotation.rotate_simd(vector), rotation.rotate(vector));
+        let actual = <[f32; 4]>::from(rotation.multiply_simd(other));
+        let expected = <[f32; 4]>::from(rotation * other);
+        assert!(actual
+            .iter()
+            .zip(expected)
+            .all(|(a, b)| (a - b).abs() < 1e-6));
+        let pose = Pose::new(Vector::new(0.5_f32, 0.0, -1.0), rotation);
+        let other = Pose::new(vector, other);
+        let combined = pose.combine_simd(other);
+        assert_close(combined.translation(), pose.combine(other).translation());
+        assert_close(pose.apply_to_simd(vector), pose.apply_to(vector));
+        let other = Vector::new(0.25_f32, 4.0, -1.5);
+        assert!((vector.dot_simd(other) - vector.dot(other)).abs() < 1e-6);
+        assert_close(vector.cross_simd(other), vector.cross(other));
+        assert!((vector.norm_simd() - vector.norm()).abs() < 1e-6);
+        let mut vectors = [vector, -vector, Vector::<f32>::ZERO];
+        pose.apply_to_slice_simd(&mut vectors);
+        for (actual, original) in vectors
+            .into_iter()
+            .zip([vector, -vector, Vector::<f32>::ZERO])
+        {
+            assert_close(actual, pose.apply_to(original));
+        }
+    }
+}
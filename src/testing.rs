@@ -0,0 +1,183 @@
+use crate::ops::Norm;
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::vector::Vector;
+use core::f64::consts::FRAC_1_SQRT_2;
+
+/// Default absolute tolerance of the assertion macros.
+pub const TOLERANCE: f64 = 1e-9;
+
+/// Fixture poses covering identity, half turns, quarter turns and general rotations,
+/// combined with zero, axis-aligned and skewed translations.
+pub const POSES: [Pose<f64, f64>; 6] = [
+    Pose::new(
+        Vector::new(0.0, 0.0, 0.0),
+        Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0),
+    ),
+    Pose::new(
+        Vector::new(1.0, 0.0, 0.0),
+        Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0),
+    ),
+    Pose::new(
+        Vector::new(0.0, -2.0, 0.5),
+        Quaternion::with_wijk(FRAC_1_SQRT_2, 0.0, 0.0, FRAC_1_SQRT_2),
+    ),
+    Pose::new(
+        Vector::new(3.0, 1.0, -4.0),
+        Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5),
+    ),
+    Pose::new(
+        Vector::new(-0.25, 7.5, 2.0),
+        Quaternion::with_wijk(-0.1, 0.7, 0.1, -0.7),
+    ),
+    Pose::new(
+        Vector::new(1e3, -1e3, 1e-3),
+        Quaternion::with_wijk(0.9, -0.3, 0.3, 0.1),
+    ),
+];
+
+/// Calculate distance between two vectors.
+pub fn vector_error(left: Vector<f64>, right: Vector<f64>) -> f64 {
+    Norm::norm(left - right)
+}
+
+/// Calculate distance between two quaternions treating `q` and `-q` as the same rotation.
+/// ```
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::testing::quaternion_error;
+/// let rotation = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5);
+/// assert_eq!(quaternion_error(rotation, -rotation), 0.0);
+/// ```
+pub fn quaternion_error(left: Quaternion<f64>, right: Quaternion<f64>) -> f64 {
+    let difference = Norm::norm(left + -right);
+    let sum = Norm::norm(left + right);
+    if sum < difference {
+        sum
+    } else {
+        difference
+    }
+}
+
+/// Calculate translation and rotation errors between two poses, see [`vector_error`] and
+/// [`quaternion_error`].
+pub fn pose_error(left: Pose<f64, f64>, right: Pose<f64, f64>) -> (f64, f64) {
+    (
+        vector_error(left.translation(), right.translation()),
+        quaternion_error(left.rotation(), right.rotation()),
+    )
+}
+
+/// Assert that two `f64` vectors are closer than the tolerance, [`TOLERANCE`] by default.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::assert_vector_eq;
+/// assert_vector_eq!(Vector::new(1.0, 2.0, 3.0), Vector::new(1.0, 2.0, 3.0 + 1e-12));
+/// assert_vector_eq!(Vector::new(1.0, 2.0, 3.0), Vector::new(1.0, 2.0, 3.1), 0.2);
+/// ```
+#[macro_export]
+macro_rules! assert_vector_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_vector_eq!($left, $right, $crate::testing::TOLERANCE)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                let error = $crate::testing::vector_error(*left, *right);
+                if error.is_nan() || error > tolerance {
+                    panic!(
+                        "assertion `left == right` failed: vectors differ by {error} > {tolerance}\n  left: {left:?}\n right: {right:?}"
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Assert that two `f64` quaternions represent rotations closer than the tolerance,
+/// [`TOLERANCE`] by default. Quaternions `q` and `-q` are considered equal.
+/// ```
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::assert_quaternion_eq;
+/// let rotation = Quaternion::with_wijk(0.5, 0.5, -0.5, 0.5);
+/// assert_quaternion_eq!(rotation, -rotation);
+/// ```
+#[macro_export]
+macro_rules! assert_quaternion_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_quaternion_eq!($left, $right, $crate::testing::TOLERANCE)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                let error = $crate::testing::quaternion_error(*left, *right);
+                if error.is_nan() || error > tolerance {
+                    panic!(
+                        "assertion `left == right` failed: quaternions differ by {error} > {tolerance}\n  left: {left:?}\n right: {right:?}"
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Assert that translations and rotations of two `f64` poses are closer than the tolerance,
+/// [`TOLERANCE`] by default. Rotations are compared as in [`assert_quaternion_eq`].
+/// ```
+/// # use spatial::pose::Pose;
+/// # use spatial::assert_pose_eq;
+/// # use spatial::testing::POSES;
+/// let pose = POSES[3];
+/// let flipped = Pose::new(pose.translation(), -pose.rotation());
+/// assert_pose_eq!(pose, flipped);
+/// ```
+#[macro_export]
+macro_rules! assert_pose_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_pose_eq!($left, $right, $crate::testing::TOLERANCE)
+    };
+    ($left:expr, $right:expr, $tolerance:expr $(,)?) => {
+        match (&$left, &$right, $tolerance) {
+            (left, right, tolerance) => {
+                let (translation, rotation) = $crate::testing::pose_error(*left, *right);
+                if translation.is_nan()
+                    || rotation.is_nan()
+                    || translation > tolerance
+                    || rotation > tolerance
+                {
+                    panic!(
+                        "assertion `left == right` failed: poses differ by {translation} in translation and {rotation} in rotation > {tolerance}\n  left: {left:?}\n right: {right:?}"
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::POSES;
+    use crate::ops::Norm;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn fixtures_are_unit_and_assertions_respect_double_cover() {
+        for pose in POSES {
+            assert!((Norm::norm(pose.rotation()) - 1.0).abs() < 1e-12);
+            crate::assert_quaternion_eq!(pose.rotation(), -pose.rotation());
+        }
+        let rotation = Quaternion::with_wijk(0.0, 1.0, 0.0, 0.0);
+        let close = Quaternion::with_wijk(1e-6, 1.0, 0.0, 0.0);
+        crate::assert_quaternion_eq!(rotation, close, 1e-5);
+        let different = std::panic::catch_unwind(|| {
+            crate::assert_quaternion_eq!(rotation, Quaternion::with_wijk(1.0, 0.0, 0.0, 0.0));
+        });
+        assert!(different.is_err());
+        let nan = std::panic::catch_unwind(|| {
+            crate::assert_vector_eq!(Vector::new(f64::NAN, 0.0, 0.0), Vector::new(0.0, 0.0, 0.0));
+        });
+        assert!(nan.is_err());
+    }
+}
@@ -0,0 +1,918 @@
+use crate::ops::Real;
+use crate::pose::Pose;
+use crate::quaternion::Quaternion;
+use crate::twist::Twist;
+use crate::vector::Vector;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use num::Float;
+
+/// Policy for sampling poses outside of the recorded time range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Extrapolation {
+    /// Repeat the first or the last recorded pose.
+    Hold,
+    /// Continue motion of the first or the last recorded segment with constant velocity.
+    Linear,
+}
+
+/// Resample timestamped poses at fixed time step, starting at the first sample time and
+/// ending at or before the last sample time.
+/// Returns `None` if there are no samples, timestamps are not finite and strictly increasing,
+/// the time range is not finite or time step is not positive and finite.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::trajectory::resample;
+/// let samples = [
+///     (0.0, Pose::new(Vector::new(0.0, 0.0, 0.0), Quaternion::identity())),
+///     (0.3, Pose::new(Vector::new(3.0, 0.0, 0.0), Quaternion::identity())),
+///     (1.0, Pose::new(Vector::new(3.0, 7.0, 0.0), Quaternion::identity())),
+/// ];
+/// let mut resampled = resample(&samples, 0.5).unwrap();
+/// let (time, pose) = resampled.next().unwrap();
+/// assert_eq!(time, 0.0);
+/// assert!(pose.translation().norm() < 1e-9);
+/// let (time, pose) = resampled.next().unwrap();
+/// assert_eq!(time, 0.5);
+/// assert!((pose.translation() - Vector::new(3.0, 2.0, 0.0)).norm() < 1e-9);
+/// let (time, _) = resampled.next().unwrap();
+/// assert_eq!(time, 1.0);
+/// assert!(resampled.next().is_none());
+/// ```
+pub fn resample<T>(samples: &[(T, Pose<T, T>)], dt: T) -> Option<Resample<'_, T>>
+where
+    T: Float + Real,
+{
+    let start = samples.first()?.0;
+    let end = samples.last()?.0;
+    resample_between(samples, start, end, dt, Extrapolation::Hold)
+}
+
+/// Resample timestamped poses at fixed time step over the given time range, poses
+/// outside of the recorded time range are produced according to `extrapolation` policy.
+/// Returns `None` if there are no samples, timestamps are not finite and strictly increasing,
+/// the time range is not finite or time step is not positive and finite.
+pub fn resample_between<T>(
+    samples: &[(T, Pose<T, T>)],
+    start: T,
+    end: T,
+    dt: T,
+    extrapolation: Extrapolation,
+) -> Option<Resample<'_, T>>
+where
+    T: Float + Real,
+{
+    if samples.is_empty()
+        || samples.iter().any(|(time, _)| !time.is_finite())
+        || samples.windows(2).any(|pair| pair[1].0 <= pair[0].0)
+        || !start.is_finite()
+        || !end.is_finite()
+        || !dt.is_finite()
+        || dt <= T::zero()
+    {
+        return None;
+    }
+    Some(Resample {
+        samples,
+        start,
+        end,
+        dt,
+        extrapolation,
+        step: T::zero(),
+        segment: 0,
+    })
+}
+
+/// Iterator over uniformly resampled poses, see [`resample`].
+#[derive(Clone, Debug)]
+pub struct Resample<'a, T> {
+    samples: &'a [(T, Pose<T, T>)],
+    start: T,
+    end: T,
+    dt: T,
+    extrapolation: Extrapolation,
+    step: T,
+    segment: usize,
+}
+
+impl<'a, T> Iterator for Resample<'a, T>
+where
+    T: Float + Real,
+{
+    type Item = (T, Pose<T, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let time = self.start + self.step * self.dt;
+        if time > self.end {
+            return None;
+        }
+        self.step = self.step + T::one();
+        let last = self.samples.len() - 1;
+        if last == 0 {
+            return Some((time, self.samples[0].1));
+        }
+        while self.segment + 1 < last && self.samples[self.segment + 1].0 <= time {
+            self.segment += 1;
+        }
+        let (start_time, start) = self.samples[self.segment];
+        let (end_time, end) = self.samples[self.segment + 1];
+        let progress = (time - start_time) / (end_time - start_time);
+        let progress = match self.extrapolation {
+            Extrapolation::Hold => progress.max(T::zero()).min(T::one()),
+            Extrapolation::Linear => progress,
+        };
+        Some((time, start.interpolate(end, progress)))
+    }
+}
+
+/// Smooth orientation curve passing through timestamped keyframes.
+///
+/// Consecutive keyframes are connected with [`Quaternion::squad`], inner control quaternions
+/// are computed from the neighbouring keyframes such that the angular velocity is continuous
+/// at every keyframe, including non-uniformly spaced ones.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::trajectory::SquadCurve;
+/// # use core::f64::consts::FRAC_PI_2;
+/// let keys = [
+///     (0.0, Quaternion::identity()),
+///     (1.0, Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z())),
+///     (3.0, Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_x())),
+/// ];
+/// let curve = SquadCurve::new(&keys).unwrap();
+/// let result = curve.sample(1.0);
+/// assert!((result.dot(keys[1].1) - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct SquadCurve<'a, T> {
+    keys: &'a [(T, Quaternion<T>)],
+}
+
+impl<'a, T> SquadCurve<'a, T>
+where
+    T: Float + Real,
+{
+    /// Create curve over given keyframes.
+    /// Returns `None` if there are no keyframes or timestamps are not strictly increasing.
+    pub fn new(keys: &'a [(T, Quaternion<T>)]) -> Option<Self> {
+        if keys.is_empty() || keys.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+            None
+        } else {
+            Some(Self { keys })
+        }
+    }
+
+    /// Get timestamp of the first keyframe.
+    pub fn start_time(&self) -> T {
+        self.keys[0].0
+    }
+
+    /// Get timestamp of the last keyframe.
+    pub fn end_time(&self) -> T {
+        self.keys[self.keys.len() - 1].0
+    }
+
+    /// Sample orientation at given time, times outside of the keyframe range are clamped.
+    pub fn sample(&self, time: T) -> Quaternion<T> {
+        let last = self.keys.len() - 1;
+        if last == 0 || time <= self.start_time() {
+            return self.keys[0].1;
+        }
+        if time >= self.end_time() {
+            return self.keys[last].1;
+        }
+        let index = self.keys.partition_point(|key| key.0 <= time) - 1;
+        let (start_time, start) = self.keys[index];
+        let (end_time, end) = self.keys[index + 1];
+        let end = align(start, end);
+        let progress = (time - start_time) / (end_time - start_time);
+        let a = control(self.keys, |&key| key, index, start, true);
+        let b = control(self.keys, |&key| key, index + 1, end, false);
+        start.squad(a, b, end, progress)
+    }
+}
+
+/// Owned smooth orientation curve with precomputed control quaternions, samples the same
+/// rotations as [`SquadCurve`] without recomputing logarithms of the neighbouring keyframes
+/// on every sample.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::trajectory::{SquadCurve, SquadSpline};
+/// # use core::f64::consts::FRAC_PI_2;
+/// let keys = [
+///     (0.0, Quaternion::identity()),
+///     (1.0, Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z())),
+///     (3.0, Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_x())),
+///     (3.5, Quaternion::from_angle_axis(-FRAC_PI_2, Vector::unit_y())),
+/// ];
+/// let spline = SquadSpline::new(&keys).unwrap();
+/// let curve = SquadCurve::new(&keys).unwrap();
+/// for time in [-1.0, 0.0, 0.3, 1.0, 2.2, 3.1, 3.5, 4.0] {
+///     assert!((spline.sample(time).dot(curve.sample(time)).abs() - 1.0).abs() < 1e-12);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SquadSpline<T> {
+    keys: Vec<SquadKey<T>>,
+}
+
+/// Keyframe of [`SquadSpline`] aligned to the previous keyframe, with its control quaternions.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SquadKey<T> {
+    time: T,
+    rotation: Quaternion<T>,
+    incoming: Quaternion<T>,
+    outgoing: Quaternion<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> SquadSpline<T>
+where
+    T: Float + Real,
+{
+    /// Create spline over given keyframes.
+    /// Returns `None` if there are no keyframes or timestamps are not strictly increasing.
+    pub fn new(keys: &[(T, Quaternion<T>)]) -> Option<Self> {
+        SquadCurve::new(keys)?;
+        let mut aligned: Vec<(T, Quaternion<T>)> = Vec::with_capacity(keys.len());
+        for &(time, rotation) in keys {
+            let rotation = match aligned.last() {
+                Some(&(_, previous)) => align(previous, rotation),
+                None => rotation,
+            };
+            aligned.push((time, rotation));
+        }
+        let keys = aligned
+            .iter()
+            .enumerate()
+            .map(|(index, &(time, rotation))| SquadKey {
+                time,
+                rotation,
+                incoming: control(&aligned, |&key| key, index, rotation, false),
+                outgoing: control(&aligned, |&key| key, index, rotation, true),
+            })
+            .collect();
+        Some(Self { keys })
+    }
+
+    /// Get timestamp of the first keyframe.
+    pub fn start_time(&self) -> T {
+        self.keys[0].time
+    }
+
+    /// Get timestamp of the last keyframe.
+    pub fn end_time(&self) -> T {
+        self.keys[self.keys.len() - 1].time
+    }
+
+    /// Sample orientation at given time, times outside of the keyframe range are clamped.
+    pub fn sample(&self, time: T) -> Quaternion<T> {
+        let last = self.keys.len() - 1;
+        if last == 0 || time <= self.start_time() {
+            return self.keys[0].rotation;
+        }
+        if time >= self.end_time() {
+            return self.keys[last].rotation;
+        }
+        let index = self.keys.partition_point(|key| key.time <= time) - 1;
+        let (start, end) = (self.keys[index], self.keys[index + 1]);
+        let progress = (time - start.time) / (end.time - start.time);
+        start
+            .rotation
+            .squad(start.outgoing, end.incoming, end.rotation, progress)
+    }
+}
+
+/// Compute outgoing or incoming control quaternion for keyframe with given index,
+/// `current` is the keyframe rotation aligned to the sampled segment.
+fn control<T, K>(
+    keys: &[K],
+    key: impl Fn(&K) -> (T, Quaternion<T>),
+    index: usize,
+    current: Quaternion<T>,
+    outgoing: bool,
+) -> Quaternion<T>
+where
+    T: Float + Real,
+{
+    let two = T::one() + T::one();
+    let time = key(&keys[index]).0;
+    let inverse = current.inverse();
+    let previous =
+        index
+            .checked_sub(1)
+            .map(|index| key(&keys[index]))
+            .map(|(previous_time, previous)| {
+                let log = (inverse * align(current, previous)).log();
+                (time - previous_time, log)
+            });
+    let next = keys.get(index + 1).map(|next| {
+        let (next_time, next) = key(next);
+        let log = (inverse * align(current, next)).log();
+        (next_time - time, log)
+    });
+    let offset = match (previous, next, outgoing) {
+        (Some((previous_dt, previous)), Some((next_dt, next)), true) => {
+            let velocity = (next - previous) / (previous_dt + next_dt);
+            (velocity * next_dt - next) / two
+        }
+        (Some((previous_dt, previous)), Some((next_dt, next)), false) => {
+            let velocity = (next - previous) / (previous_dt + next_dt);
+            (-previous - velocity * previous_dt) / two
+        }
+        _ => return current,
+    };
+    current * Quaternion::exp(offset)
+}
+
+/// Translation interpolation between samples of [`PoseSpline`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TranslationSpline {
+    /// Straight segments with constant velocity, same as [`Pose::interpolate`].
+    Linear,
+    /// Cubic segments with velocity at every sample equal to the difference between the
+    /// neighbouring samples divided by their time difference, one-sided at the ends.
+    CatmullRom,
+    /// Cubic segments with velocity at every sample equal to the derivative of the parabola
+    /// through the sample and its neighbours, reproduces uniformly accelerated motion exactly.
+    Hermite,
+}
+
+/// Rotation interpolation between samples of [`PoseSpline`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RotationSpline {
+    /// Constant angular velocity between samples, see [`Quaternion::slerp`].
+    Slerp,
+    /// Continuous angular velocity across samples, see [`SquadCurve`].
+    Squad,
+}
+
+/// Smooth pose curve through timestamped samples with selectable translation and rotation
+/// interpolation, samples poses and their body-frame velocity twists.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::trajectory::{PoseSpline, RotationSpline, TranslationSpline};
+/// let samples = [0.0, 1.0, 2.5].map(|time: f64| {
+///     let falling = Vector::new(time, 0.0, -4.9 * time * time);
+///     (time, Pose::new(falling, Quaternion::from_angle_axis(time, Vector::unit_z())))
+/// });
+/// let spline = PoseSpline::new(&samples, TranslationSpline::Hermite, RotationSpline::Slerp).unwrap();
+/// let pose = spline.sample(2.0);
+/// assert!((pose.translation() - Vector::new(2.0, 0.0, -19.6)).norm() < 1e-9);
+/// let twist = spline.velocity(2.0);
+/// let velocity = pose.rotation().rotate(twist.linear());
+/// assert!((velocity - Vector::new(1.0, 0.0, -19.6)).norm() < 1e-9);
+/// assert!((twist.angular() - Vector::unit_z()).norm() < 1e-9);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct PoseSpline<'a, T> {
+    samples: &'a [(T, Pose<T, T>)],
+    translation: TranslationSpline,
+    rotation: RotationSpline,
+}
+
+impl<'a, T> PoseSpline<'a, T>
+where
+    T: Float + Real,
+{
+    /// Create spline over given samples.
+    /// Returns `None` if there are no samples or timestamps are not strictly increasing.
+    pub fn new(
+        samples: &'a [(T, Pose<T, T>)],
+        translation: TranslationSpline,
+        rotation: RotationSpline,
+    ) -> Option<Self> {
+        if samples.is_empty() || samples.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+            None
+        } else {
+            Some(Self {
+                samples,
+                translation,
+                rotation,
+            })
+        }
+    }
+
+    /// Get timestamp of the first sample.
+    pub fn start_time(&self) -> T {
+        self.samples[0].0
+    }
+
+    /// Get timestamp of the last sample.
+    pub fn end_time(&self) -> T {
+        self.samples[self.samples.len() - 1].0
+    }
+
+    /// Sample pose at given time, times outside of the sample range are clamped.
+    pub fn sample(&self, time: T) -> Pose<T, T> {
+        match self.segment(time) {
+            Some((index, progress)) => Pose::new(
+                self.hermite(index, progress, false),
+                self.rotation_at(index, progress),
+            ),
+            None if time < self.start_time() => self.samples[0].1,
+            None => self.samples[self.samples.len() - 1].1,
+        }
+    }
+
+    /// Calculate body-frame velocity twist at given time, like [`Pose::log`] twists.
+    /// Velocity is zero outside of the sample range. Squad angular velocity is estimated by
+    /// central difference within the segment.
+    pub fn velocity(&self, time: T) -> Twist<T, T> {
+        let Some((index, progress)) = self.segment(time) else {
+            return Twist::new(Vector::zero(), Vector::zero());
+        };
+        let dt = self.samples[index + 1].0 - self.samples[index].0;
+        let rotation = self.rotation_at(index, progress);
+        let linear = rotation
+            .inverse()
+            .rotate(self.hermite(index, progress, true) / dt);
+        let angular = match self.rotation {
+            RotationSpline::Slerp => {
+                let (start, end) = (self.samples[index].1, self.samples[index + 1].1);
+                (start.rotation().inverse() * end.rotation()).log() / dt
+            }
+            RotationSpline::Squad => {
+                #[cfg(not(feature = "deterministic"))]
+                let step = <T as Float>::epsilon().cbrt();
+                #[cfg(feature = "deterministic")]
+                let step = crate::math::via_f64(<T as Float>::epsilon(), crate::math::cbrt);
+                let before = (progress - step).max(T::zero());
+                let after = (progress + step).min(T::one());
+                let difference =
+                    self.rotation_at(index, before).inverse() * self.rotation_at(index, after);
+                difference.log() / ((after - before) * dt)
+            }
+        };
+        Twist::new(linear, angular)
+    }
+
+    /// Find segment index and progress within it, `None` outside of the sample range.
+    fn segment(&self, time: T) -> Option<(usize, T)> {
+        let last = self.samples.len() - 1;
+        if last == 0 || time.is_nan() || time < self.start_time() || time > self.end_time() {
+            return None;
+        }
+        let index = self.samples.partition_point(|(sample, _)| *sample <= time) - 1;
+        let index = index.min(last - 1);
+        let (start_time, end_time) = (self.samples[index].0, self.samples[index + 1].0);
+        Some((index, (time - start_time) / (end_time - start_time)))
+    }
+
+    /// Interpolate translation of the segment, or its derivative by progress.
+    fn hermite(&self, index: usize, progress: T, derivative: bool) -> Vector<T> {
+        let (start_time, start) = self.samples[index];
+        let (end_time, end) = self.samples[index + 1];
+        let (start, end) = (start.translation(), end.translation());
+        if self.translation == TranslationSpline::Linear {
+            return if derivative {
+                end - start
+            } else {
+                start + (end - start) * progress
+            };
+        }
+        let (one, two) = (T::one(), T::one() + T::one());
+        let (three, s, squared) = (two + one, progress, progress * progress);
+        // Hermite basis weights of start, start velocity, end and end velocity.
+        let [a, b, c, d] = if derivative {
+            let six = three * two;
+            [
+                six * squared - six * s,
+                three * squared - two * two * s + one,
+                six * s - six * squared,
+                three * squared - two * s,
+            ]
+        } else {
+            let cubed = squared * s;
+            [
+                two * cubed - three * squared + one,
+                cubed - two * squared + s,
+                three * squared - two * cubed,
+                cubed - squared,
+            ]
+        };
+        let dt = end_time - start_time;
+        start * a + self.tangent(index) * (dt * b) + end * c + self.tangent(index + 1) * (dt * d)
+    }
+
+    /// Calculate translation velocity at the sample with given index.
+    fn tangent(&self, index: usize) -> Vector<T> {
+        let last = self.samples.len() - 1;
+        let slope = |index: usize| {
+            let (start_time, start) = self.samples[index];
+            let (end_time, end) = self.samples[index + 1];
+            let dt = end_time - start_time;
+            ((end.translation() - start.translation()) / dt, dt)
+        };
+        if last == 1 {
+            return slope(0).0;
+        }
+        match (self.translation, index) {
+            (TranslationSpline::CatmullRom, 0) => slope(0).0,
+            (TranslationSpline::CatmullRom, index) if index == last => slope(last - 1).0,
+            (TranslationSpline::CatmullRom, index) => {
+                let (previous_time, previous) = self.samples[index - 1];
+                let (next_time, next) = self.samples[index + 1];
+                (next.translation() - previous.translation()) / (next_time - previous_time)
+            }
+            (_, 0) => {
+                let ((first, first_dt), (second, second_dt)) = (slope(0), slope(1));
+                let outer = first_dt + first_dt + second_dt;
+                (first * outer - second * first_dt) / (first_dt + second_dt)
+            }
+            (_, index) if index == last => {
+                let ((first, first_dt), (second, second_dt)) = (slope(last - 2), slope(last - 1));
+                let outer = second_dt + second_dt + first_dt;
+                (second * outer - first * second_dt) / (first_dt + second_dt)
+            }
+            (_, index) => {
+                let ((first, first_dt), (second, second_dt)) = (slope(index - 1), slope(index));
+                (first * second_dt + second * first_dt) / (first_dt + second_dt)
+            }
+        }
+    }
+
+    /// Interpolate rotation of the segment.
+    fn rotation_at(&self, index: usize, progress: T) -> Quaternion<T> {
+        let start = self.samples[index].1.rotation();
+        let end = align(start, self.samples[index + 1].1.rotation());
+        match self.rotation {
+            RotationSpline::Slerp => start.slerp(end, progress),
+            RotationSpline::Squad => {
+                let key = |&(time, pose): &(T, Pose<T, T>)| (time, pose.rotation());
+                let a = control(self.samples, key, index, start, true);
+                let b = control(self.samples, key, index + 1, end, false);
+                start.squad(a, b, end, progress)
+            }
+        }
+    }
+}
+
+/// Growable sequence of timestamped poses with strictly increasing timestamps.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::trajectory::Trajectory;
+/// let mut trajectory = Trajectory::new();
+/// for step in 0..1000 {
+///     let time = step as f64 * 0.01;
+///     let pose = Pose::new(Vector::new(time, 0.0, 0.0), Quaternion::identity());
+///     assert!(trajectory.push(time, pose));
+/// }
+/// assert!(!trajectory.push(0.5, Pose::new(Vector::zero(), Quaternion::identity())));
+/// let pose = trajectory.at(2.345).unwrap();
+/// assert!((pose.translation() - Vector::new(2.345, 0.0, 0.0)).norm() < 1e-9);
+/// assert_eq!(trajectory.resample(0.5).unwrap().count(), 20);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Trajectory<T> {
+    samples: Vec<(T, Pose<T, T>)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Trajectory<T>
+where
+    T: Float + Real,
+{
+    /// Create empty trajectory.
+    pub fn new() -> Self {
+        Self {
+            samples: Vec::new(),
+        }
+    }
+
+    /// Create trajectory from samples, `None` if timestamps are not finite and strictly
+    /// increasing.
+    pub fn from_samples(samples: Vec<(T, Pose<T, T>)>) -> Option<Self> {
+        if samples.iter().any(|(time, _)| !time.is_finite())
+            || samples.windows(2).any(|pair| pair[1].0 <= pair[0].0)
+        {
+            return None;
+        }
+        Some(Self { samples })
+    }
+
+    /// Get number of samples.
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check whether there are no samples.
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Get samples in time order.
+    pub fn samples(&self) -> &[(T, Pose<T, T>)] {
+        &self.samples
+    }
+
+    /// Append sample, returns `false` if time is not finite or not after the latest sample.
+    pub fn push(&mut self, time: T, pose: Pose<T, T>) -> bool {
+        if !time.is_finite() || self.samples.last().is_some_and(|(last, _)| time <= *last) {
+            return false;
+        }
+        self.samples.push((time, pose));
+        true
+    }
+
+    /// Get pose at given time interpolated between the enclosing samples,
+    /// `None` if time is outside of the recorded time range.
+    pub fn at(&self, time: T) -> Option<Pose<T, T>> {
+        let (start_time, _) = *self.samples.first()?;
+        let (end_time, end) = *self.samples.last()?;
+        if time.is_nan() || time < start_time || time > end_time {
+            return None;
+        }
+        if time == end_time {
+            return Some(end);
+        }
+        let upper = self.samples.partition_point(|(sample, _)| *sample <= time);
+        let (start_time, start) = self.samples[upper - 1];
+        let (end_time, end) = self.samples[upper];
+        let progress = (time - start_time) / (end_time - start_time);
+        Some(start.interpolate(end, progress))
+    }
+
+    /// Create smooth curve through the samples, `None` if there are no samples.
+    pub fn spline(
+        &self,
+        translation: TranslationSpline,
+        rotation: RotationSpline,
+    ) -> Option<PoseSpline<'_, T>> {
+        PoseSpline::new(&self.samples, translation, rotation)
+    }
+
+    /// Resample trajectory at fixed time step, see [`resample`].
+    pub fn resample(&self, dt: T) -> Option<Resample<'_, T>> {
+        resample(&self.samples, dt)
+    }
+}
+
+/// Predict pose at given time assuming constant body-frame twist.
+///
+/// The twist is fitted to the given recent samples as the duration-weighted mean of the twists
+/// between consecutive samples, the last sample is then extrapolated with it.
+/// Returns `None` if there are no samples or timestamps are not strictly increasing.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::trajectory::predict;
+/// let samples = [
+///     (0.0, Pose::new(Vector::new(0.0, 0.0, 0.0), Quaternion::identity())),
+///     (0.1, Pose::new(Vector::new(0.1, 0.0, 0.0), Quaternion::identity())),
+///     (0.3, Pose::new(Vector::new(0.3, 0.0, 0.0), Quaternion::identity())),
+/// ];
+/// let result = predict(&samples, 0.5).unwrap();
+/// assert!((result.translation() - Vector::new(0.5, 0.0, 0.0)).norm() < 1e-9);
+/// ```
+pub fn predict<T>(samples: &[(T, Pose<T, T>)], time: T) -> Option<Pose<T, T>>
+where
+    T: Float + Real,
+{
+    let &(last_time, last) = samples.last()?;
+    if samples.windows(2).any(|pair| pair[1].0 <= pair[0].0) {
+        return None;
+    }
+    if samples.len() == 1 {
+        return Some(last);
+    }
+    let zero = Twist::new(Vector::zero(), Vector::zero());
+    let sum = samples.windows(2).fold(zero, |sum, pair| {
+        let (start, end) = (pair[0].1, pair[1].1);
+        sum + (start.inverse() * end).log()
+    });
+    let twist = sum / (last_time - samples[0].0);
+    Some(last.extrapolate(twist, time - last_time))
+}
+
+/// Calculate total translational length of the path through given poses.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::trajectory::path_length;
+/// let poses = [
+///     Pose::new(Vector::new(0.0_f64, 0.0, 0.0), Quaternion::identity()),
+///     Pose::new(Vector::new(3.0, 0.0, 0.0), Quaternion::identity()),
+///     Pose::new(Vector::new(3.0, 4.0, 0.0), Quaternion::identity()),
+/// ];
+/// assert!((path_length(&poses) - 7.0).abs() < 1e-9);
+/// ```
+pub fn path_length<T>(poses: &[Pose<T, T>]) -> T
+where
+    T: Float + Real,
+{
+    poses.windows(2).fold(T::zero(), |length, pair| {
+        length + (pair[1].translation() - pair[0].translation()).norm()
+    })
+}
+
+/// Calculate total rotation angle accumulated along the path through given poses.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::trajectory::rotation_length;
+/// # use core::f64::consts::{FRAC_PI_2, PI};
+/// let poses = [
+///     Pose::new(Vector::zero(), Quaternion::identity()),
+///     Pose::new(Vector::zero(), Quaternion::from_angle_axis(FRAC_PI_2, Vector::unit_z())),
+///     Pose::new(Vector::zero(), Quaternion::identity()),
+/// ];
+/// assert!((rotation_length(&poses) - PI).abs() < 1e-9);
+/// ```
+pub fn rotation_length<T>(poses: &[Pose<T, T>]) -> T
+where
+    T: Float + Real,
+{
+    poses.windows(2).fold(T::zero(), |length, pair| {
+        length + pair[0].rotation().angle_to(pair[1].rotation())
+    })
+}
+
+/// Calculate maximum deviation of `other` path from `reference` path.
+///
+/// Every pose of `other` is matched with the closest point of `reference` path, where
+/// segments between consecutive reference poses are interpolated with [`Pose::interpolate`].
+/// Returns maximum translational distance and maximum rotation angle between matched poses,
+/// or `None` if any of the paths is empty.
+/// ```
+/// # use spatial::vector::Vector;
+/// # use spatial::quaternion::Quaternion;
+/// # use spatial::pose::Pose;
+/// # use spatial::trajectory::max_deviation;
+/// let planned = [
+///     Pose::new(Vector::new(0.0_f64, 0.0, 0.0), Quaternion::identity()),
+///     Pose::new(Vector::new(10.0, 0.0, 0.0), Quaternion::identity()),
+/// ];
+/// let executed = [
+///     Pose::new(Vector::new(0.0, 0.0, 0.0), Quaternion::identity()),
+///     Pose::new(Vector::new(4.0, 0.5, 0.0), Quaternion::identity()),
+///     Pose::new(Vector::new(10.0, 0.0, 0.0), Quaternion::identity()),
+/// ];
+/// let (translation, rotation) = max_deviation(&planned, &executed).unwrap();
+/// assert!((translation - 0.5).abs() < 1e-9);
+/// assert!(rotation.abs() < 1e-9);
+/// ```
+pub fn max_deviation<T>(reference: &[Pose<T, T>], other: &[Pose<T, T>]) -> Option<(T, T)>
+where
+    T: Float + Real,
+{
+    let first = *reference.first()?;
+    other.first()?;
+    let deviation = other.iter().fold((T::zero(), T::zero()), |maximum, pose| {
+        let point = pose.translation();
+        let closest = reference
+            .windows(2)
+            .map(|pair| {
+                let (start, end) = (pair[0], pair[1]);
+                let direction = end.translation() - start.translation();
+                let squared = direction.dot(direction);
+                let progress = if squared > T::zero() {
+                    let progress = (point - start.translation()).dot(direction) / squared;
+                    progress.max(T::zero()).min(T::one())
+                } else {
+                    T::zero()
+                };
+                start.interpolate(end, progress)
+            })
+            .fold(first, |closest, candidate| {
+                let closest_distance = (closest.translation() - point).norm();
+                if (candidate.translation() - point).norm() < closest_distance {
+                    candidate
+                } else {
+                    closest
+                }
+            });
+        let (translation, rotation) = closest.distance_to(*pose);
+        (maximum.0.max(translation), maximum.1.max(rotation))
+    });
+    Some(deviation)
+}
+
+/// Flip sign of `other` to lie in the same hemisphere as `reference`.
+fn align<T>(reference: Quaternion<T>, other: Quaternion<T>) -> Quaternion<T>
+where
+    T: Float,
+{
+    if reference.dot(other) < T::zero() {
+        -other
+    } else {
+        other
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        resample, resample_between, Extrapolation, PoseSpline, RotationSpline, SquadCurve,
+        TranslationSpline,
+    };
+    use crate::pose::Pose;
+    use crate::quaternion::Quaternion;
+    use crate::vector::Vector;
+
+    #[test]
+    fn pose_spline_velocity_matches_sampled_motion() {
+        let samples = [0.0, 0.4, 1.0, 1.5, 3.0].map(|time: f64| {
+            let translation = Vector::new(time.sin(), time * time, 1.0 - time);
+            let rotation = Quaternion::from_angle_axis(time * time, Vector::new(1.0, 2.0, 0.5));
+            (time, Pose::new(translation, rotation))
+        });
+        let step = 1e-6;
+        for translation in [
+            TranslationSpline::Linear,
+            TranslationSpline::CatmullRom,
+            TranslationSpline::Hermite,
+        ] {
+            for rotation in [RotationSpline::Slerp, RotationSpline::Squad] {
+                let spline = PoseSpline::new(&samples, translation, rotation).unwrap();
+                for &(time, pose) in &samples {
+                    let sampled = spline.sample(time);
+                    assert!((sampled.translation() - pose.translation()).norm() < 1e-12);
+                    assert!((sampled.rotation().dot(pose.rotation()).abs() - 1.0).abs() < 1e-12);
+                }
+                for time in [0.1, 0.7, 1.2, 2.9] {
+                    let (before, after) = (spline.sample(time - step), spline.sample(time + step));
+                    let expected = (before.inverse() * after).log() / (2.0 * step);
+                    let twist = spline.velocity(time);
+                    assert!((twist.linear() - expected.linear()).norm() < 1e-5);
+                    assert!((twist.angular() - expected.angular()).norm() < 1e-5);
+                }
+                assert_eq!(spline.sample(-1.0), samples[0].1);
+                assert_eq!(spline.sample(4.0), samples[4].1);
+                assert_eq!(spline.velocity(4.0).linear(), Vector::new(0.0, 0.0, 0.0));
+            }
+        }
+        let spline =
+            PoseSpline::new(&samples, TranslationSpline::Hermite, RotationSpline::Slerp).unwrap();
+        let translation = spline.sample(2.0).translation();
+        assert!((translation.y() - 4.0).abs() < 1e-12);
+        assert!((translation.z() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn squad_curve_is_smooth_at_keyframes() {
+        let keys = [
+            (0.0, Quaternion::identity()),
+            (0.5, Quaternion::from_angle_axis(0.7, Vector::unit_z())),
+            (
+                2.0,
+                Quaternion::from_angle_axis(1.2, Vector::new(1.0, 1.0, 0.0)),
+            ),
+            (2.5, Quaternion::from_angle_axis(-0.4, Vector::unit_y())),
+        ];
+        let curve = SquadCurve::new(&keys).unwrap();
+        let step = 1e-5;
+        for &(time, _) in &keys[1..3] {
+            let before = (curve.sample(time - step).inverse() * curve.sample(time)).log() / step;
+            let after = (curve.sample(time).inverse() * curve.sample(time + step)).log() / step;
+            assert!((before - after).norm() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn resampling_rejects_non_finite_range() {
+        let samples = [(0.0, Pose::identity()), (1.0, Pose::identity())];
+        let between = |start: f64, end: f64, dt: f64| {
+            resample_between(&samples, start, end, dt, Extrapolation::Hold).is_some()
+        };
+        assert!(between(-1.0, 2.0, 0.5));
+        assert!(!between(0.0, f64::NAN, 0.5));
+        assert!(!between(f64::NEG_INFINITY, 1.0, 0.5));
+        assert!(!between(0.0, f64::INFINITY, 0.5));
+        assert!(!between(0.0, 1.0, f64::INFINITY));
+        let unknown = [(0.0, Pose::identity()), (f64::NAN, Pose::identity())];
+        assert!(resample(&unknown, 0.5).is_none());
+        assert_eq!(resample(&samples, 0.25).unwrap().count(), 5);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn trajectory_rejects_unordered_and_non_finite_times() {
+        use super::Trajectory;
+        use alloc::vec;
+        let pose = Pose::<f64, f64>::identity();
+        assert!(Trajectory::from_samples(vec![(0.0, pose), (1.0, pose)]).is_some());
+        assert!(Trajectory::from_samples(vec![(1.0, pose), (1.0, pose)]).is_none());
+        assert!(Trajectory::from_samples(vec![(0.0, pose), (f64::NAN, pose)]).is_none());
+        assert!(Trajectory::from_samples(vec![(f64::NAN, pose), (0.0, pose)]).is_none());
+        assert!(Trajectory::from_samples(vec![(f64::INFINITY, pose)]).is_none());
+        let mut trajectory = Trajectory::new();
+        assert!(!trajectory.push(f64::NEG_INFINITY, pose));
+        assert!(trajectory.push(0.0, pose));
+        assert_eq!(trajectory.len(), 1);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::vector::Vector;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Spatial velocity, pair of linear and angular velocities.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Twist<V, W> {
+    linear: Vector<V>,
+    angular: Vector<W>,
+}
+
+impl<V, W> Twist<V, W> {
+    /// Create new `Twist` with given linear and angular velocities.
+    pub const fn new(linear: Vector<V>, angular: Vector<W>) -> Self {
+        Self { linear, angular }
+    }
+
+    /// Get reference to stored linear velocity.
+    pub fn linear_ref(&self) -> &Vector<V> {
+        &self.linear
+    }
+
+    /// Get reference to stored angular velocity.
+    pub fn angular_ref(&self) -> &Vector<W> {
+        &self.angular
+    }
+
+    /// Get mutable reference to stored linear velocity.
+    pub fn linear_mut(&mut self) -> &mut Vector<V> {
+        &mut self.linear
+    }
+
+    /// Get mutable reference to stored angular velocity.
+    pub fn angular_mut(&mut self) -> &mut Vector<W> {
+        &mut self.angular
+    }
+
+    /// Set linear velocity.
+    pub fn set_linear(&mut self, linear: Vector<V>) {
+        self.linear = linear;
+    }
+
+    /// Set angular velocity.
+    pub fn set_angular(&mut self, angular: Vector<W>) {
+        self.angular = angular;
+    }
+
+    /// Get linear velocity.
+    pub fn linear(&self) -> Vector<V>
+    where
+        V: Copy,
+    {
+        self.linear
+    }
+
+    /// Get angular velocity.
+    pub fn angular(&self) -> Vector<W>
+    where
+        W: Copy,
+    {
+        self.angular
+    }
+}
+
+/// Implement constant twists for given floating point type.
+macro_rules! impl_twist_constants {
+    ($($float:ty),+) => {$(
+        impl Twist<$float, $float> {
+            /// Twist with zero linear and angular velocities.
+            pub const ZERO: Self = Self::new(Vector::<$float>::ZERO, Vector::<$float>::ZERO);
+        }
+    )+};
+}
+
+impl_twist_constants!(f32, f64);
+
+/// Scale both linear and angular velocities, e.g. by time step.
+impl<V, W, U, VR, WR> Mul<U> for Twist<V, W>
+where
+    V: Mul<U, Output = VR>,
+    W: Mul<U, Output = WR>,
+    U: Copy,
+{
+    type Output = Twist<VR, WR>;
+    fn mul(self, scalar: U) -> Self::Output {
+        Twist {
+            linear: self.linear * scalar,
+            angular: self.angular * scalar,
+        }
+    }
+}
+
+/// Divide both linear and angular velocities, e.g. by time step.
+impl<V, W, U, VR, WR> Div<U> for Twist<V, W>
+where
+    V: Div<U, Output = VR>,
+    W: Div<U, Output = WR>,
+    U: Copy,
+{
+    type Output = Twist<VR, WR>;
+    fn div(self, scalar: U) -> Self::Output {
+        Twist {
+            linear: self.linear / scalar,
+            angular: self.angular / scalar,
+        }
+    }
+}
+
+impl<V, W> Add for Twist<V, W>
+where
+    V: Add<Output = V>,
+    W: Add<Output = W>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            linear: self.linear + other.linear,
+            angular: self.angular + other.angular,
+        }
+    }
+}
+
+impl<V, W> Sub for Twist<V, W>
+where
+    V: Sub<Output = V>,
+    W: Sub<Output = W>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            linear: self.linear - other.linear,
+            angular: self.angular - other.angular,
+        }
+    }
+}
+
+impl<V, W> Neg for Twist<V, W>
+where
+    V: Neg<Output = V>,
+    W: Neg<Output = W>,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            linear: -self.linear,
+            angular: -self.angular,
+        }
+    }
+}
+
+impl<V, W> AddAssign for Twist<V, W>
+where
+    V: AddAssign,
+    W: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.linear += other.linear;
+        self.angular += other.angular;
+    }
+}
+
+impl<V, W> SubAssign for Twist<V, W>
+where
+    V: SubAssign,
+    W: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.linear -= other.linear;
+        self.angular -= other.angular;
+    }
+}
+
+impl<V, W, U> MulAssign<U> for Twist<V, W>
+where
+    V: MulAssign<U>,
+    W: MulAssign<U>,
+    U: Copy,
+{
+    fn mul_assign(&mut self, scalar: U) {
+        self.linear *= scalar;
+        self.angular *= scalar;
+    }
+}
+
+impl<V, W, U> DivAssign<U> for Twist<V, W>
+where
+    V: DivAssign<U>,
+    W: DivAssign<U>,
+    U: Copy,
+{
+    fn div_assign(&mut self, scalar: U) {
+        self.linear /= scalar;
+        self.angular /= scalar;
+    }
+}
+
+/// Implement scalar multiplication from the left for given floating point type.
+macro_rules! impl_twist_scalar_operators {
+    ($($float:ty),+) => {$(
+        impl Mul<Twist<$float, $float>> for $float {
+            type Output = Twist<$float, $float>;
+            fn mul(self, twist: Twist<$float, $float>) -> Self::Output {
+                twist * self
+            }
+        }
+    )+};
+}
+
+impl_twist_scalar_operators!(f32, f64);
@@ -0,0 +1,317 @@
+use crate::chain::FrameTree;
+use crate::error::UrdfError;
+use crate::pose::Pose;
+use std::string::String;
+use std::vec::Vec;
+
+/// Joint of a robot description, pose of the child link frame relative to the parent one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Joint {
+    name: String,
+    parent: String,
+    child: String,
+    origin: Pose<f64, f64>,
+}
+
+impl Joint {
+    /// Get joint name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get name of the parent link.
+    pub fn parent(&self) -> &str {
+        &self.parent
+    }
+
+    /// Get name of the child link.
+    pub fn child(&self) -> &str {
+        &self.child
+    }
+
+    /// Get pose of the child link relative to the parent link at zero joint position.
+    pub fn origin(&self) -> Pose<f64, f64> {
+        self.origin
+    }
+}
+
+/// Parse the first `<origin xyz="..." rpy="..."/>` element of the fragment into pose, missing
+/// attributes default to zero. Angles are roll, pitch and yaw in radians with the convention
+/// of [`Quaternion::from_rpy`](crate::quaternion::Quaternion::from_rpy), which matches URDF.
+/// ```
+/// # use spatial::urdf::parse_origin;
+/// # use spatial::vector::Vector;
+/// let pose = parse_origin(r#"<origin xyz="1 0 0.5" rpy="0 0 1.5707963267948966"/>"#).unwrap();
+/// assert!((pose.translation() - Vector::new(1.0, 0.0, 0.5)).norm() < 1e-12);
+/// let turned = pose.rotation().rotate(Vector::<f64>::unit_x());
+/// assert!((turned - Vector::unit_y()).norm() < 1e-12);
+/// ```
+pub fn parse_origin(fragment: &str) -> Result<Pose<f64, f64>, UrdfError> {
+    for tag in tags(fragment) {
+        let tag = tag?;
+        if tag.name == "origin" {
+            return origin(tag.attributes);
+        }
+    }
+    Err(UrdfError::MissingOrigin)
+}
+
+/// Parse all joints of the robot description in document order. Joints without `<origin>`
+/// have identity pose, joints nested in other elements, e.g. transmissions, are skipped.
+pub fn parse_joints(document: &str) -> Result<Vec<Joint>, UrdfError> {
+    let mut joints = Vec::new();
+    let mut current: Option<(usize, PartialJoint)> = None;
+    let mut depth: usize = 0;
+    for tag in tags(document) {
+        let tag = tag?;
+        if let Some(closed) = tag.name.strip_prefix('/') {
+            depth = depth.checked_sub(1).ok_or(UrdfError::Malformed)?;
+            match current.take() {
+                Some((level, joint)) if closed == "joint" && level == depth => {
+                    joints.push(joint.finish()?);
+                }
+                other => current = other,
+            }
+            continue;
+        }
+        match &mut current {
+            Some((level, joint)) if depth == *level + 1 => match tag.name {
+                "parent" => joint.parent = attribute(tag.attributes, "link")?.map(String::from),
+                "child" => joint.child = attribute(tag.attributes, "link")?.map(String::from),
+                "origin" => joint.origin = origin(tag.attributes)?,
+                _ => {}
+            },
+            None if tag.name == "joint" && depth <= 1 => {
+                let joint = PartialJoint {
+                    name: attribute(tag.attributes, "name")?.map(String::from),
+                    parent: None,
+                    child: None,
+                    origin: Pose::identity(),
+                };
+                if tag.empty {
+                    joints.push(joint.finish()?);
+                } else {
+                    current = Some((depth, joint));
+                }
+            }
+            _ => {}
+        }
+        if !tag.empty {
+            depth += 1;
+        }
+    }
+    match current {
+        Some(_) => Err(UrdfError::Malformed),
+        None => Ok(joints),
+    }
+}
+
+/// Parse joints of the robot description into tree of link frames.
+/// ```
+/// # use spatial::urdf::parse_frame_tree;
+/// # use spatial::vector::Vector;
+/// let robot = r#"
+///     <robot name="arm">
+///         <link name="base"/>
+///         <joint name="shoulder" type="revolute">
+///             <parent link="base"/>
+///             <child link="upper"/>
+///             <origin xyz="0 0 0.5"/>
+///         </joint>
+///         <joint name="elbow" type="revolute">
+///             <origin xyz="0.3 0 0" rpy="0 0 3.141592653589793"/>
+///             <parent link="upper"/>
+///             <child link="lower"/>
+///         </joint>
+///     </robot>
+/// "#;
+/// let tree = parse_frame_tree(robot).unwrap();
+/// let lower = tree.pose_of(&"lower".into(), &"base".into()).unwrap();
+/// assert!((lower.translation() - Vector::new(0.3, 0.0, 0.5)).norm() < 1e-12);
+/// ```
+pub fn parse_frame_tree(document: &str) -> Result<FrameTree<String, f64, f64>, UrdfError> {
+    let mut tree = FrameTree::new();
+    for joint in parse_joints(document)? {
+        tree.insert(joint.child, joint.parent, joint.origin)?;
+    }
+    Ok(tree)
+}
+
+/// Joint element being parsed.
+struct PartialJoint {
+    name: Option<String>,
+    parent: Option<String>,
+    child: Option<String>,
+    origin: Pose<f64, f64>,
+}
+
+impl PartialJoint {
+    /// Check that name and both links are present.
+    fn finish(self) -> Result<Joint, UrdfError> {
+        match (self.name, self.parent, self.child) {
+            (Some(name), Some(parent), Some(child)) => Ok(Joint {
+                name,
+                parent,
+                child,
+                origin: self.origin,
+            }),
+            _ => Err(UrdfError::IncompleteJoint),
+        }
+    }
+}
+
+/// Start, end or empty element tag, `name` of end tags starts with `/`.
+struct Tag<'a> {
+    name: &'a str,
+    attributes: &'a str,
+    empty: bool,
+}
+
+/// Iterate over element tags of the document skipping comments, declarations and text.
+fn tags(document: &str) -> impl Iterator<Item = Result<Tag<'_>, UrdfError>> {
+    let mut rest = document;
+    core::iter::from_fn(move || loop {
+        rest = &rest[rest.find('<')?..];
+        let terminator = if rest.starts_with("<!--") {
+            "-->"
+        } else if rest.starts_with("<?") {
+            "?>"
+        } else {
+            ">"
+        };
+        let Some(end) = rest.find(terminator) else {
+            rest = "";
+            return Some(Err(UrdfError::Malformed));
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + terminator.len()..];
+        if terminator != ">" || tag.starts_with('!') {
+            continue;
+        }
+        let (tag, empty) = match tag.strip_suffix('/') {
+            Some(tag) => (tag, true),
+            None => (tag, false),
+        };
+        let split = tag.find(char::is_whitespace).unwrap_or(tag.len());
+        return Some(Ok(Tag {
+            name: &tag[..split],
+            attributes: &tag[split..],
+            empty,
+        }));
+    })
+}
+
+/// Find value of the attribute in tag attributes, values may use either quote.
+fn attribute<'a>(attributes: &'a str, key: &str) -> Result<Option<&'a str>, UrdfError> {
+    let mut rest = attributes.trim_start();
+    while !rest.is_empty() {
+        let (name, value) = rest.split_once('=').ok_or(UrdfError::Malformed)?;
+        let value = value.trim_start();
+        let quote = value
+            .chars()
+            .next()
+            .filter(|quote| *quote == '"' || *quote == '\'')
+            .ok_or(UrdfError::Malformed)?;
+        let (value, tail) = value[1..].split_once(quote).ok_or(UrdfError::Malformed)?;
+        if name.trim_end() == key {
+            return Ok(Some(value));
+        }
+        rest = tail.trim_start();
+    }
+    Ok(None)
+}
+
+/// Parse `xyz` and `rpy` attributes of origin element.
+fn origin(attributes: &str) -> Result<Pose<f64, f64>, UrdfError> {
+    let [x, y, z] = triple(attribute(attributes, "xyz")?)?;
+    let [roll, pitch, yaw] = triple(attribute(attributes, "rpy")?)?;
+    Ok(Pose::<f64, f64>::from_xyz_rpy(x, y, z, roll, pitch, yaw))
+}
+
+/// Parse three whitespace separated numbers, missing value is zero.
+fn triple(value: Option<&str>) -> Result<[f64; 3], UrdfError> {
+    let mut triple = [0.0; 3];
+    if let Some(value) = value {
+        let mut numbers = value.split_whitespace().map(str::parse);
+        for slot in &mut triple {
+            *slot = numbers
+                .next()
+                .and_then(Result::ok)
+                .ok_or(UrdfError::InvalidTriple)?;
+        }
+        if numbers.next().is_some() {
+            return Err(UrdfError::InvalidTriple);
+        }
+    }
+    Ok(triple)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_frame_tree, parse_joints, parse_origin};
+    use crate::error::{FrameError, UrdfError};
+    use crate::pose::{Pose, Posed};
+    use std::vec::Vec;
+
+    const ROBOT: &str = r#"<?xml version="1.0"?>
+        <robot name="arm">
+            <!-- <joint name="commented"><parent link="a"/><child link="b"/></joint> -->
+            <link name="base">
+                <visual><origin xyz="5 5 5"/></visual>
+            </link>
+            <joint name="shoulder" type="revolute">
+                <parent link='base'/>
+                <child link="upper"/>
+                <origin rpy="0.1 -0.2 0.3" xyz="0 0 0.5"/>
+                <axis xyz="0 0 1"/>
+            </joint>
+            <joint name="elbow" type="revolute">
+                <parent link="upper"/>
+                <child link="lower"/>
+                <origin xyz="0.3 0 0" rpy="0 1.2 0"/>
+            </joint>
+            <joint name="wrist" type="fixed">
+                <parent link="lower"/>
+                <child link="tool"/>
+            </joint>
+            <transmission name="drive">
+                <joint name="elbow"><hardwareInterface>Effort</hardwareInterface></joint>
+            </transmission>
+        </robot>"#;
+
+    #[test]
+    fn joint_chain_composes_origins() {
+        let joints = parse_joints(ROBOT).unwrap();
+        let names: Vec<&str> = joints.iter().map(|joint| joint.name()).collect();
+        assert_eq!(names, ["shoulder", "elbow", "wrist"]);
+        assert_eq!(joints[2].origin(), Pose::identity());
+        let expected = Posed::from_xyz_rpy(0.0, 0.0, 0.5, 0.1, -0.2, 0.3)
+            * Posed::from_xyz_rpy(0.3, 0.0, 0.0, 0.0, 1.2, 0.0);
+        let tree = parse_frame_tree(ROBOT).unwrap();
+        let tool = tree.pose_of(&"tool".into(), &"base".into()).unwrap();
+        let (distance, angle) = tool.distance_to(expected);
+        assert!(distance < 1e-12 && angle < 1e-12);
+        assert_eq!(parse_origin("<origin/>"), Ok(Pose::identity()));
+        assert_eq!(
+            parse_origin(r#"<origin xyz="1 2"/>"#),
+            Err(UrdfError::InvalidTriple)
+        );
+        assert_eq!(parse_origin("<link/>"), Err(UrdfError::MissingOrigin));
+        assert_eq!(
+            parse_joints(r#"<joint name="loose"><parent link="a"/></joint>"#),
+            Err(UrdfError::IncompleteJoint)
+        );
+        assert_eq!(
+            parse_joints(r#"<joint name="open"><parent link="a"/>"#),
+            Err(UrdfError::Malformed)
+        );
+        let cycle = r#"<robot>
+            <joint name="a"><parent link="x"/><child link="y"/></joint>
+            <joint name="b"><parent link="y"/><child link="x"/></joint>
+        </robot>"#;
+        assert_eq!(
+            parse_frame_tree(cycle).unwrap_err(),
+            UrdfError::Frame(FrameError::Cycle)
+        );
+    }
+}
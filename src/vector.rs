@@ -1,10 +1,17 @@
-use crate::ops::Sqrt;
-use core::ops::{Add, Div, Mul, Neg, Sub};
+use crate::error::{to_array, ConversionError, ZeroNormError};
 #[cfg(feature = "num")]
-use num::{Float, One, Zero};
+use crate::ops::Real;
+use crate::ops::{Epsilon, Norm, Sqrt};
+use core::fmt::{Display, Formatter};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+#[cfg(feature = "num")]
+use num::{One, Zero};
 
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
 pub struct Vector<T> {
     x: T,
     y: T,
@@ -12,10 +19,61 @@ pub struct Vector<T> {
 }
 
 impl<T> Vector<T> {
-    pub fn new(x: T, y: T, z: T) -> Self {
+    pub const fn new(x: T, y: T, z: T) -> Self {
         Self { x, y, z }
     }
 
+    /// View coordinates as `[x, y, z]` array without copying.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let mut vector = Vector::new(1.0, 2.0, 3.0);
+    /// vector.as_mut_slice()[1] = 5.0;
+    /// assert_eq!(vector.as_slice(), &[1.0, 5.0, 3.0]);
+    /// ```
+    pub fn as_array(&self) -> &[T; 3] {
+        // SAFETY: `Vector` is `repr(C)` with three fields of type `T`, which is the layout of `[T; 3]`.
+        unsafe { &*(self as *const Self).cast::<[T; 3]>() }
+    }
+
+    /// View coordinates as mutable `[x, y, z]` array without copying.
+    pub fn as_mut_array(&mut self) -> &mut [T; 3] {
+        // SAFETY: `Vector` is `repr(C)` with three fields of type `T`, which is the layout of `[T; 3]`.
+        unsafe { &mut *(self as *mut Self).cast::<[T; 3]>() }
+    }
+
+    /// View slice of vectors as flat `[x, y, z, x, y, z, ...]` slice without copying, e.g. to
+    /// upload it into vertex buffer.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let mut points = [Vector::new(1.0, 2.0, 3.0), Vector::new(4.0, 5.0, 6.0)];
+    /// Vector::flatten_slice_mut(&mut points)[4] = 0.0;
+    /// assert_eq!(Vector::flatten_slice(&points), &[1.0, 2.0, 3.0, 4.0, 0.0, 6.0]);
+    /// ```
+    pub fn flatten_slice(vectors: &[Self]) -> &[T] {
+        // SAFETY: every vector has the layout of `[T; 3]` and arrays have no padding between
+        // elements, so `n` consecutive vectors have the layout of `[T; 3 * n]`.
+        unsafe { core::slice::from_raw_parts(vectors.as_ptr().cast::<T>(), vectors.len() * 3) }
+    }
+
+    /// View slice of vectors as flat mutable slice without copying, see
+    /// [`Vector::flatten_slice`].
+    pub fn flatten_slice_mut(vectors: &mut [Self]) -> &mut [T] {
+        // SAFETY: see `flatten_slice`.
+        unsafe {
+            core::slice::from_raw_parts_mut(vectors.as_mut_ptr().cast::<T>(), vectors.len() * 3)
+        }
+    }
+
+    /// View coordinates as `[x, y, z]` slice without copying.
+    pub fn as_slice(&self) -> &[T] {
+        self.as_array()
+    }
+
+    /// View coordinates as mutable `[x, y, z]` slice without copying.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.as_mut_array()
+    }
+
     pub fn map<F: Fn(T) -> R, R>(self, mapper: F) -> Vector<R> {
         Vector {
             x: mapper(self.x),
@@ -30,8 +88,72 @@ impl<T> Vector<T> {
     {
         self.map(Into::into)
     }
+
+    /// Convert components with `as` semantics, e.g. from `f64` to `f32`.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// assert_eq!(Vector::new(1.5_f64, -2.0, 0.25).cast::<f32>(), Vector::new(1.5_f32, -2.0, 0.25));
+    /// ```
+    #[cfg(feature = "num")]
+    pub fn cast<R>(self) -> Vector<R>
+    where
+        T: num::traits::AsPrimitive<R>,
+        R: Copy + 'static,
+    {
+        self.map(num::traits::AsPrimitive::as_)
+    }
+}
+
+/// Implement constant vectors for given floating point type.
+macro_rules! impl_vector_constants {
+    ($($float:ty),+) => {$(
+        impl Vector<$float> {
+            /// Vector with all components equal to zero.
+            pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+            /// Unit vector along the `x` axis.
+            pub const UNIT_X: Self = Self::new(1.0, 0.0, 0.0);
+            /// Unit vector along the `y` axis.
+            pub const UNIT_Y: Self = Self::new(0.0, 1.0, 0.0);
+            /// Unit vector along the `z` axis.
+            pub const UNIT_Z: Self = Self::new(0.0, 0.0, 1.0);
+        }
+    )+};
+}
+
+impl_vector_constants!(f32, f64);
+
+/// Vector with `f32` components.
+pub type Vec3f = Vector<f32>;
+/// Vector with `f64` components.
+pub type Vec3d = Vector<f64>;
+
+/// Implement convenience methods for given floating point type.
+macro_rules! impl_vector_helpers {
+    ($($float:ty),+) => {$(
+        impl Vector<$float> {
+            /// Calculate Euclidean length of this vector, with fused multiply-add under the
+            /// `fma` feature.
+            pub fn length(self) -> $float {
+                #[cfg(feature = "fma")]
+                {
+                    let z = self.z * self.z;
+                    let squared = num::Float::mul_add(self.y, self.y, z);
+                    Sqrt::sqrt(num::Float::mul_add(self.x, self.x, squared))
+                }
+                #[cfg(not(feature = "fma"))]
+                self.norm()
+            }
+
+            /// Scale vector to unit length, vectors shorter than epsilon are returned unchanged.
+            pub fn normalize(self) -> Self {
+                self.normalized_checked().unwrap_or(self)
+            }
+        }
+    )+};
 }
 
+impl_vector_helpers!(f32, f64);
+
 #[cfg(feature = "num")]
 impl<T> Vector<T>
 where
@@ -158,10 +280,26 @@ impl<T> Vector<T> {
     }
 }
 
-#[cfg(feature = "num")]
+impl<T> Norm for Vector<T>
+where
+    T: Copy + Mul,
+    T::Output: Sqrt + Add<Output = T::Output>,
+{
+    type Output = <T::Output as Sqrt>::Output;
+    fn norm(self) -> Self::Output {
+        Vector::norm(self)
+    }
+}
+
 impl<T> Vector<T>
 where
-    T: Float + Sqrt<Output = T> + Div<Output = T>,
+    T: Copy
+        + PartialOrd
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sqrt<Output = T>
+        + Epsilon,
 {
     pub fn normalized_checked(self) -> Option<Self> {
         let norm = self.norm();
@@ -171,6 +309,108 @@ where
             Some(self / norm)
         }
     }
+
+    /// Scale vector to unit norm, see [`ZeroNormError`].
+    pub fn try_normalized(self) -> Result<Self, ZeroNormError<T>> {
+        let norm = self.norm();
+        if norm < T::epsilon() {
+            Err(ZeroNormError { norm })
+        } else {
+            Ok(self / norm)
+        }
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> Vector<T>
+where
+    T: Real,
+{
+    /// Find two unit vectors completing the direction of this vector into right-handed
+    /// orthonormal frame `(self, first, second)`, by the branchless method of Duff et al.,
+    /// which is continuous everywhere except the `z = 0` plane. `None` for zero vector.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let approach = Vector::new(0.0_f64, 0.0, -2.0);
+    /// let (first, second) = approach.orthonormal_basis().unwrap();
+    /// assert!(first.dot(approach).abs() < 1e-12 && second.dot(approach).abs() < 1e-12);
+    /// assert!((first.cross(second) - Vector::new(0.0, 0.0, -1.0)).norm() < 1e-12);
+    /// ```
+    pub fn orthonormal_basis(self) -> Option<(Self, Self)> {
+        let n = self.normalized_checked()?;
+        let sign = if n.z < T::zero() { -T::one() } else { T::one() };
+        let a = -T::one() / (sign + n.z);
+        let b = n.x * n.y * a;
+        let first = Self::new(T::one() + sign * n.x * n.x * a, sign * b, -sign * n.x);
+        let second = Self::new(b, sign + n.y * n.y * a, -n.y);
+        Some((first, second))
+    }
+
+    /// Map two independent samples from the uniform `[0, 1]` distribution to unit vector
+    /// uniformly distributed on the sphere, e.g. to scatter directions with any random
+    /// number generator.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let direction = Vector::from_uniform_sphere([0.5_f64, 0.25]);
+    /// assert!((direction - Vector::unit_y()).norm() < 1e-12);
+    /// ```
+    pub fn from_uniform_sphere(samples: [T; 2]) -> Self {
+        let two = T::one() + T::one();
+        let z = T::one() - two * samples[0];
+        let squared = T::one() - z * z;
+        let radius = if squared > T::zero() {
+            squared.sqrt()
+        } else {
+            T::zero()
+        };
+        let angle = two * (-T::one()).acos() * samples[1];
+        Self::new(radius * angle.cos(), radius * angle.sin(), z)
+    }
+
+    /// Find unit vector perpendicular to this one, see [`Vector::orthonormal_basis`].
+    /// `None` for zero vector.
+    pub fn any_perpendicular(self) -> Option<Self> {
+        self.orthonormal_basis().map(|(first, _)| first)
+    }
+
+    /// Reflect vector across the plane through origin with given non-zero normal, which
+    /// doesn't have to be normalized.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let reflected = Vector::new(1.0_f64, 2.0, 3.0).reflect_across(Vector::new(0.0, 2.0, 0.0));
+    /// assert_eq!(reflected, Vector::new(1.0, -2.0, 3.0));
+    /// ```
+    pub fn reflect_across(self, normal: Self) -> Self {
+        let two = T::one() + T::one();
+        self - normal * (two * self.dot(normal) / normal.dot(normal))
+    }
+}
+
+#[cfg(feature = "simd")]
+impl Vector<f32> {
+    /// Calculate dot product using `f32x4` lanes, see the `simd` feature.
+    pub fn dot_simd(self, other: Self) -> f32 {
+        crate::simd::dot(self, other)
+    }
+
+    /// Calculate cross product using `f32x4` lanes, see the `simd` feature.
+    pub fn cross_simd(self, other: Self) -> Self {
+        crate::simd::cross_vectors(self, other)
+    }
+
+    /// Calculate Euclidean norm using `f32x4` lanes, see the `simd` feature.
+    pub fn norm_simd(self) -> f32 {
+        Sqrt::sqrt(self.dot_simd(self))
+    }
+}
+
+#[cfg(feature = "fast-math")]
+impl Vector<f32> {
+    /// Normalize vector using approximate inverse square root, see the `fast-math` feature.
+    /// Vector must have non-zero finite norm.
+    pub fn normalized_fast(self) -> Self {
+        self * crate::fast_math::inverse_sqrt(self.dot(self))
+    }
 }
 
 impl<T> Vector<T> {
@@ -208,6 +448,119 @@ impl<T> Vector<T> {
     }
 }
 
+impl<T> Vector<T> {
+    /// Multiply vectors component-wise.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let scale = Vector::new(2.0, 0.5, -1.0);
+    /// assert_eq!(Vector::new(1.0, 4.0, 3.0).hadamard(scale), Vector::new(2.0, 2.0, -3.0));
+    /// ```
+    pub fn hadamard<U, R>(self, other: Vector<U>) -> Vector<R>
+    where
+        T: Mul<U, Output = R>,
+    {
+        Vector {
+            x: self.x * other.x,
+            y: self.y * other.y,
+            z: self.z * other.z,
+        }
+    }
+
+    /// Calculate sum of the components.
+    pub fn sum(self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.x + self.y + self.z
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: PartialOrd,
+{
+    /// Get smaller of each pair of components, e.g. the minimal corner of bounding box.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let (a, b) = (Vector::new(1.0, -2.0, 3.0), Vector::new(0.0, 5.0, 3.5));
+    /// assert_eq!(a.component_min(b), Vector::new(0.0, -2.0, 3.0));
+    /// assert_eq!(a.component_max(b), Vector::new(1.0, 5.0, 3.5));
+    /// ```
+    pub fn component_min(self, other: Self) -> Self {
+        Self {
+            x: min(self.x, other.x),
+            y: min(self.y, other.y),
+            z: min(self.z, other.z),
+        }
+    }
+
+    /// Get larger of each pair of components, see [`Vector::component_min`].
+    pub fn component_max(self, other: Self) -> Self {
+        Self {
+            x: max(self.x, other.x),
+            y: max(self.y, other.y),
+            z: max(self.z, other.z),
+        }
+    }
+
+    /// Limit every component to the range between the corresponding components of `lower`
+    /// and `upper`.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let lower = Vector::new(-1.0, -1.0, 0.0);
+    /// let upper = Vector::new(1.0, 1.0, 10.0);
+    /// assert_eq!(Vector::new(-3.0, 0.5, 12.0).clamp(lower, upper), Vector::new(-1.0, 0.5, 10.0));
+    /// ```
+    pub fn clamp(self, lower: Self, upper: Self) -> Self {
+        self.component_max(lower).component_min(upper)
+    }
+
+    /// Get the smallest component.
+    /// ```
+    /// # use spatial::vector::Vector;
+    /// let vector = Vector::new(2.0, -7.0, 3.0);
+    /// assert_eq!(vector.min_element(), -7.0);
+    /// assert_eq!(vector.max_element(), 3.0);
+    /// assert_eq!(vector.abs().max_element(), 7.0);
+    /// ```
+    pub fn min_element(self) -> T {
+        min(min(self.x, self.y), self.z)
+    }
+
+    /// Get the largest component.
+    pub fn max_element(self) -> T {
+        max(max(self.x, self.y), self.z)
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Copy + PartialOrd + Neg<Output = T>,
+{
+    /// Get absolute value of every component.
+    pub fn abs(self) -> Self {
+        self.map(|value| if value < -value { -value } else { value })
+    }
+}
+
+/// Get smaller of two values, the first one if they are unordered.
+pub(crate) fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Get larger of two values, the first one if they are unordered.
+pub(crate) fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
 impl<T, U, R> Div<U> for Vector<T>
 where
     T: Div<U, Output = R>,
@@ -236,6 +589,30 @@ where
     }
 }
 
+impl<T> Vector<T> {
+    /// Calculate dot product borrowing both vectors, for scalar types which are not `Copy`.
+    pub fn dot_ref<U, R>(&self, other: &Vector<U>) -> R
+    where
+        for<'a> &'a T: Mul<&'a U, Output = R>,
+        R: Add<Output = R>,
+    {
+        &self.x * &other.x + &self.y * &other.y + &self.z * &other.z
+    }
+
+    /// Calculate cross product borrowing both vectors, for scalar types which are not `Copy`.
+    pub fn cross_ref<U, R>(&self, other: &Vector<U>) -> Vector<R>
+    where
+        for<'a> &'a T: Mul<&'a U, Output = R>,
+        R: Sub<Output = R>,
+    {
+        Vector {
+            x: &self.y * &other.z - &self.z * &other.y,
+            y: &self.z * &other.x - &self.x * &other.z,
+            z: &self.x * &other.y - &self.y * &other.x,
+        }
+    }
+}
+
 impl<T> Add for Vector<T>
 where
     T: Add<Output = T>,
@@ -277,3 +654,151 @@ where
         }
     }
 }
+
+impl<T> AddAssign for Vector<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T> SubAssign for Vector<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+impl<T, U> MulAssign<U> for Vector<T>
+where
+    T: MulAssign<U>,
+    U: Copy,
+{
+    fn mul_assign(&mut self, scalar: U) {
+        self.x *= scalar;
+        self.y *= scalar;
+        self.z *= scalar;
+    }
+}
+
+impl<T, U> DivAssign<U> for Vector<T>
+where
+    T: DivAssign<U>,
+    U: Copy,
+{
+    fn div_assign(&mut self, scalar: U) {
+        self.x /= scalar;
+        self.y /= scalar;
+        self.z /= scalar;
+    }
+}
+
+/// Implement scalar multiplication from the left for given floating point type, generic
+/// implementation is not allowed for foreign scalar types.
+macro_rules! impl_vector_scalar_operators {
+    ($($float:ty),+) => {$(
+        impl Mul<Vector<$float>> for $float {
+            type Output = Vector<$float>;
+            fn mul(self, vector: Vector<$float>) -> Self::Output {
+                vector * self
+            }
+        }
+    )+};
+}
+
+impl_vector_scalar_operators!(f32, f64);
+
+/// Format as `[x, y, z]`, formatter flags such as precision apply to every component.
+/// ```
+/// # use spatial::vector::Vector;
+/// assert_eq!(format!("{:.2}", Vector::new(1.0, -0.5, 1.0 / 3.0)), "[1.00, -0.50, 0.33]");
+/// ```
+impl<T: Display> Display for Vector<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("[")?;
+        self.x.fmt(formatter)?;
+        formatter.write_str(", ")?;
+        self.y.fmt(formatter)?;
+        formatter.write_str(", ")?;
+        self.z.fmt(formatter)?;
+        formatter.write_str("]")
+    }
+}
+
+impl<T> From<[T; 3]> for Vector<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Vector<T>> for [T; 3] {
+    fn from(vector: Vector<T>) -> Self {
+        [vector.x, vector.y, vector.z]
+    }
+}
+
+/// Convert slice of `x`, `y` and `z` components.
+impl<T: Copy> TryFrom<&[T]> for Vector<T> {
+    type Error = ConversionError;
+    fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+        to_array::<T, 3>(slice).map(Self::from)
+    }
+}
+
+/// Access components by index in `x, y, z` order, panics if the index is out of bounds.
+/// ```
+/// # use spatial::vector::Vector;
+/// let mut vector = Vector::new(1.0, 2.0, 3.0);
+/// vector[2] = 5.0;
+/// assert_eq!(vector[0] + vector[2], 6.0);
+/// assert_eq!(vector.into_iter().sum::<f64>(), 8.0);
+/// for component in &mut vector {
+///     *component *= 2.0;
+/// }
+/// assert_eq!(vector, Vector::new(2.0, 4.0, 10.0));
+/// ```
+impl<T> Index<usize> for Vector<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_array()[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_mut_array()[index]
+    }
+}
+
+/// Iterate over components in `x, y, z` order.
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, 3>;
+    fn into_iter(self) -> Self::IntoIter {
+        <[T; 3]>::from(self).into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_array().iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Vector<T> {
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_array().iter_mut()
+    }
+}
@@ -0,0 +1,232 @@
+use crate::ops::{Epsilon, Norm, Sqrt};
+use crate::vector::Vector;
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+#[cfg(feature = "num")]
+use num::{One, Zero};
+
+/// Planar vector, counterpart of [`Vector`] for mobile robots and other planar problems.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Vector2<T> {
+    x: T,
+    y: T,
+}
+
+impl<T> Vector2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn map<F: Fn(T) -> R, R>(self, mapper: F) -> Vector2<R> {
+        Vector2 {
+            x: mapper(self.x),
+            y: mapper(self.y),
+        }
+    }
+
+    /// Lift into spatial vector with given `z` coordinate.
+    pub fn into_vector3(self, z: T) -> Vector<T> {
+        Vector::new(self.x, self.y, z)
+    }
+}
+
+/// Implement constant planar vectors for given floating point type.
+macro_rules! impl_vector2_constants {
+    ($($float:ty),+) => {$(
+        impl Vector2<$float> {
+            /// Vector with all components equal to zero.
+            pub const ZERO: Self = Self::new(0.0, 0.0);
+            /// Unit vector along the `x` axis.
+            pub const UNIT_X: Self = Self::new(1.0, 0.0);
+            /// Unit vector along the `y` axis.
+            pub const UNIT_Y: Self = Self::new(0.0, 1.0);
+        }
+    )+};
+}
+
+impl_vector2_constants!(f32, f64);
+
+impl<T> Vector2<T>
+where
+    T: Copy,
+{
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    pub fn y(&self) -> T {
+        self.y
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> Vector2<T>
+where
+    T: Zero,
+{
+    pub fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+}
+
+#[cfg(feature = "num")]
+impl<T> Vector2<T>
+where
+    T: One + Zero,
+{
+    pub fn unit_x() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+
+    pub fn unit_y() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+}
+
+impl<T> Vector2<T> {
+    pub fn dot<U, R>(self, other: Vector2<U>) -> R
+    where
+        T: Mul<U, Output = R>,
+        R: Add<Output = R>,
+    {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Calculate `z` component of the cross product of vectors lifted into the `xy` plane.
+    /// ```
+    /// # use spatial::vector2::Vector2;
+    /// assert_eq!(Vector2::new(1.0, 0.0).cross(Vector2::new(0.0, 2.0)), 2.0);
+    /// ```
+    pub fn cross<U, R>(self, other: Vector2<U>) -> R
+    where
+        T: Copy + Mul<U, Output = R>,
+        U: Copy,
+        R: Sub<Output = R>,
+    {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Rotate vector by quarter turn counterclockwise.
+    pub fn perpendicular(self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn norm<I, R>(self) -> R
+    where
+        T: Copy + Mul<Output = I>,
+        I: Sqrt<Output = R> + Add<Output = I>,
+    {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+impl<T> Norm for Vector2<T>
+where
+    T: Copy + Mul,
+    T::Output: Sqrt + Add<Output = T::Output>,
+{
+    type Output = <T::Output as Sqrt>::Output;
+    fn norm(self) -> Self::Output {
+        Vector2::norm(self)
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Copy
+        + PartialOrd
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Sqrt<Output = T>
+        + Epsilon,
+{
+    pub fn normalized_checked(self) -> Option<Self> {
+        let norm = self.norm();
+        if norm < T::epsilon() {
+            None
+        } else {
+            Some(self / norm)
+        }
+    }
+}
+
+impl<T> Add for Vector2<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T> Sub for Vector2<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T> Neg for Vector2<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T, U, R> Mul<U> for Vector2<T>
+where
+    T: Mul<U, Output = R>,
+    U: Copy,
+{
+    type Output = Vector2<R>;
+    fn mul(self, scalar: U) -> Self::Output {
+        Vector2::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T, U, R> Div<U> for Vector2<T>
+where
+    T: Div<U, Output = R>,
+    U: Copy,
+{
+    type Output = Vector2<R>;
+    fn div(self, scalar: U) -> Self::Output {
+        Vector2::new(self.x / scalar, self.y / scalar)
+    }
+}
+
+impl<T> From<[T; 2]> for Vector2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Vector2<T>> for [T; 2] {
+    fn from(vector: Vector2<T>) -> Self {
+        [vector.x, vector.y]
+    }
+}
+
+/// Format as `[x, y]`, formatter flags such as precision apply to every component.
+impl<T: Display> Display for Vector2<T> {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("[")?;
+        self.x.fmt(formatter)?;
+        formatter.write_str(", ")?;
+        self.y.fmt(formatter)?;
+        formatter.write_str("]")
+    }
+}
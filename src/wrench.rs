@@ -0,0 +1,209 @@
+use crate::vector::Vector;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Spatial force, pair of force and torque.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Wrench<F, M> {
+    force: Vector<F>,
+    torque: Vector<M>,
+}
+
+impl<F, M> Wrench<F, M> {
+    /// Create new `Wrench` with given force and torque.
+    pub const fn new(force: Vector<F>, torque: Vector<M>) -> Self {
+        Self { force, torque }
+    }
+
+    /// Get reference to stored force.
+    pub fn force_ref(&self) -> &Vector<F> {
+        &self.force
+    }
+
+    /// Get reference to stored torque.
+    pub fn torque_ref(&self) -> &Vector<M> {
+        &self.torque
+    }
+
+    /// Get mutable reference to stored force.
+    pub fn force_mut(&mut self) -> &mut Vector<F> {
+        &mut self.force
+    }
+
+    /// Get mutable reference to stored torque.
+    pub fn torque_mut(&mut self) -> &mut Vector<M> {
+        &mut self.torque
+    }
+
+    /// Set force.
+    pub fn set_force(&mut self, force: Vector<F>) {
+        self.force = force;
+    }
+
+    /// Set torque.
+    pub fn set_torque(&mut self, torque: Vector<M>) {
+        self.torque = torque;
+    }
+
+    /// Get force.
+    pub fn force(&self) -> Vector<F>
+    where
+        F: Copy,
+    {
+        self.force
+    }
+
+    /// Get torque.
+    pub fn torque(&self) -> Vector<M>
+    where
+        M: Copy,
+    {
+        self.torque
+    }
+}
+
+/// Implement constant wrenches for given floating point type.
+macro_rules! impl_wrench_constants {
+    ($($float:ty),+) => {$(
+        impl Wrench<$float, $float> {
+            /// Wrench with zero force and torque.
+            pub const ZERO: Self = Self::new(Vector::<$float>::ZERO, Vector::<$float>::ZERO);
+        }
+    )+};
+}
+
+impl_wrench_constants!(f32, f64);
+
+/// Scale both force and torque, e.g. by gain.
+impl<F, M, U, FR, MR> Mul<U> for Wrench<F, M>
+where
+    F: Mul<U, Output = FR>,
+    M: Mul<U, Output = MR>,
+    U: Copy,
+{
+    type Output = Wrench<FR, MR>;
+    fn mul(self, scalar: U) -> Self::Output {
+        Wrench {
+            force: self.force * scalar,
+            torque: self.torque * scalar,
+        }
+    }
+}
+
+/// Divide both force and torque, e.g. by gain.
+impl<F, M, U, FR, MR> Div<U> for Wrench<F, M>
+where
+    F: Div<U, Output = FR>,
+    M: Div<U, Output = MR>,
+    U: Copy,
+{
+    type Output = Wrench<FR, MR>;
+    fn div(self, scalar: U) -> Self::Output {
+        Wrench {
+            force: self.force / scalar,
+            torque: self.torque / scalar,
+        }
+    }
+}
+
+impl<F, M> Add for Wrench<F, M>
+where
+    F: Add<Output = F>,
+    M: Add<Output = M>,
+{
+    type Output = Self;
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            force: self.force + other.force,
+            torque: self.torque + other.torque,
+        }
+    }
+}
+
+impl<F, M> Sub for Wrench<F, M>
+where
+    F: Sub<Output = F>,
+    M: Sub<Output = M>,
+{
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            force: self.force - other.force,
+            torque: self.torque - other.torque,
+        }
+    }
+}
+
+impl<F, M> Neg for Wrench<F, M>
+where
+    F: Neg<Output = F>,
+    M: Neg<Output = M>,
+{
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self {
+            force: -self.force,
+            torque: -self.torque,
+        }
+    }
+}
+
+impl<F, M> AddAssign for Wrench<F, M>
+where
+    F: AddAssign,
+    M: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.force += other.force;
+        self.torque += other.torque;
+    }
+}
+
+impl<F, M> SubAssign for Wrench<F, M>
+where
+    F: SubAssign,
+    M: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.force -= other.force;
+        self.torque -= other.torque;
+    }
+}
+
+impl<F, M, U> MulAssign<U> for Wrench<F, M>
+where
+    F: MulAssign<U>,
+    M: MulAssign<U>,
+    U: Copy,
+{
+    fn mul_assign(&mut self, scalar: U) {
+        self.force *= scalar;
+        self.torque *= scalar;
+    }
+}
+
+impl<F, M, U> DivAssign<U> for Wrench<F, M>
+where
+    F: DivAssign<U>,
+    M: DivAssign<U>,
+    U: Copy,
+{
+    fn div_assign(&mut self, scalar: U) {
+        self.force /= scalar;
+        self.torque /= scalar;
+    }
+}
+
+/// Implement scalar multiplication from the left for given floating point type.
+macro_rules! impl_wrench_scalar_operators {
+    ($($float:ty),+) => {$(
+        impl Mul<Wrench<$float, $float>> for $float {
+            type Output = Wrench<$float, $float>;
+            fn mul(self, wrench: Wrench<$float, $float>) -> Self::Output {
+                wrench * self
+            }
+        }
+    )+};
+}
+
+impl_wrench_scalar_operators!(f32, f64);